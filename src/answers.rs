@@ -0,0 +1,529 @@
+use std::{
+    collections::{BTreeSet, HashMap},
+    fmt::{Display, Write as _},
+    fs,
+    path::Path,
+};
+
+use crate::{
+    error::{Error, Result},
+    solution::Part,
+    utils::{
+        file::read_lines,
+        input::{input_file_name, InputSource},
+    },
+};
+
+/// Default location of the answer registry.
+pub const DEFAULT_ANSWERS_FILE: &str = "answers.toml";
+
+/// Comment at the top of a saved registry.
+const HEADER: &str = "\
+# Known answers, checked by `aoc --verify` and updated by `aoc submit`.
+#
+# Tables are named [<input>.dayNN], where <input> is the name of the directory holding the
+# dayNN.txt files (or the file name without extension for other input files).
+";
+
+/// Known answers, keyed by input name, day and part.
+///
+/// The registry is a small subset of TOML with one table per input and day:
+///
+/// ```toml
+/// [inputs.day09]
+/// part1 = "6607511583593"
+/// part2 = 6636608781232
+/// part2_wrong = ["6636608781", "6636608781233"]
+/// part2_too_low = 6636608781
+/// part2_retry_at = 1733731200
+/// ```
+///
+/// Answers can be strings or integers. The `partN_*` keys record what is known from answers
+/// submitted to the website, see [`Attempts`]. The input name is usually the directory holding
+/// the `dayNN.txt` files, see [`input_name`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    entries: HashMap<(String, u8, Part), String>,
+    attempts: HashMap<(String, u8, Part), Attempts>,
+}
+
+/// What the website said about the wrong answers submitted for a part.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Attempts {
+    /// Every answer that was rejected, in the order they were submitted.
+    pub wrong: Vec<String>,
+    /// The largest answer known to be too low.
+    pub too_low: Option<i64>,
+    /// The smallest answer known to be too high.
+    pub too_high: Option<i64>,
+    /// Unix time in seconds before which the website doesn't accept another answer.
+    pub retry_at: Option<u64>,
+}
+
+/// The keys of a day table, each prefixed with `partN`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Key {
+    Answer,
+    Wrong,
+    TooLow,
+    TooHigh,
+    RetryAt,
+}
+
+impl Key {
+    const ALL: [Key; 5] = [
+        Key::Answer,
+        Key::Wrong,
+        Key::TooLow,
+        Key::TooHigh,
+        Key::RetryAt,
+    ];
+
+    fn suffix(&self) -> &'static str {
+        match self {
+            Key::Answer => "",
+            Key::Wrong => "_wrong",
+            Key::TooLow => "_too_low",
+            Key::TooHigh => "_too_high",
+            Key::RetryAt => "_retry_at",
+        }
+    }
+}
+
+impl Answers {
+    pub fn parse(text: &str) -> Result<Self> {
+        let mut answers = Answers::default();
+        let mut table: Option<(String, u8)> = None;
+        for (i, line) in text.lines().enumerate() {
+            let line = strip_comment(line).trim();
+            if line.is_empty() {
+                continue;
+            }
+            let result = if let Some(header) = line.strip_prefix('[') {
+                parse_table_header(header).map(|header| table = Some(header))
+            } else {
+                table
+                    .as_ref()
+                    .ok_or_else(|| Error::parse("Answer outside of an [input.dayNN] table"))
+                    .and_then(|(input, day)| answers.parse_entry(input, *day, line))
+            };
+            result.map_err(|e| e.at_line(i + 1))?;
+        }
+        Ok(answers)
+    }
+
+    /// Parses a `partN = answer` or `partN_<key> = value` line of the table of `input` and `day`.
+    fn parse_entry(&mut self, input: &str, day: u8, line: &str) -> Result<()> {
+        let (key, value) = line
+            .split_once('=')
+            .ok_or_else(|| Error::parse(format!("Expected 'partN = answer', got '{}'", line)))?;
+        let (part, key) = parse_key(key.trim())?;
+        let value = value.trim();
+        let id = (input.to_string(), day, part);
+        match key {
+            Key::Answer => {
+                self.entries.insert(id, parse_answer(value)?);
+            }
+            Key::Wrong => self.attempts.entry(id).or_default().wrong = parse_array(value)?,
+            Key::TooLow => self.attempts.entry(id).or_default().too_low = Some(parse_int(value)?),
+            Key::TooHigh => self.attempts.entry(id).or_default().too_high = Some(parse_int(value)?),
+            Key::RetryAt => self.attempts.entry(id).or_default().retry_at = Some(parse_int(value)?),
+        }
+        Ok(())
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        Self::parse(&read_lines(path)?).map_err(|e| e.in_file(path.display()))
+    }
+
+    /// Formats the registry the way [`Answers::parse`] reads it, with the tables sorted by input
+    /// and day.
+    pub fn to_toml(&self) -> String {
+        let tables: BTreeSet<(&str, u8)> = self
+            .entries
+            .keys()
+            .chain(self.attempts.keys())
+            .map(|(input, day, _)| (input.as_str(), *day))
+            .collect();
+        let mut text = HEADER.to_string();
+        for (input, day) in tables {
+            let name = if input
+                .chars()
+                .all(|ch| ch.is_ascii_alphanumeric() || ch == '_' || ch == '-')
+            {
+                input.to_string()
+            } else {
+                quote(input)
+            };
+            writeln!(text, "\n[{}.day{:02}]", name, day).unwrap();
+            for part in Part::ALL {
+                let id = (input.to_string(), day, part);
+                let attempts = self.attempts.get(&id);
+                for key in Key::ALL {
+                    let value = match key {
+                        Key::Answer => self.entries.get(&id).map(|answer| quote(answer)),
+                        Key::Wrong => attempts.filter(|a| !a.wrong.is_empty()).map(|a| {
+                            let wrong: Vec<String> = a.wrong.iter().map(|w| quote(w)).collect();
+                            format!("[{}]", wrong.join(", "))
+                        }),
+                        Key::TooLow => attempts.and_then(|a| a.too_low).map(|n| n.to_string()),
+                        Key::TooHigh => attempts.and_then(|a| a.too_high).map(|n| n.to_string()),
+                        Key::RetryAt => attempts.and_then(|a| a.retry_at).map(|n| n.to_string()),
+                    };
+                    if let Some(value) = value {
+                        writeln!(text, "part{}{} = {}", part, key.suffix(), value).unwrap();
+                    }
+                }
+            }
+        }
+        text
+    }
+
+    /// Writes the registry to `path`, replacing any comments with the standard header.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        fs::write(path, self.to_toml()).map_err(|e| Error::io(path.display(), e))
+    }
+
+    pub fn get(&self, input: &str, day: u8, part: Part) -> Option<&str> {
+        self.entries
+            .get(&(input.to_string(), day, part))
+            .map(String::as_str)
+    }
+
+    pub fn insert(&mut self, input: &str, day: u8, part: Part, answer: impl Into<String>) {
+        self.entries
+            .insert((input.to_string(), day, part), answer.into());
+    }
+
+    /// What is known about the wrong answers of the part, if any were submitted.
+    pub fn attempts(&self, input: &str, day: u8, part: Part) -> Option<&Attempts> {
+        self.attempts.get(&(input.to_string(), day, part))
+    }
+
+    pub fn attempts_mut(&mut self, input: &str, day: u8, part: Part) -> &mut Attempts {
+        self.attempts
+            .entry((input.to_string(), day, part))
+            .or_default()
+    }
+
+    /// Compares `answer` to the recorded answer of the part, if there is one.
+    pub fn check(&self, input: Option<&str>, day: u8, part: Part, answer: &str) -> Verdict {
+        match input.and_then(|input| self.get(input, day, part)) {
+            None => Verdict::Unknown,
+            Some(expected) if expected == answer => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.to_string(),
+            },
+        }
+    }
+}
+
+/// Outcome of comparing a computed answer to the registry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Unknown,
+}
+
+impl Verdict {
+    /// The verdict as one lowercase word, without the expected answer.
+    pub fn status(&self) -> &'static str {
+        match self {
+            Verdict::Pass => "pass",
+            Verdict::Fail { .. } => "fail",
+            Verdict::Unknown => "unknown",
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "pass"),
+            Verdict::Fail { expected } => write!(f, "FAIL, expected {}", expected),
+            Verdict::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+/// Name under which the answers for the input of `day` are recorded.
+///
+/// For `dir/dayNN.txt` this is the name of the directory, so that a set of inputs shares one
+/// name, otherwise the file name without extension. Stdin has no name.
+pub fn input_name(source: &InputSource, day: u8) -> Option<String> {
+    let InputSource::File(path) = source else {
+        return None;
+    };
+    let is_day_file = path
+        .file_name()
+        .is_some_and(|name| name.to_str() == Some(input_file_name(day).as_str()));
+    let name = if is_day_file {
+        let dir = path
+            .parent()
+            .filter(|dir| !dir.as_os_str().is_empty())
+            .unwrap_or(Path::new("."));
+        // Canonicalized so that `./day05.txt` and `../inputs/day05.txt` are named too.
+        let dir = dir.canonicalize().unwrap_or_else(|_| dir.to_path_buf());
+        dir.file_name().map(|name| name.to_owned())
+    } else {
+        path.file_stem().map(|stem| stem.to_owned())
+    };
+    name.and_then(|name| name.to_str().map(str::to_string))
+}
+
+/// Removes a trailing `# comment`, unless the `#` is inside a string.
+pub(crate) fn strip_comment(line: &str) -> &str {
+    let mut in_string = false;
+    let mut escaped = false;
+    for (i, ch) in line.char_indices() {
+        match ch {
+            _ if escaped => escaped = false,
+            '\\' if in_string => escaped = true,
+            '"' => in_string = !in_string,
+            '#' if !in_string => return &line[..i],
+            _ => {}
+        }
+    }
+    line
+}
+
+/// Parses the rest of a `[input.dayNN]` line.
+fn parse_table_header(header: &str) -> Result<(String, u8)> {
+    let header = header
+        .strip_suffix(']')
+        .ok_or_else(|| Error::parse("Missing ']' at the end of the table header"))?;
+    let (input, day) = header
+        .trim()
+        .rsplit_once('.')
+        .ok_or_else(|| Error::parse(format!("Expected [input.dayNN], got [{}]", header)))?;
+    let input = unquote(input.trim()).unwrap_or_else(|| input.trim().to_string());
+    let day = day
+        .trim()
+        .strip_prefix("day")
+        .and_then(|day| day.parse().ok())
+        .filter(|day| (1..=25).contains(day))
+        .ok_or_else(|| Error::parse(format!("Invalid day '{}'", day.trim())))?;
+    if input.is_empty() {
+        return Err(Error::parse("Empty input name"));
+    }
+    Ok((input.to_string(), day))
+}
+
+/// Splits a `partN` or `partN_<key>` key into the part and the key.
+fn parse_key(key: &str) -> Result<(Part, Key)> {
+    let unknown = || Error::parse(format!("Unknown key '{}'", key));
+    let (part, suffix) = match key.get(..5) {
+        Some("part1") => (Part::One, &key[5..]),
+        Some("part2") => (Part::Two, &key[5..]),
+        _ => return Err(unknown()),
+    };
+    let key = Key::ALL
+        .into_iter()
+        .find(|k| k.suffix() == suffix)
+        .ok_or_else(unknown)?;
+    Ok((part, key))
+}
+
+/// Parses an answer, given as a string or an integer.
+fn parse_answer(value: &str) -> Result<String> {
+    match unquote(value) {
+        Some(answer) => Ok(answer),
+        None if value.parse::<i64>().is_ok() => Ok(value.to_string()),
+        None => Err(Error::parse(format!(
+            "Expected a string or an integer, got '{}'",
+            value
+        ))),
+    }
+}
+
+fn parse_int<T: std::str::FromStr>(value: &str) -> Result<T> {
+    value
+        .parse()
+        .map_err(|_| Error::parse(format!("Expected an integer, got '{}'", value)))
+}
+
+/// Parses an array of answers like `["ab,cd", 12]`.
+fn parse_array(value: &str) -> Result<Vec<String>> {
+    let mut rest = value
+        .strip_prefix('[')
+        .and_then(|v| v.strip_suffix(']'))
+        .ok_or_else(|| Error::parse(format!("Expected an array, got '{}'", value)))?
+        .trim();
+    let mut answers = Vec::new();
+    while !rest.is_empty() {
+        // Strings may contain commas, so find the end of each item before splitting.
+        let end = match rest.strip_prefix('"') {
+            Some(string) => closing_quote(string).map_or(rest.len(), |i| i + 2),
+            None => rest.find(',').unwrap_or(rest.len()),
+        };
+        answers.push(parse_answer(rest[..end].trim())?);
+        rest = rest[end..].trim_start();
+        rest = rest.strip_prefix(',').unwrap_or(rest).trim_start();
+    }
+    Ok(answers)
+}
+
+/// `value` as a TOML string, with `"` and `\` escaped.
+fn quote(value: &str) -> String {
+    let escaped = value.replace('\\', "\\\\").replace('"', "\\\"");
+    format!("\"{}\"", escaped)
+}
+
+/// The contents of the TOML string `value`, or `None` if it isn't a single string.
+///
+/// Undoes the escapes that [`quote`] writes. A backslash before any other character is dropped.
+pub(crate) fn unquote(value: &str) -> Option<String> {
+    let inner = value.strip_prefix('"')?;
+    if closing_quote(inner)? + 1 != inner.len() {
+        return None;
+    }
+    let mut unescaped = String::new();
+    let mut chars = inner[..inner.len() - 1].chars();
+    while let Some(ch) = chars.next() {
+        unescaped.push(if ch == '\\' { chars.next()? } else { ch });
+    }
+    Some(unescaped)
+}
+
+/// Position of the `"` that ends a string, in the rest of the string after its opening `"`.
+fn closing_quote(rest: &str) -> Option<usize> {
+    let mut escaped = false;
+    for (i, ch) in rest.char_indices() {
+        match ch {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            '"' => return Some(i),
+            _ => {}
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse(
+            "# Known answers\n\
+             [inputs.day09]\n\
+             part1 = \"6607511583593\" # block compaction\n\
+             part2 = 6636608781232\n\
+             \n\
+             [\"friend's inputs\".day23]\n\
+             part2 = \"ag,bt,cq\"\n",
+        )
+        .unwrap();
+        assert_eq!(answers.get("inputs", 9, Part::One), Some("6607511583593"));
+        assert_eq!(answers.get("inputs", 9, Part::Two), Some("6636608781232"));
+        assert_eq!(
+            answers.get("friend's inputs", 23, Part::Two),
+            Some("ag,bt,cq")
+        );
+        assert_eq!(answers.get("inputs", 23, Part::Two), None);
+
+        let err = Answers::parse("[inputs.day09]\npart3 = 1\n").unwrap_err();
+        assert_eq!(err.to_string(), "2: Unknown key 'part3'");
+        let err = Answers::parse("part1 = 1\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "1: Answer outside of an [input.dayNN] table"
+        );
+        let err = Answers::parse("[inputs.day26]\n").unwrap_err();
+        assert_eq!(err.to_string(), "1: Invalid day 'day26'");
+    }
+
+    #[test]
+    fn test_attempts() {
+        let text = "[inputs.day05]\n\
+                    part1_wrong = [\"ab,cd\", 12, \"7\"]\n\
+                    part1_too_low = 7\n\
+                    part1_too_high = 12\n\
+                    part1_retry_at = 1733400000\n\
+                    part2 = \"42\"\n";
+        let answers = Answers::parse(text).unwrap();
+        let attempts = answers.attempts("inputs", 5, Part::One).unwrap();
+        assert_eq!(attempts.wrong, ["ab,cd", "12", "7"]);
+        assert_eq!((attempts.too_low, attempts.too_high), (Some(7), Some(12)));
+        assert_eq!(attempts.retry_at, Some(1733400000));
+        assert_eq!(answers.attempts("inputs", 5, Part::Two), None);
+
+        let toml = answers.to_toml();
+        assert!(toml.starts_with(HEADER));
+        assert!(toml.contains("part1_wrong = [\"ab,cd\", \"12\", \"7\"]\n"));
+        assert_eq!(Answers::parse(&toml).unwrap(), answers);
+
+        let err = Answers::parse("[inputs.day05]\npart1_wrong = 12\n").unwrap_err();
+        assert_eq!(err.to_string(), "2: Expected an array, got '12'");
+        let err = Answers::parse("[inputs.day05]\npart1_too_low = \"x\"\n").unwrap_err();
+        assert_eq!(err.to_string(), "2: Expected an integer, got '\"x\"'");
+    }
+
+    #[test]
+    fn test_registry_is_saved_unchanged() {
+        let text = format!(
+            "{}\n\
+             [\"friend's inputs\".day23]\n\
+             part2 = \"ag,bt,cq\"\n\
+             \n\
+             [inputs.day09]\n\
+             part1 = \"6607511583593\"\n\
+             part2 = \"6636608781232\"\n\
+             part2_wrong = [\"6636608781\", \"6636608781233\"]\n\
+             part2_too_low = 6636608781\n\
+             part2_retry_at = 1733731200\n",
+            HEADER
+        );
+        let answers = Answers::parse(&text).unwrap();
+        assert_eq!(answers.to_toml(), text);
+    }
+
+    #[test]
+    fn test_quotes_and_backslashes() {
+        let mut answers = Answers::default();
+        answers.insert("inputs", 1, Part::One, r#"a"b\c # d"#);
+        answers.attempts_mut("inputs", 1, Part::One).wrong = vec![r#"x", "y"#.to_string()];
+        let toml = answers.to_toml();
+        assert!(toml.contains(r#"part1 = "a\"b\\c # d""#));
+        assert_eq!(Answers::parse(&toml).unwrap(), answers);
+        assert_eq!(unquote(r#""a\"b""#), Some(r#"a"b"#.to_string()));
+        assert_eq!(unquote(r#""a"b""#), None);
+        assert_eq!(unquote(r#""a\""#), None);
+    }
+
+    #[test]
+    fn test_check() {
+        let mut answers = Answers::default();
+        answers.insert("inputs", 1, Part::One, "11");
+        assert_eq!(
+            answers.check(Some("inputs"), 1, Part::One, "11"),
+            Verdict::Pass
+        );
+        assert_eq!(
+            answers.check(Some("inputs"), 1, Part::One, "12"),
+            Verdict::Fail {
+                expected: "11".to_string()
+            }
+        );
+        assert_eq!(
+            answers.check(Some("inputs"), 1, Part::Two, "31"),
+            Verdict::Unknown
+        );
+        assert_eq!(answers.check(None, 1, Part::One, "11"), Verdict::Unknown);
+    }
+
+    #[test]
+    fn test_input_name() {
+        let source = InputSource::File(PathBuf::from("src/day05.txt"));
+        assert_eq!(input_name(&source, 5), Some("src".to_string()));
+        let source = InputSource::File(PathBuf::from("examples/large.txt"));
+        assert_eq!(input_name(&source, 5), Some("large".to_string()));
+        assert_eq!(input_name(&InputSource::Stdin, 5), None);
+    }
+}
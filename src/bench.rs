@@ -0,0 +1,517 @@
+use std::{
+    fmt::Display,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    error::Result,
+    runner::solve_part,
+    solution::{Part, Runnable},
+    utils::{
+        input::InputSource,
+        json::Json,
+        table::{format_csv, format_duration, format_table},
+    },
+};
+
+/// A step of solving a day that is timed on its own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stage {
+    Parse,
+    Part(Part),
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Stage::Parse => write!(f, "parse"),
+            Stage::Part(part) => write!(f, "part{}", part),
+        }
+    }
+}
+
+/// Minimum, median and maximum of repeated measurements.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// Summarizes the samples, or returns `None` if there are none.
+    pub fn from_samples(mut samples: Vec<Duration>) -> Option<Self> {
+        samples.sort();
+        let n = samples.len();
+        let median = match n {
+            0 => return None,
+            _ if n % 2 == 1 => samples[n / 2],
+            _ => (samples[n / 2 - 1] + samples[n / 2]) / 2,
+        };
+        Some(Stats {
+            runs: n,
+            min: samples[0],
+            median,
+            max: samples[n - 1],
+        })
+    }
+}
+
+/// The timings of a stage, or why a part stopped without an answer.
+pub type StageResult = Result<Stats, String>;
+
+/// Timings of the stages of one day.
+#[derive(Debug, Clone)]
+pub struct BenchReport {
+    pub day: u8,
+    pub title: &'static str,
+    pub results: Result<Vec<(Stage, StageResult)>, String>,
+}
+
+impl BenchReport {
+    /// Whether the input couldn't be read or parsed, or a part failed.
+    pub fn has_errors(&self) -> bool {
+        match &self.results {
+            Ok(results) => results.iter().any(|(_, stats)| stats.is_err()),
+            Err(_) => true,
+        }
+    }
+}
+
+/// Calls `f` `runs` times and returns the last result with the time of each call.
+fn repeat<T>(runs: usize, mut f: impl FnMut() -> Result<T>) -> Result<(T, Stats)> {
+    let mut samples = Vec::with_capacity(runs);
+    let mut last = None;
+    for _ in 0..runs.max(1) {
+        let start = Instant::now();
+        let result = f()?;
+        samples.push(start.elapsed());
+        last = Some(result);
+    }
+    let stats = Stats::from_samples(samples).expect("At least one run");
+    Ok((last.expect("At least one run"), stats))
+}
+
+/// Parses the input and solves each of the given parts `runs` times.
+///
+/// Like [`solve_parts`](crate::runner::solve_parts), each run of a part is stopped after `limit`
+/// and a panic of its solver is caught. The part then gets the failure instead of its timings,
+/// and the other parts are still measured.
+pub fn bench_parts(
+    solution: &dyn Runnable,
+    input: &str,
+    parts: &[Part],
+    runs: usize,
+    limit: Option<Duration>,
+) -> Result<Vec<(Stage, StageResult)>> {
+    let (parsed, parse_stats) = repeat(runs, || solution.parse(input))?;
+    let mut results = vec![(Stage::Parse, Ok(parse_stats))];
+    for &part in parts {
+        let mut samples = Vec::with_capacity(runs);
+        let stats = (0..runs.max(1))
+            .try_for_each(|_| {
+                let result = solve_part(solution, parsed.as_ref(), part, limit);
+                samples.push(result.elapsed);
+                result.error().map_or(Ok(()), Err)
+            })
+            .map(|_| Stats::from_samples(samples).expect("At least one run"));
+        results.push((Stage::Part(part), stats));
+    }
+    Ok(results)
+}
+
+/// Reads the input of the day from `source` and benchmarks the given parts, each run within
+/// `limit`.
+pub fn bench_day(
+    solution: &dyn Runnable,
+    source: &InputSource,
+    parts: &[Part],
+    runs: usize,
+    limit: Option<Duration>,
+) -> BenchReport {
+    let results = source
+        .read()
+        .and_then(|input| {
+            bench_parts(solution, &input, parts, runs, limit).map_err(|e| e.in_file(source))
+        })
+        .map_err(|e| e.to_string());
+    BenchReport {
+        day: solution.day(),
+        title: solution.title(),
+        results,
+    }
+}
+
+/// One row per day and stage: day, title, stage, then the runs and timings formatted by `time`.
+fn rows(reports: &[BenchReport], time: impl Fn(Duration) -> String) -> Vec<Vec<String>> {
+    let mut rows = Vec::new();
+    for report in reports {
+        let day = report.day.to_string();
+        let title = report.title.to_string();
+        match &report.results {
+            Ok(results) => {
+                for (stage, stats) in results {
+                    let mut row = vec![day.clone(), title.clone(), stage.to_string()];
+                    match stats {
+                        Ok(stats) => row.extend([
+                            stats.runs.to_string(),
+                            time(stats.min),
+                            time(stats.median),
+                            time(stats.max),
+                            String::new(),
+                        ]),
+                        Err(e) => {
+                            row.extend(std::iter::repeat_n(String::new(), 4));
+                            row.push(e.clone());
+                        }
+                    }
+                    rows.push(row);
+                }
+            }
+            Err(e) => {
+                let mut row = vec![day, title];
+                row.extend(std::iter::repeat_n(String::new(), 5));
+                row.push(e.clone());
+                rows.push(row);
+            }
+        }
+    }
+    rows
+}
+
+/// Formats the reports as a table with one row per day and stage.
+pub fn bench_table(reports: &[BenchReport]) -> String {
+    let rows: Vec<Vec<String>> = rows(reports, format_duration)
+        .into_iter()
+        .map(|mut row| {
+            // Errors go right after the last column with a value: the title of a day whose input
+            // failed, or the stage that failed.
+            if let Some(error) = row.pop().filter(|e| !e.is_empty()) {
+                row.retain(|cell| !cell.is_empty());
+                row.push(format!("error: {}", error));
+            }
+            row
+        })
+        .collect();
+    format_table(
+        &["Day", "Title", "Stage", "Runs", "Min", "Median", "Max"],
+        &rows,
+    )
+}
+
+/// Formats the reports as CSV with one row per day and stage, with times in nanoseconds.
+pub fn bench_csv(reports: &[BenchReport]) -> String {
+    format_csv(
+        &[
+            "day",
+            "title",
+            "stage",
+            "runs",
+            "min_ns",
+            "median_ns",
+            "max_ns",
+            "error",
+        ],
+        &rows(reports, |t| t.as_nanos().to_string()),
+    )
+}
+
+/// Formats the reports as a JSON array with one object per day and stage, with times in
+/// nanoseconds.
+pub fn bench_json(reports: &[BenchReport]) -> String {
+    let mut records = Vec::new();
+    for report in reports {
+        let record = |stage: Option<Stage>, stats: Option<&Stats>, error: Option<&str>| {
+            Json::object([
+                ("day", report.day.into()),
+                ("title", report.title.into()),
+                ("stage", stage.map(|stage| stage.to_string()).into()),
+                ("runs", stats.map(|s| s.runs).into()),
+                ("min_ns", stats.map(|s| s.min.as_nanos()).into()),
+                ("median_ns", stats.map(|s| s.median.as_nanos()).into()),
+                ("max_ns", stats.map(|s| s.max.as_nanos()).into()),
+                ("error", error.into()),
+            ])
+        };
+        match &report.results {
+            Ok(results) => records.extend(results.iter().map(|(stage, stats)| {
+                record(
+                    Some(*stage),
+                    stats.as_ref().ok(),
+                    stats.as_ref().err().map(String::as_str),
+                )
+            })),
+            Err(e) => records.push(record(None, None, Some(e))),
+        }
+    }
+    Json::lines(&records)
+}
+
+/// Answer and timings of one implementation of a part.
+#[derive(Debug, Clone)]
+pub struct ImplementationResult {
+    pub part: Part,
+    pub name: &'static str,
+    pub answer: String,
+    pub stats: Stats,
+}
+
+/// Answers and timings of all implementations of the parts of one day.
+#[derive(Debug, Clone)]
+pub struct CompareReport {
+    pub day: u8,
+    pub title: &'static str,
+    pub results: Result<Vec<ImplementationResult>, String>,
+}
+
+impl CompareReport {
+    /// The implementations whose answer differs from the main one of the same part.
+    pub fn disagreements(&self) -> Vec<&ImplementationResult> {
+        let Ok(results) = &self.results else {
+            return Vec::new();
+        };
+        results
+            .iter()
+            .filter(|result| {
+                results
+                    .iter()
+                    .find(|main| main.part == result.part)
+                    .is_some_and(|main| main.answer != result.answer)
+            })
+            .collect()
+    }
+}
+
+/// Parses the input once and solves each of the given parts `runs` times with each of its
+/// implementations, the main one first.
+pub fn compare_parts(
+    solution: &dyn Runnable,
+    input: &str,
+    parts: &[Part],
+    runs: usize,
+) -> Result<Vec<ImplementationResult>> {
+    let parsed = solution.parse(input)?;
+    let mut results = Vec::new();
+    for &part in parts {
+        for name in solution.implementations(part) {
+            let (answer, stats) = repeat(runs, || {
+                Ok(solution
+                    .solve_with(parsed.as_ref(), part, name)
+                    .expect("Listed implementation"))
+            })?;
+            results.push(ImplementationResult {
+                part,
+                name,
+                answer,
+                stats,
+            });
+        }
+    }
+    Ok(results)
+}
+
+/// Reads the input of the day from `source` and compares the implementations of the given parts.
+pub fn compare_day(
+    solution: &dyn Runnable,
+    source: &InputSource,
+    parts: &[Part],
+    runs: usize,
+) -> CompareReport {
+    let results = source
+        .read()
+        .and_then(|input| {
+            compare_parts(solution, &input, parts, runs).map_err(|e| e.in_file(source))
+        })
+        .map_err(|e| e.to_string());
+    CompareReport {
+        day: solution.day(),
+        title: solution.title(),
+        results,
+    }
+}
+
+/// Formats the reports as a table with one row per implementation, with its median time relative
+/// to the main implementation and whether its answer agrees with it.
+pub fn compare_table(reports: &[CompareReport]) -> String {
+    let mut rows = Vec::new();
+    for report in reports {
+        let day = report.day.to_string();
+        let title = report.title.to_string();
+        let results = match &report.results {
+            Ok(results) => results,
+            Err(e) => {
+                rows.push(vec![day, title, format!("error: {}", e)]);
+                continue;
+            }
+        };
+        for result in results {
+            let main = results
+                .iter()
+                .find(|main| main.part == result.part)
+                .expect("Main implementation comes first");
+            let relative = result.stats.median.as_secs_f64() / main.stats.median.as_secs_f64();
+            let agrees = if std::ptr::eq(main, result) {
+                String::new()
+            } else if main.answer == result.answer {
+                "yes".to_string()
+            } else {
+                "NO".to_string()
+            };
+            rows.push(vec![
+                day.clone(),
+                title.clone(),
+                result.part.to_string(),
+                result.name.to_string(),
+                result.answer.clone(),
+                result.stats.runs.to_string(),
+                format_duration(result.stats.min),
+                format_duration(result.stats.median),
+                format_duration(result.stats.max),
+                format!("{:.2}x", relative),
+                agrees,
+            ]);
+        }
+    }
+    format_table(
+        &[
+            "Day",
+            "Title",
+            "Part",
+            "Implementation",
+            "Answer",
+            "Runs",
+            "Min",
+            "Median",
+            "Max",
+            "vs main",
+            "Agrees",
+        ],
+        &rows,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day09::Day09;
+
+    #[test]
+    fn test_stats() {
+        let ms = Duration::from_millis;
+        let stats = Stats::from_samples(vec![ms(5), ms(1), ms(3), ms(9)]).unwrap();
+        assert_eq!(
+            stats,
+            Stats {
+                runs: 4,
+                min: ms(1),
+                median: ms(4),
+                max: ms(9)
+            }
+        );
+        assert_eq!(Stats::from_samples(vec![ms(2)]).unwrap().median, ms(2));
+        assert_eq!(Stats::from_samples(Vec::new()), None);
+    }
+
+    #[test]
+    fn test_bench_csv() {
+        let ms = Duration::from_millis;
+        let stats = Stats {
+            runs: 3,
+            min: ms(1),
+            median: ms(2),
+            max: ms(3),
+        };
+        let reports = [
+            BenchReport {
+                day: 1,
+                title: "First",
+                results: Ok(vec![
+                    (Stage::Parse, Ok(stats)),
+                    (Stage::Part(Part::Two), Ok(stats)),
+                ]),
+            },
+            BenchReport {
+                day: 2,
+                title: "Second",
+                results: Err("missing".to_string()),
+            },
+        ];
+        let csv = bench_csv(&reports);
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines[1], "1,First,parse,3,1000000,2000000,3000000,");
+        assert_eq!(lines[2], "1,First,part2,3,1000000,2000000,3000000,");
+        assert_eq!(lines[3], "2,Second,,,,,,missing");
+
+        let table = bench_table(&reports);
+        assert_eq!(table.lines().nth(4), Some("2   | Second | error: missing"));
+
+        let json = bench_json(&reports);
+        assert_eq!(
+            json.lines().nth(2),
+            Some(
+                r#"  {"day":1,"title":"First","stage":"part2","runs":3,"min_ns":1000000,"median_ns":2000000,"max_ns":3000000,"error":null},"#
+            )
+        );
+    }
+
+    #[test]
+    fn test_bench_failures() {
+        // With the size of the real memory space, the bytes of the example never cut off the exit.
+        let input = std::fs::read_to_string("examples/day18.txt").unwrap();
+        let day = crate::day18::Day18::default();
+        let results = bench_parts(&day, &input, &Part::ALL, 2, None).unwrap();
+        let stages: Vec<_> = results.iter().map(|(stage, _)| *stage).collect();
+        assert_eq!(
+            stages,
+            [Stage::Parse, Stage::Part(Part::One), Stage::Part(Part::Two)]
+        );
+        assert_eq!(results[1].1.as_ref().unwrap().runs, 2);
+        assert!(results[2].1.as_ref().unwrap_err().starts_with("panicked: "));
+
+        let report = BenchReport {
+            day: 18,
+            title: "RAM Run",
+            results: Ok(results),
+        };
+        assert!(report.has_errors());
+        let table = bench_table(std::slice::from_ref(&report));
+        assert!(table
+            .lines()
+            .nth(4)
+            .unwrap()
+            .starts_with("18  | RAM Run | part2 | error: panicked: "));
+        let csv = bench_csv(std::slice::from_ref(&report));
+        assert!(csv
+            .lines()
+            .nth(3)
+            .unwrap()
+            .starts_with("18,RAM Run,part2,,,,,panicked: "));
+    }
+
+    #[test]
+    fn test_compare() {
+        let results = compare_parts(&Day09, "2333133121414131402\n", &[Part::Two], 2).unwrap();
+        let names: Vec<_> = results.iter().map(|result| result.name).collect();
+        assert_eq!(names, ["main", "compact-files"]);
+        assert!(results.iter().all(|result| result.answer == "2858"));
+
+        let mut report = CompareReport {
+            day: 9,
+            title: "Disk Fragmenter",
+            results: Ok(results),
+        };
+        assert!(report.disagreements().is_empty());
+        let table = compare_table(std::slice::from_ref(&report));
+        assert!(table.lines().nth(3).unwrap().ends_with("| yes"));
+
+        if let Ok(results) = &mut report.results {
+            results[1].answer = "42".to_string();
+        }
+        let disagreements = report.disagreements();
+        assert_eq!(disagreements.len(), 1);
+        assert_eq!(disagreements[0].name, "compact-files");
+        let table = compare_table(&[report]);
+        assert!(table.lines().nth(3).unwrap().ends_with("| NO"));
+    }
+}
@@ -0,0 +1,849 @@
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+    process,
+    time::{Duration, SystemTime},
+};
+
+use aoc2024::{
+    answers::{input_name, Answers, Verdict, DEFAULT_ANSWERS_FILE},
+    bench::{
+        bench_csv, bench_day, bench_json, bench_table, compare_day, compare_table, BenchReport,
+    },
+    client::{base_url, session_token, Client, Fetched, SESSION_FILE_VAR, SESSION_VAR},
+    config::{params_table, Config, DEFAULT_CONFIG_FILE},
+    generate::{generator, GENERATORS},
+    runner::{
+        lint_day, matrix_table, record_day, render_day, run_day, solve_day, summary_csv,
+        summary_json, summary_table, verification_summary, DayInput, DayMatrix, DayReport,
+        DEFAULT_TIME_LIMIT,
+    },
+    solution::{solution, solutions, Part, Runnable},
+    submit::{self, Outcome},
+    utils::{
+        input::{input_dir, InputSource, INPUT_DIR_VAR},
+        record::{parse_animation, replay, Recorder, DEFAULT_MAX_FRAMES},
+        render::RenderFormat,
+    },
+};
+
+const USAGE: &str = "Usage: aoc [-i INPUT] [--verify [--answers FILE] | --bench RUNS] [--timeout SECS] [--format FORMAT] [--render DIR] [DAY [PART]]
+       aoc -i DIR -i DIR... [--verify [--answers FILE]] [--timeout SECS] [--format FORMAT] [DAY [PART]]
+       aoc [-i INPUT] --record DIR [--every N] [--max-frames N] [--frames FORMAT] DAY [PART]
+       aoc --replay FILE [--fps FPS]
+       aoc fetch [-i DIR] [DAY]
+       aoc submit [-i INPUT] [--answers FILE] [--timeout SECS] DAY PART [ANSWER]
+       aoc lint [-i INPUT] [DAY]
+       aoc generate [--seed SEED] [--size SIZE] DAY
+       aoc compare [-i INPUT] [--bench RUNS] [DAY [PART]]
+       aoc params [DAY]
+
+Runs all days if no day is given, otherwise the given day (both parts unless PART is 1 or 2).
+A part that takes longer than the timeout is stopped at its next cancellation check and reported
+as timed out. Only the loops that may run forever or for very long check, so the rest of a part
+always runs to completion.
+With several --input directories, the days are run against each of them and the answers and
+times are printed side by side, flagging the inputs a part fails or is much slower on.
+`fetch` downloads the inputs that aren't in the input directory yet, logged in with the session
+token from $AOC_SESSION or the file named by $AOC_SESSION_FILE (default ~/.config/aoc/session).
+`submit` sends the answer of a part (computed unless given) and records the response in the
+answers file. Answers that are known to be wrong, or not until the website's wait is over, are
+not sent.
+`lint` checks the inputs against what the solvers assume and lists every problem with its line
+and column, without solving anything.
+`generate` prints a random input for the day, the same for the same seed, to stress the solver
+with `aoc DAY -i - --bench RUNS`.
+`compare` runs every implementation of the parts on the same input (RUNS times, default 3),
+checks that they agree with the main one and prints their timings. Without a DAY, it compares
+the days that have more than one implementation.
+`params` lists the puzzle parameters of the days, like the size of the room of day 14, with the
+values they get from the config file and --param.
+
+Options:
+  -i, --input INPUT    Input file of the day, `-` for stdin, or a directory with dayNN.txt files
+                       (for `fetch`, the directory to download the inputs to). Repeat it with
+                       directories to compare the answers and times of several accounts
+      --verify         Compare the answers to the known answers and report pass/fail/unknown
+      --answers FILE   Known answers to verify against or record into (default: answers.toml)
+      --bench RUNS     Time parsing and each part RUNS times and report min/median/max
+      --timeout SECS   Stop solving a part (each run of it with --bench) after SECS seconds, `0`
+                       or `none` for no limit (default: 60)
+      --format FORMAT  Output as `text` (default), `csv` or `json`, one record per part with times
+                       in nanoseconds, the input's SHA-256 and the verdict
+      --render DIR     Also write pictures of the puzzle states of the days that have them to DIR
+      --record DIR     Record the simulation of the day step by step into DIR/dayNN-partN.anim
+      --every N        Only record every Nth step (default: 1)
+      --max-frames N   Stop recording after N frames, keeping the final state (default: 1000)
+      --frames FORMAT  Write numbered frames as `ans`, `ppm`, `pgm` or `svg` instead of one file
+      --replay FILE    Play a recorded .anim file in the terminal
+      --fps FPS        Frames per second of the replay (default: 10)
+      --seed SEED      Seed of the generated input (default: 0)
+      --size SIZE      Size of the generated input (default: about the size of the real input)
+      --config FILE    Puzzle parameters of the days (default: aoc.toml, if it exists)
+  -p, --param [dayNN.]NAME=VALUE
+                       Set a puzzle parameter, of every day run that has it unless a day is given";
+
+/// How the reports are printed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Text,
+    Csv,
+    Json,
+}
+
+fn usage_error(message: &str) -> ! {
+    eprintln!("{}\n\n{}", message, USAGE);
+    process::exit(2);
+}
+
+/// The solution of the day given on the command line.
+fn day_solution(day: &str) -> Box<dyn Runnable> {
+    let day: u8 = day
+        .parse()
+        .unwrap_or_else(|_| usage_error(&format!("Invalid day: {}", day)));
+    solution(day).unwrap_or_else(|| usage_error(&format!("No solution for day {}", day)))
+}
+
+/// Loads the parameters from the config file, if there is one, followed by those from `--param`.
+fn load_config(file: Option<&str>, param_args: &[String]) -> Config {
+    let mut config = match file {
+        Some(file) => Config::load(file),
+        None if Path::new(DEFAULT_CONFIG_FILE).exists() => Config::load(DEFAULT_CONFIG_FILE),
+        None => Ok(Config::default()),
+    }
+    .unwrap_or_else(|e| {
+        eprintln!("Error: {}", e);
+        process::exit(1);
+    });
+    for arg in param_args {
+        config
+            .set_arg(arg)
+            .unwrap_or_else(|e| usage_error(&format!("Invalid value for --param: {}", e)));
+    }
+    config
+}
+
+/// Sets the parameters of the days from the config, exiting if one is unknown or invalid.
+fn configure(mut days: Vec<Box<dyn Runnable>>, config: &Config) -> Vec<Box<dyn Runnable>> {
+    if let Err(e) = config.apply(&mut days) {
+        eprintln!("Error: {}", e);
+        process::exit(1);
+    }
+    days
+}
+
+/// Parses a positive number given as the value of `option`.
+fn positive<T: std::str::FromStr + PartialOrd + Default>(option: &str, value: &str) -> T {
+    value
+        .parse()
+        .ok()
+        .filter(|n| *n > T::default())
+        .unwrap_or_else(|| usage_error(&format!("Invalid value for {}: {}", option, value)))
+}
+
+/// Parses the value of `--timeout`: seconds, or `0` or `none` for no time limit.
+fn time_limit(value: &str) -> Option<Duration> {
+    if value == "none" {
+        return None;
+    }
+    // Anything that isn't a number is rejected along with negative, infinite and huge ones.
+    let seconds: f64 = value.parse().unwrap_or(f64::NAN);
+    if seconds == 0.0 {
+        return None;
+    }
+    Duration::try_from_secs_f64(seconds)
+        .map(Some)
+        .unwrap_or_else(|_| usage_error(&format!("Invalid value for --timeout: {}", value)))
+}
+
+/// Records the simulation of each part and writes it as an animation or as numbered frames.
+fn record(
+    solution: &dyn Runnable,
+    source: &InputSource,
+    parts: &[Part],
+    dir: &Path,
+    every: usize,
+    max_frames: usize,
+    frames: Option<RenderFormat>,
+) -> Result<(), String> {
+    let input = DayInput::load(solution, source);
+    for &part in parts {
+        let name = format!("day{:02}-part{}", solution.day(), part);
+        let recorder = match frames {
+            Some(format) => Recorder::to_frames(dir, &name, format, every),
+            None => Recorder::to_animation(&dir.join(format!("{}.anim", name)), every),
+        };
+        let mut recorder = recorder.with_max_frames(max_frames);
+        record_day(solution, &input, part, &mut recorder)?;
+        if recorder.kept() == 0 {
+            return Err(format!(
+                "Day {} has no step-by-step simulation to record",
+                solution.day()
+            ));
+        }
+        let (steps, kept, capped) = (recorder.steps(), recorder.kept(), recorder.capped());
+        let files = recorder.close().map_err(|e| e.to_string())?;
+        let written = match frames {
+            Some(_) => dir.display().to_string(),
+            None => files[0].display().to_string(),
+        };
+        eprintln!(
+            "Recorded {} steps of day {} part {}, wrote {} frames to {}",
+            steps,
+            solution.day(),
+            part,
+            kept,
+            written
+        );
+        if capped {
+            eprintln!(
+                "Stopped keeping steps after {} frames, raise --every or --max-frames to cover \
+                 the whole simulation",
+                max_frames
+            );
+        }
+    }
+    Ok(())
+}
+
+fn session() -> Result<String, String> {
+    session_token().ok_or_else(|| {
+        format!(
+            "No session token, set ${} or put it into the file named by ${}",
+            SESSION_VAR, SESSION_FILE_VAR
+        )
+    })
+}
+
+/// Downloads the inputs of the days that aren't in `dir` yet.
+fn fetch(days: &[u8], dir: &Path) -> Result<(), String> {
+    let mut client = Client::new(base_url(), session()?);
+    for &day in days {
+        match client.fetch_input(day, dir).map_err(|e| e.to_string())? {
+            Fetched::Cached(path) => eprintln!("Day {}: already in {}", day, path.display()),
+            Fetched::Downloaded(path) => eprintln!("Day {}: wrote {}", day, path.display()),
+        }
+    }
+    Ok(())
+}
+
+/// Prints every problem found in the inputs of the days, and returns how many there are.
+fn lint(days: &[Box<dyn Runnable>], input: Option<&str>) -> usize {
+    let mut problems = 0;
+    for solution in days {
+        let source = InputSource::resolve(solution.day(), input);
+        match lint_day(solution.as_ref(), &source) {
+            Ok(errors) => {
+                for error in &errors {
+                    println!("{}", error);
+                }
+                problems += errors.len();
+            }
+            Err(e) => {
+                println!("{}", e);
+                problems += 1;
+            }
+        }
+    }
+    eprintln!("Checked {} inputs, found {} problems", days.len(), problems);
+    problems
+}
+
+/// Submits the answer of the part, computed from the input unless given, and records what the
+/// website said in the answers file.
+fn submit(
+    solution: &dyn Runnable,
+    source: &InputSource,
+    part: Part,
+    answer: Option<String>,
+    answers_file: &str,
+    limit: Option<Duration>,
+) -> Result<Outcome, String> {
+    let day = solution.day();
+    let input = input_name(source, day)
+        .ok_or_else(|| "Answers read from stdin can't be recorded, use --input".to_string())?;
+    let mut answers = if Path::new(answers_file).exists() {
+        Answers::load(answers_file).map_err(|e| e.to_string())?
+    } else {
+        Answers::default()
+    };
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            let report = run_day(solution, source, &[part], limit);
+            let results = report.results?;
+            let result = results
+                .iter()
+                .find(|result| result.part == part)
+                .expect("The part was run");
+            if let Some(error) = result.error() {
+                return Err(format!("Part {} {}", part, error));
+            }
+            result.answer.clone()
+        }
+    };
+    let now = SystemTime::now();
+    submit::check(&answers, &input, day, part, &answer, now)
+        .map_err(|refusal| format!("Not submitting {}: {}", answer, refusal))?;
+
+    let outcome = Client::new(base_url(), session()?)
+        .submit(day, part, &answer)
+        .map_err(|e| e.to_string())?;
+    submit::record(&mut answers, &input, day, part, &answer, &outcome, now);
+    answers.save(answers_file).map_err(|e| e.to_string())?;
+    println!("Day {} part {}: {} is {}", day, part, answer, outcome);
+    Ok(outcome)
+}
+
+/// Prints the problems the validators found in the inputs, which were solved anyway.
+fn print_warnings(reports: &[DayReport]) {
+    for warning in reports.iter().flat_map(|report| &report.warnings) {
+        eprintln!("Warning: {}", warning);
+    }
+}
+
+/// Solves the days with the inputs of each of the `inputs` directories and prints them side by
+/// side. Exits with 1 if a day couldn't be solved with one of them or got a wrong answer, but not
+/// for slowdowns, which depend on the machine.
+fn run_matrix(
+    days: &[Box<dyn Runnable>],
+    inputs: &[String],
+    parts: &[Part],
+    limit: Option<Duration>,
+    answers: Option<&Answers>,
+    format: Format,
+) {
+    let dirs: Vec<PathBuf> = inputs.iter().map(PathBuf::from).collect();
+    let names: Vec<String> = inputs
+        .iter()
+        .map(|input| input.trim_end_matches('/').to_string())
+        .collect();
+    let matrices: Vec<DayMatrix> = days
+        .iter()
+        .map(|solution| {
+            let mut matrix = DayMatrix::run(solution.as_ref(), &dirs, parts, limit);
+            if let Some(answers) = answers {
+                matrix.verify(answers);
+            }
+            matrix
+        })
+        .collect();
+    let reports: Vec<DayReport> = matrices
+        .iter()
+        .flat_map(|matrix| matrix.reports.iter().cloned())
+        .collect();
+    match format {
+        Format::Text => {
+            print!("{}", matrix_table(&names, &matrices));
+            if answers.is_some() {
+                println!("\n{}", verification_summary(&reports));
+            }
+        }
+        Format::Csv => print!("{}", summary_csv(&reports)),
+        Format::Json => print!("{}", summary_json(&reports)),
+    }
+    print_warnings(&reports);
+    if matrices.iter().any(DayMatrix::has_errors) {
+        process::exit(1);
+    }
+}
+
+fn main() {
+    let mut input_args = Vec::new();
+    let mut verify = false;
+    let mut answers_file = None;
+    let mut bench_runs = None;
+    let mut format = Format::Text;
+    let mut render_dir = None;
+    let mut record_dir = None;
+    let mut every = 1;
+    let mut max_frames = DEFAULT_MAX_FRAMES;
+    let mut frames = None;
+    let mut replay_file = None;
+    let mut fps = 10.0;
+    let mut seed = None;
+    let mut size = None;
+    let mut timeout = None;
+    let mut config_file = None;
+    let mut param_args = Vec::new();
+    let mut positional = Vec::new();
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                println!(
+                    "\nWithout --input, inputs are read from ${} or inputs/.",
+                    INPUT_DIR_VAR
+                );
+                return;
+            }
+            "-i" | "--input" => {
+                let value = args
+                    .next()
+                    .unwrap_or_else(|| usage_error("Missing value for --input"));
+                input_args.push(value);
+            }
+            "--verify" => verify = true,
+            "--answers" => {
+                let value = args
+                    .next()
+                    .unwrap_or_else(|| usage_error("Missing value for --answers"));
+                answers_file = Some(value);
+            }
+            "--bench" => {
+                let value = args
+                    .next()
+                    .unwrap_or_else(|| usage_error("Missing value for --bench"));
+                bench_runs = Some(positive::<usize>("--bench", &value));
+            }
+            "--format" => {
+                let value = args
+                    .next()
+                    .unwrap_or_else(|| usage_error("Missing value for --format"));
+                format = match value.as_str() {
+                    "text" => Format::Text,
+                    "csv" => Format::Csv,
+                    "json" => Format::Json,
+                    _ => usage_error(&format!("Invalid format: {}", value)),
+                };
+            }
+            "--render" => {
+                let value = args
+                    .next()
+                    .unwrap_or_else(|| usage_error("Missing value for --render"));
+                render_dir = Some(PathBuf::from(value));
+            }
+            "--record" => {
+                let value = args
+                    .next()
+                    .unwrap_or_else(|| usage_error("Missing value for --record"));
+                record_dir = Some(PathBuf::from(value));
+            }
+            "--every" => {
+                let value = args
+                    .next()
+                    .unwrap_or_else(|| usage_error("Missing value for --every"));
+                every = positive("--every", &value);
+            }
+            "--max-frames" => {
+                let value = args
+                    .next()
+                    .unwrap_or_else(|| usage_error("Missing value for --max-frames"));
+                max_frames = positive("--max-frames", &value);
+            }
+            "--frames" => {
+                let value = args
+                    .next()
+                    .unwrap_or_else(|| usage_error("Missing value for --frames"));
+                let format = RenderFormat::ALL
+                    .into_iter()
+                    .find(|format| format.extension() == value)
+                    .unwrap_or_else(|| usage_error(&format!("Invalid frame format: {}", value)));
+                frames = Some(format);
+            }
+            "--replay" => {
+                let value = args
+                    .next()
+                    .unwrap_or_else(|| usage_error("Missing value for --replay"));
+                replay_file = Some(value);
+            }
+            "--fps" => {
+                let value = args
+                    .next()
+                    .unwrap_or_else(|| usage_error("Missing value for --fps"));
+                fps = positive("--fps", &value);
+            }
+            "--seed" => {
+                let value = args
+                    .next()
+                    .unwrap_or_else(|| usage_error("Missing value for --seed"));
+                let value = value.parse::<u64>().unwrap_or_else(|_| {
+                    usage_error(&format!("Invalid value for --seed: {}", value))
+                });
+                seed = Some(value);
+            }
+            "--size" => {
+                let value = args
+                    .next()
+                    .unwrap_or_else(|| usage_error("Missing value for --size"));
+                size = Some(positive::<usize>("--size", &value));
+            }
+            "--timeout" => {
+                let value = args
+                    .next()
+                    .unwrap_or_else(|| usage_error("Missing value for --timeout"));
+                timeout = Some(time_limit(&value));
+            }
+            "--config" => {
+                let value = args
+                    .next()
+                    .unwrap_or_else(|| usage_error("Missing value for --config"));
+                config_file = Some(value);
+            }
+            "-p" | "--param" => {
+                let value = args
+                    .next()
+                    .unwrap_or_else(|| usage_error("Missing value for --param"));
+                param_args.push(value);
+            }
+            _ => positional.push(arg),
+        }
+    }
+    let config = load_config(config_file.as_deref(), &param_args);
+    let input_arg = input_args.first().cloned();
+    if input_args.len() > 1 {
+        let subcommand = matches!(
+            positional.first().map(String::as_str),
+            Some("fetch" | "submit" | "lint" | "generate" | "compare" | "params")
+        );
+        if subcommand
+            || bench_runs.is_some()
+            || render_dir.is_some()
+            || record_dir.is_some()
+            || replay_file.is_some()
+        {
+            usage_error("Only running the days takes more than one --input");
+        }
+        if let Some(input) = input_args.iter().find(|input| !Path::new(input).is_dir()) {
+            usage_error(&format!(
+                "--input must be a directory when given more than once: {}",
+                input
+            ));
+        }
+    }
+
+    if positional.first().map(String::as_str) == Some("fetch") {
+        if verify || bench_runs.is_some() || render_dir.is_some() || record_dir.is_some() {
+            usage_error("fetch only takes --input and a DAY");
+        }
+        let days: Vec<u8> = match positional.get(1) {
+            None => solutions().iter().map(|solution| solution.day()).collect(),
+            Some(day) => vec![day
+                .parse()
+                .ok()
+                .filter(|day| (1..=25).contains(day))
+                .unwrap_or_else(|| usage_error(&format!("Invalid day: {}", day)))],
+        };
+        if positional.len() > 2 {
+            usage_error("Too many arguments");
+        }
+        let dir = input_arg.map_or_else(input_dir, PathBuf::from);
+        if let Err(e) = fetch(&days, &dir) {
+            eprintln!("Error: {}", e);
+            process::exit(1);
+        }
+        return;
+    }
+    if positional.first().map(String::as_str) == Some("submit") {
+        if verify || bench_runs.is_some() || render_dir.is_some() || record_dir.is_some() {
+            usage_error(
+                "submit only takes --input, --answers, --timeout, a DAY, a PART and an ANSWER",
+            );
+        }
+        let (Some(day), Some(part)) = (positional.get(1), positional.get(2)) else {
+            usage_error("submit needs a DAY and a PART");
+        };
+        if positional.len() > 4 {
+            usage_error("Too many arguments");
+        }
+        let days = configure(vec![day_solution(day)], &config);
+        let solution = &days[0];
+        let part = part.parse().unwrap_or_else(|e: String| usage_error(&e));
+        let source = InputSource::resolve(solution.day(), input_arg.as_deref());
+        let answers_file = answers_file.as_deref().unwrap_or(DEFAULT_ANSWERS_FILE);
+        let answer = positional.get(3).cloned();
+        let limit = timeout.unwrap_or(Some(DEFAULT_TIME_LIMIT));
+        match submit(
+            solution.as_ref(),
+            &source,
+            part,
+            answer,
+            answers_file,
+            limit,
+        ) {
+            Ok(Outcome::Correct) => return,
+            Ok(_) => process::exit(1),
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                process::exit(1);
+            }
+        }
+    }
+    if positional.first().map(String::as_str) == Some("lint") {
+        if verify || bench_runs.is_some() || render_dir.is_some() || record_dir.is_some() {
+            usage_error("lint only takes --input and a DAY");
+        }
+        if positional.len() > 2 {
+            usage_error("Too many arguments");
+        }
+        let days = match positional.get(1) {
+            None => solutions(),
+            Some(day) => vec![day_solution(day)],
+        };
+        let days = configure(days, &config);
+        if let Some(input) = &input_arg {
+            if days.len() > 1 && !Path::new(input).is_dir() {
+                usage_error("--input must be a directory when linting all days");
+            }
+        }
+        if lint(&days, input_arg.as_deref()) > 0 {
+            process::exit(1);
+        }
+        return;
+    }
+    if positional.first().map(String::as_str) == Some("generate") {
+        let [_, day] = positional.as_slice() else {
+            usage_error("generate needs a DAY");
+        };
+        let day: u8 = day
+            .parse()
+            .unwrap_or_else(|_| usage_error(&format!("Invalid day: {}", day)));
+        let generator = generator(day).unwrap_or_else(|| {
+            let days: Vec<String> = GENERATORS.iter().map(|g| g.day.to_string()).collect();
+            usage_error(&format!(
+                "No generator for day {}, there are generators for days {}",
+                day,
+                days.join(", ")
+            ))
+        });
+        let size = size.unwrap_or(generator.default_size);
+        print!("{}", generator.generate(seed.unwrap_or(0), size));
+        eprintln!("Generated day {} with {} {}", day, size, generator.size);
+        return;
+    }
+    if positional.first().map(String::as_str) == Some("compare") {
+        if verify
+            || render_dir.is_some()
+            || record_dir.is_some()
+            || timeout.is_some()
+            || format != Format::Text
+        {
+            usage_error("compare only takes --input, --bench, a DAY and a PART");
+        }
+        if positional.len() > 3 {
+            usage_error("Too many arguments");
+        }
+        let days: Vec<_> = match positional.get(1) {
+            None => solutions()
+                .into_iter()
+                .filter(|solution| {
+                    Part::ALL
+                        .iter()
+                        .any(|&part| solution.implementations(part).len() > 1)
+                })
+                .collect(),
+            Some(day) => vec![day_solution(day)],
+        };
+        let days = configure(days, &config);
+        let parts = match positional.get(2) {
+            None => Part::ALL.to_vec(),
+            Some(part) => vec![part.parse().unwrap_or_else(|e: String| usage_error(&e))],
+        };
+        if let Some(input) = &input_arg {
+            if days.len() > 1 && !Path::new(input).is_dir() {
+                usage_error("--input must be a directory when comparing all days");
+            }
+        }
+        let reports: Vec<_> = days
+            .iter()
+            .map(|solution| {
+                let source = InputSource::resolve(solution.day(), input_arg.as_deref());
+                compare_day(solution.as_ref(), &source, &parts, bench_runs.unwrap_or(3))
+            })
+            .collect();
+        print!("{}", compare_table(&reports));
+        let mut failed = false;
+        for report in &reports {
+            failed |= report.results.is_err();
+            for result in report.disagreements() {
+                eprintln!(
+                    "Day {} part {}: {} gives {}, which disagrees with the main implementation",
+                    report.day, result.part, result.name, result.answer
+                );
+                failed = true;
+            }
+        }
+        if failed {
+            process::exit(1);
+        }
+        return;
+    }
+    if positional.first().map(String::as_str) == Some("params") {
+        if positional.len() > 2 {
+            usage_error("Too many arguments");
+        }
+        let days = match positional.get(1) {
+            None => solutions(),
+            Some(day) => vec![day_solution(day)],
+        };
+        print!("{}", params_table(&configure(days, &config)));
+        return;
+    }
+    if seed.is_some() || size.is_some() {
+        usage_error("--seed and --size only apply to generate");
+    }
+    if positional.len() > 2 {
+        usage_error("Too many arguments");
+    }
+
+    if let Some(file) = replay_file {
+        if !positional.is_empty() || record_dir.is_some() {
+            usage_error("--replay only takes the file to play");
+        }
+        let text = fs::read_to_string(&file).unwrap_or_else(|e| {
+            eprintln!("Error: {}: {}", file, e);
+            process::exit(1);
+        });
+        let animation = parse_animation(&text).unwrap_or_else(|e| {
+            eprintln!("Error: {}", e.in_file(&file));
+            process::exit(1);
+        });
+        if let Err(e) = replay(&animation, fps, &mut io::stdout().lock()) {
+            eprintln!("Error: {}", e);
+            process::exit(1);
+        }
+        return;
+    }
+    if record_dir.is_some() && (verify || bench_runs.is_some() || render_dir.is_some()) {
+        usage_error("--record can't be combined with --verify, --bench or --render");
+    }
+    if record_dir.is_some() && positional.is_empty() {
+        usage_error("--record needs a DAY");
+    }
+    if verify && bench_runs.is_some() {
+        usage_error("--verify can't be combined with --bench");
+    }
+    if render_dir.is_some() && bench_runs.is_some() {
+        usage_error("--render can't be combined with --bench");
+    }
+    if timeout.is_some() && record_dir.is_some() {
+        usage_error("--timeout can't be combined with --record");
+    }
+    let limit = timeout.unwrap_or(Some(DEFAULT_TIME_LIMIT));
+
+    let days = match positional.first() {
+        None => solutions(),
+        Some(day) => vec![day_solution(day)],
+    };
+    let days = configure(days, &config);
+    let parts = match positional.get(1) {
+        None => Part::ALL.to_vec(),
+        Some(part) => vec![part.parse().unwrap_or_else(|e: String| usage_error(&e))],
+    };
+    if let Some(input) = &input_arg {
+        if days.len() > 1 && !Path::new(input).is_dir() {
+            usage_error("--input must be a directory when running all days");
+        }
+    }
+
+    if let Some(dir) = &record_dir {
+        let solution = &days[0];
+        let source = InputSource::resolve(solution.day(), input_arg.as_deref());
+        if let Err(e) = record(
+            solution.as_ref(),
+            &source,
+            &parts,
+            dir,
+            every,
+            max_frames,
+            frames,
+        ) {
+            eprintln!("Error: {}", e);
+            process::exit(1);
+        }
+        return;
+    }
+
+    if let Some(runs) = bench_runs {
+        let reports: Vec<_> = days
+            .iter()
+            .map(|solution| {
+                let source = InputSource::resolve(solution.day(), input_arg.as_deref());
+                bench_day(solution.as_ref(), &source, &parts, runs, limit)
+            })
+            .collect();
+        match format {
+            Format::Text => print!("{}", bench_table(&reports)),
+            Format::Csv => print!("{}", bench_csv(&reports)),
+            Format::Json => print!("{}", bench_json(&reports)),
+        }
+        if reports.iter().any(BenchReport::has_errors) {
+            process::exit(1);
+        }
+        return;
+    }
+
+    let answers = verify.then(|| {
+        let file = answers_file.as_deref().unwrap_or(DEFAULT_ANSWERS_FILE);
+        Answers::load(file).unwrap_or_else(|e| {
+            eprintln!("Error: {}", e);
+            process::exit(1);
+        })
+    });
+
+    if input_args.len() > 1 {
+        run_matrix(&days, &input_args, &parts, limit, answers.as_ref(), format);
+        return;
+    }
+
+    // Each input is read once, since rendering uses it again and stdin can't be read twice.
+    let inputs: Vec<_> = days
+        .iter()
+        .map(|solution| {
+            let source = InputSource::resolve(solution.day(), input_arg.as_deref());
+            DayInput::load(solution.as_ref(), &source)
+        })
+        .collect();
+    let reports: Vec<_> = days
+        .iter()
+        .zip(&inputs)
+        .map(|(solution, input)| {
+            let mut report = solve_day(solution.as_ref(), input, &parts, limit);
+            if let Some(answers) = &answers {
+                report.verify(
+                    answers,
+                    input_name(&input.source, solution.day()).as_deref(),
+                );
+            }
+            report
+        })
+        .collect();
+    match format {
+        Format::Text => {
+            print!("{}", summary_table(&reports));
+            if answers.is_some() {
+                println!("\n{}", verification_summary(&reports));
+            }
+        }
+        Format::Csv => print!("{}", summary_csv(&reports)),
+        Format::Json => print!("{}", summary_json(&reports)),
+    }
+    print_warnings(&reports);
+
+    let mut render_failed = false;
+    if let Some(dir) = &render_dir {
+        let mut num_files = 0;
+        for (solution, input) in days.iter().zip(&inputs) {
+            match render_day(solution.as_ref(), input, dir) {
+                Ok(files) => num_files += files.len(),
+                Err(e) => {
+                    eprintln!("Error rendering day {}: {}", solution.day(), e);
+                    render_failed = true;
+                }
+            }
+        }
+        eprintln!("Wrote {} files to {}", num_files, dir.display());
+    }
+
+    let failed = reports
+        .iter()
+        .flat_map(|report| report.verdicts())
+        .any(|verdict| matches!(verdict, Verdict::Fail { .. }));
+    let errors = reports
+        .iter()
+        .any(|report| report.results.is_err() || report.has_failures());
+    if failed || render_failed || errors {
+        process::exit(1);
+    }
+}
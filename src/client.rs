@@ -0,0 +1,194 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    error::{Error, Result},
+    solution::Part,
+    submit::Outcome,
+    utils::input::input_file_name,
+};
+
+/// Year of the puzzles, part of every URL on the website.
+pub const YEAR: u16 = 2024;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Environment variable overriding the website, e.g. to point at a local server in tests.
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+/// Environment variable holding the value of the `session` cookie of a logged in browser.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Environment variable pointing to a file with the session token, instead of `SESSION_VAR`.
+pub const SESSION_FILE_VAR: &str = "AOC_SESSION_FILE";
+
+/// Minimum time between two requests of a client, to go easy on the website.
+pub const DEFAULT_THROTTLE: Duration = Duration::from_secs(5);
+
+const USER_AGENT: &str = concat!("aoc2024/", env!("CARGO_PKG_VERSION"));
+
+/// The base URL of the website, from `$AOC_BASE_URL` if set.
+pub fn base_url() -> String {
+    env::var(BASE_URL_VAR).unwrap_or_else(|_| DEFAULT_BASE_URL.to_string())
+}
+
+/// Default location of the session token file, `~/.config/aoc/session`.
+fn default_session_file() -> Option<PathBuf> {
+    let config = env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".config")))?;
+    Some(config.join("aoc").join("session"))
+}
+
+/// The session token from `$AOC_SESSION`, or else from the file named by `$AOC_SESSION_FILE`
+/// (default `~/.config/aoc/session`).
+pub fn session_token() -> Option<String> {
+    let token = match env::var(SESSION_VAR) {
+        Ok(token) => token,
+        Err(_) => {
+            let file = env::var_os(SESSION_FILE_VAR)
+                .map(PathBuf::from)
+                .or_else(default_session_file)?;
+            fs::read_to_string(file).ok()?
+        }
+    };
+    let token = token.trim();
+    (!token.is_empty()).then(|| token.to_string())
+}
+
+/// A logged in connection to the puzzle website, making at most one request per throttle
+/// interval.
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+    throttle: Duration,
+    last_request: Option<Instant>,
+}
+
+impl Client {
+    pub fn new(base_url: impl Into<String>, session: impl Into<String>) -> Self {
+        let base_url: String = base_url.into();
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.into(),
+            agent: ureq::AgentBuilder::new()
+                .timeout(Duration::from_secs(30))
+                .user_agent(USER_AGENT)
+                .build(),
+            throttle: DEFAULT_THROTTLE,
+            last_request: None,
+        }
+    }
+
+    /// Sets the minimum time between two requests.
+    pub fn with_throttle(mut self, throttle: Duration) -> Self {
+        self.throttle = throttle;
+        self
+    }
+
+    /// Sleeps until the throttle interval since the previous request has passed.
+    fn wait_turn(&mut self) {
+        if let Some(last) = self.last_request {
+            let elapsed = last.elapsed();
+            if elapsed < self.throttle {
+                thread::sleep(self.throttle - elapsed);
+            }
+        }
+        self.last_request = Some(Instant::now());
+    }
+
+    /// Sends a GET request, or a POST request with `form` as body, and returns the body of the
+    /// response. Responses with an error status are errors.
+    fn request(&mut self, path: &str, form: Option<&[(&str, &str)]>) -> Result<String> {
+        self.wait_turn();
+        let url = format!("{}{}", self.base_url, path);
+        let method = if form.is_some() { "POST" } else { "GET" };
+        let request = self
+            .agent
+            .request(method, &url)
+            .set("Cookie", &format!("session={}", self.session));
+        let response = match form {
+            Some(form) => request.send_form(form),
+            None => request.call(),
+        };
+        match response {
+            Ok(response) => response
+                .into_string()
+                .map_err(|e| Error::http(&url, e.to_string())),
+            Err(ureq::Error::Status(status, response)) => {
+                let body = response.into_string().unwrap_or_default();
+                let reason = match body.lines().next().map(str::trim) {
+                    Some(line) if !line.is_empty() => format!("{} ({})", status, line),
+                    _ => status.to_string(),
+                };
+                Err(Error::http(&url, reason))
+            }
+            Err(e) => Err(Error::http(&url, e.to_string())),
+        }
+    }
+
+    fn get(&mut self, path: &str) -> Result<String> {
+        self.request(path, None)
+    }
+
+    /// Submits `answer` for the part and returns what the website made of it.
+    ///
+    /// This always sends the answer; check it against the registry with
+    /// [`submit::check`](crate::submit::check) first.
+    pub fn submit(&mut self, day: u8, part: Part, answer: &str) -> Result<Outcome> {
+        let path = format!("/{}/day/{}/answer", YEAR, day);
+        let level = part.to_string();
+        let page = self.request(&path, Some(&[("level", &level), ("answer", answer)]))?;
+        Outcome::parse(&page).ok_or_else(|| {
+            Error::http(
+                format!("{}{}", self.base_url, path),
+                "Unexpected response to the answer",
+            )
+        })
+    }
+
+    /// Downloads the puzzle input of `day`.
+    pub fn input(&mut self, day: u8) -> Result<String> {
+        self.get(&format!("/{}/day/{}/input", YEAR, day))
+    }
+
+    /// Makes sure the input of `day` is in `dir` as `dayNN.txt`, downloading it only if it isn't
+    /// there yet.
+    ///
+    /// The input is written to a temporary file first, so an interrupted download is never
+    /// mistaken for a cached input.
+    pub fn fetch_input(&mut self, day: u8, dir: &Path) -> Result<Fetched> {
+        let path = dir.join(input_file_name(day));
+        if path.exists() {
+            return Ok(Fetched::Cached(path));
+        }
+        let input = self.input(day)?;
+        fs::create_dir_all(dir).map_err(|e| Error::io(dir.display(), e))?;
+        let partial = path.with_extension("txt.part");
+        fs::write(&partial, input)
+            .and_then(|_| fs::rename(&partial, &path))
+            .map_err(|e| Error::io(path.display(), e))?;
+        Ok(Fetched::Downloaded(path))
+    }
+}
+
+/// Where [`Client::fetch_input`] found the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fetched {
+    /// The input was already there and wasn't downloaded again.
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+impl Fetched {
+    pub fn path(&self) -> &Path {
+        match self {
+            Fetched::Cached(path) | Fetched::Downloaded(path) => path,
+        }
+    }
+}
@@ -0,0 +1,322 @@
+use std::path::Path;
+
+use crate::{
+    answers::{strip_comment, unquote},
+    error::{Error, Result},
+    solution::Runnable,
+    utils::{file::read_lines, table::format_table},
+};
+
+/// Default location of the parameter config file, used if it exists.
+pub const DEFAULT_CONFIG_FILE: &str = "aoc.toml";
+
+/// Values for the puzzle parameters of the days, see [`Solution::params`].
+///
+/// The config file is a small subset of TOML with one table per day:
+///
+/// ```toml
+/// [day14]
+/// width = 11
+/// height = 7
+/// ```
+///
+/// Values can be integers or strings, and are parsed into the type of the parameter when they are
+/// applied. Settings added with [`Config::set_arg`] come after those of the file, so they
+/// override them.
+///
+/// [`Solution::params`]: crate::solution::Solution::params
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Config {
+    /// The file the settings were loaded from, for error messages.
+    file: Option<String>,
+    settings: Vec<Setting>,
+}
+
+/// A value given to a parameter.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Setting {
+    /// The day the parameter belongs to, or `None` for every day with a parameter of that name.
+    pub day: Option<u8>,
+    pub name: String,
+    pub value: String,
+    /// Line of the config file the value is set on, `None` if it was given on the command line.
+    pub line: Option<usize>,
+}
+
+impl Config {
+    pub fn parse(text: &str) -> Result<Self> {
+        let mut config = Config::default();
+        let mut day = None;
+        for (i, line) in text.lines().enumerate() {
+            let line = strip_comment(line).trim();
+            if line.is_empty() {
+                continue;
+            }
+            let result = if let Some(header) = line.strip_prefix('[') {
+                parse_table_header(header).map(|header| day = Some(header))
+            } else {
+                day.ok_or_else(|| Error::parse("Parameter outside of a [dayNN] table"))
+                    .and_then(|day| parse_setting(line).map(|setting| (day, setting)))
+                    .map(|(day, (name, value))| {
+                        config.settings.push(Setting {
+                            day: Some(day),
+                            name,
+                            value,
+                            line: Some(i + 1),
+                        })
+                    })
+            };
+            result.map_err(|e| e.at_line(i + 1))?;
+        }
+        Ok(config)
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let mut config = Self::parse(&read_lines(path)?).map_err(|e| e.in_file(path.display()))?;
+        config.file = Some(path.display().to_string());
+        Ok(config)
+    }
+
+    /// Adds a `NAME=VALUE` or `dayNN.NAME=VALUE` setting given on the command line.
+    ///
+    /// Without a day, the value is given to every day that has a parameter called `NAME`.
+    pub fn set_arg(&mut self, arg: &str) -> Result<()> {
+        let (key, value) = parse_setting(arg)?;
+        let (day, name) = match key.split_once('.') {
+            Some((day, name)) => (Some(parse_day(day)?), name.to_string()),
+            None => (None, key),
+        };
+        self.settings.push(Setting {
+            day,
+            name,
+            value,
+            line: None,
+        });
+        Ok(())
+    }
+
+    pub fn settings(&self) -> &[Setting] {
+        &self.settings
+    }
+
+    /// Sets the parameters of `days`, in the order they were given.
+    ///
+    /// Settings for days not in `days` are skipped. A setting without a day fails if none of
+    /// `days` has a parameter of that name.
+    pub fn apply(&self, days: &mut [Box<dyn Runnable>]) -> Result<()> {
+        for setting in &self.settings {
+            let locate = |e: Error| match (setting.line, &self.file) {
+                (Some(line), Some(file)) => e.at_line(line).in_file(file),
+                (Some(line), None) => e.at_line(line),
+                (None, _) => e,
+            };
+            let has_param = |solution: &dyn Runnable| {
+                solution
+                    .params()
+                    .iter()
+                    .any(|param| param.name == setting.name)
+            };
+            match setting.day {
+                Some(day) => {
+                    if let Some(solution) = days.iter_mut().find(|s| s.day() == day) {
+                        solution
+                            .set_param(&setting.name, &setting.value)
+                            .map_err(locate)?;
+                    }
+                }
+                None => {
+                    if !days.iter().any(|solution| has_param(solution.as_ref())) {
+                        return Err(match days {
+                            // The error of the day lists the parameters it does have.
+                            [solution] => solution
+                                .set_param(&setting.name, &setting.value)
+                                .expect_err("Day has no such parameter"),
+                            _ => Error::parse(format!("No day has a parameter '{}'", setting.name)),
+                        });
+                    }
+                    for solution in days.iter_mut() {
+                        if has_param(solution.as_ref()) {
+                            solution.set_param(&setting.name, &setting.value)?;
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Formats the parameters of the days that have any as a table, with their current values.
+pub fn params_table(days: &[Box<dyn Runnable>]) -> String {
+    let rows: Vec<Vec<String>> = days
+        .iter()
+        .flat_map(|solution| {
+            solution.params().into_iter().map(|param| {
+                vec![
+                    solution.day().to_string(),
+                    solution.title().to_string(),
+                    param.name.to_string(),
+                    param.value,
+                    param.description.to_string(),
+                ]
+            })
+        })
+        .collect();
+    format_table(
+        &["Day", "Title", "Parameter", "Value", "Description"],
+        &rows,
+    )
+}
+
+/// Parses the rest of a `[dayNN]` line.
+fn parse_table_header(header: &str) -> Result<u8> {
+    let header = header
+        .strip_suffix(']')
+        .ok_or_else(|| Error::parse("Missing ']' at the end of the table header"))?;
+    parse_day(header.trim())
+}
+
+fn parse_day(day: &str) -> Result<u8> {
+    day.strip_prefix("day")
+        .and_then(|day| day.parse().ok())
+        .filter(|day| (1..=25).contains(day))
+        .ok_or_else(|| Error::parse(format!("Expected dayNN, got '{}'", day)))
+}
+
+/// Splits a `name = value` setting, with the value unquoted if it is a string.
+fn parse_setting(line: &str) -> Result<(String, String)> {
+    let (name, value) = line
+        .split_once('=')
+        .ok_or_else(|| Error::parse(format!("Expected 'name = value', got '{}'", line)))?;
+    let (name, value) = (name.trim(), value.trim());
+    if name.is_empty() || value.is_empty() {
+        return Err(Error::parse(format!(
+            "Expected 'name = value', got '{}'",
+            line
+        )));
+    }
+    let value = unquote(value).unwrap_or_else(|| value.to_string());
+    Ok((name.to_string(), value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        runner::run_day,
+        solution::{solution, solutions, Part},
+        utils::input::InputSource,
+    };
+
+    fn param(solution: &dyn Runnable, name: &str) -> String {
+        let params = solution.params();
+        let param = params.iter().find(|param| param.name == name).unwrap();
+        param.value.clone()
+    }
+
+    #[test]
+    fn test_parse_and_apply() {
+        let config = Config::parse(
+            "# What if the room were smaller?\n\
+             [day14]\n\
+             width = 11 # columns\n\
+             height = \"7\"\n\
+             \n\
+             [day20]\n\
+             min_speedup = 50\n",
+        )
+        .unwrap();
+        assert_eq!(config.settings().len(), 3);
+        let mut days = vec![solution(14).unwrap()];
+        config.apply(&mut days).unwrap();
+        assert_eq!(param(days[0].as_ref(), "width"), "11");
+        assert_eq!(param(days[0].as_ref(), "height"), "7");
+        assert_eq!(param(days[0].as_ref(), "seconds"), "100");
+
+        let err = Config::parse("width = 11\n").unwrap_err();
+        assert_eq!(err.to_string(), "1: Parameter outside of a [dayNN] table");
+        let err = Config::parse("[day26]\n").unwrap_err();
+        assert_eq!(err.to_string(), "1: Expected dayNN, got 'day26'");
+        let err = Config::parse("[day14]\nwidth\n").unwrap_err();
+        assert_eq!(err.to_string(), "2: Expected 'name = value', got 'width'");
+
+        let config = Config::parse("[day14]\nwidth = 11\nwidht = 12\n").unwrap();
+        let err = config.apply(&mut days).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "3: Day 14 has no parameter 'widht', it has width, height, seconds"
+        );
+        let config = Config::parse("[day14]\nseconds = soon\n").unwrap();
+        let err = config.apply(&mut days).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "2: Invalid value for parameter 'seconds' of day 14: Expected i64, got 'soon'"
+        );
+    }
+
+    #[test]
+    fn test_set_arg() {
+        let mut config = Config::default();
+        config.set_arg("day18.memory_size=7").unwrap();
+        config.set_arg("min_speedup = 50").unwrap();
+        assert!(config.set_arg("day18.memory_size").is_err());
+        assert!(config.set_arg("18.memory_size=7").is_err());
+
+        let mut days = solutions();
+        config.apply(&mut days).unwrap();
+        assert_eq!(param(days[17].as_ref(), "memory_size"), "7");
+        assert_eq!(param(days[19].as_ref(), "min_speedup"), "50");
+        let table = params_table(&days);
+        let row = table
+            .lines()
+            .find(|row| row.contains("memory_size"))
+            .unwrap();
+        let cells: Vec<&str> = row.split(" | ").map(str::trim).collect();
+        assert_eq!(
+            cells,
+            [
+                "18",
+                "RAM Run",
+                "memory_size",
+                "7",
+                "Width and height of the memory space"
+            ]
+        );
+
+        let mut config = Config::default();
+        config.set_arg("blinks=3").unwrap();
+        let err = config.apply(&mut days).unwrap_err();
+        assert_eq!(err.to_string(), "No day has a parameter 'blinks'");
+        let err = config.apply(&mut [solution(11).unwrap()]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Day 11 has no parameter 'blinks', it has part1_blinks, part2_blinks"
+        );
+        let err = config.apply(&mut [solution(1).unwrap()]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Day 1 has no parameter 'blinks', it has none"
+        );
+    }
+
+    #[test]
+    fn test_example_config() {
+        // The examples of the puzzles that use smaller parameters run with examples/aoc.toml.
+        let mut days = solutions();
+        Config::load("examples/aoc.toml")
+            .unwrap()
+            .apply(&mut days)
+            .unwrap();
+        for (day, file, part, answer) in [
+            (14, "day14.txt", Part::One, "12"),
+            (18, "day18.txt", Part::One, "22"),
+            (18, "day18.txt", Part::Two, "6,1"),
+            (20, "day20.txt", Part::Two, "285"),
+        ] {
+            let source = InputSource::File(Path::new("examples").join(file));
+            let report = run_day(days[day - 1].as_ref(), &source, &[part], None);
+            assert_eq!(report.answer(part), Some(answer), "day {}", day);
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use std::{collections::HashMap, fmt::Display};
+
+use crate::{
+    error::{Error, Result},
+    solution::Solution,
+    utils::{
+        parse::{list, parse_lines},
+        validate,
+    },
+};
+
+pub struct Day01;
+
+fn parse_two_integers(line: &str) -> Result<(i32, i32)> {
+    match list(line, " ")?[..] {
+        [first, second] => Ok((first, second)),
+        _ => Err(Error::parse("Expected two numbers")),
+    }
+}
+
+pub fn extract_numbers(input: &str) -> Result<(Vec<i32>, Vec<i32>)> {
+    let (mut left_numbers, mut right_numbers): (Vec<_>, Vec<_>) =
+        parse_lines(input, parse_two_integers)?.into_iter().unzip();
+    left_numbers.sort();
+    right_numbers.sort();
+    Ok((left_numbers, right_numbers))
+}
+
+pub fn compute_total_distance(left_numbers: &[i32], right_numbers: &[i32]) -> i32 {
+    let mut total_dist = 0;
+    for (left, right) in left_numbers.iter().zip(right_numbers.iter()) {
+        total_dist += (right - left).abs();
+    }
+    total_dist
+}
+
+pub fn compute_similarity_score(left_numbers: &[i32], right_numbers: &[i32]) -> i32 {
+    let mut right_numbers_count = HashMap::new();
+    for &number in right_numbers {
+        *right_numbers_count.entry(number).or_insert(0) += 1;
+    }
+    let mut similarity_score = 0;
+    for &number in left_numbers {
+        let count = right_numbers_count.get(&number).unwrap_or(&0);
+        similarity_score += count * number;
+    }
+    similarity_score
+}
+
+impl Solution for Day01 {
+    type Input = (Vec<i32>, Vec<i32>);
+
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Historian Hysteria";
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        extract_numbers(input)
+    }
+
+    fn validate(&self, input: &str) -> Vec<Error> {
+        let mut errors = validate::non_empty(input);
+        errors.extend(validate::each_line(input, parse_two_integers));
+        errors
+    }
+
+    fn part1(&self, (left_numbers, right_numbers): &Self::Input) -> impl Display {
+        compute_total_distance(left_numbers, right_numbers)
+    }
+
+    fn part2(&self, (left_numbers, right_numbers): &Self::Input) -> impl Display {
+        compute_similarity_score(left_numbers, right_numbers)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::{example_answer, Part};
+
+    #[test]
+    fn test_example() {
+        let day = Day01;
+        assert_eq!(example_answer(&day, "day01.txt", Part::One), "11");
+        assert_eq!(example_answer(&day, "day01.txt", Part::Two), "31");
+    }
+
+    #[test]
+    fn test_validate() {
+        let errors = Day01.validate("3   4\n4 x\n1 2 3\n");
+        let messages: Vec<String> = errors.iter().map(Error::to_string).collect();
+        assert_eq!(
+            messages,
+            ["2:3: Invalid number 'x'", "3: Expected two numbers"]
+        );
+        assert_eq!(Day01.validate("").len(), 1);
+    }
+}
@@ -0,0 +1,102 @@
+use std::fmt::Display;
+
+use crate::{
+    error::{Error, Result},
+    solution::Solution,
+    utils::{
+        parse::{list, parse_lines},
+        validate,
+    },
+};
+
+pub struct Day02;
+
+/// Whether the levels all increase or all decrease, by 1 to 3 at each step.
+///
+/// Reports with fewer than two levels are trivially safe.
+pub fn is_safe_1(levels: &[i32]) -> bool {
+    let diffs: Vec<i32> = levels.windows(2).map(|w| w[1] - w[0]).collect();
+    diffs.iter().all(|d| (1..=3).contains(d)) || diffs.iter().all(|d| (-3..=-1).contains(d))
+}
+
+pub fn is_safe_2(levels: &[i32]) -> bool {
+    if is_safe_1(levels) {
+        true
+    } else {
+        for i in 0..levels.len() {
+            let mut new_levels = levels.to_vec();
+            new_levels.remove(i);
+            if is_safe_1(&new_levels) {
+                return true;
+            }
+        }
+        false
+    }
+}
+
+impl Solution for Day02 {
+    type Input = Vec<Vec<i32>>;
+
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Red-Nosed Reports";
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parse_lines(input, |line| list(line, " "))
+    }
+
+    fn validate(&self, input: &str) -> Vec<Error> {
+        let mut errors = validate::non_empty(input);
+        errors.extend(validate::each_line(input, |line| {
+            let levels: Vec<i32> = list(line, " ")?;
+            if levels.is_empty() {
+                return Err(Error::parse("Empty report"));
+            }
+            Ok(levels)
+        }));
+        errors
+    }
+
+    fn part1(&self, reports: &Self::Input) -> impl Display {
+        reports
+            .iter()
+            .map(|report| if is_safe_1(report) { 1 } else { 0 })
+            .sum::<i32>()
+    }
+
+    fn part2(&self, reports: &Self::Input) -> impl Display {
+        reports
+            .iter()
+            .map(|report| if is_safe_2(report) { 1 } else { 0 })
+            .sum::<i32>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::{example_answer, Part};
+
+    #[test]
+    fn test_example() {
+        let day = Day02;
+        assert_eq!(example_answer(&day, "day02.txt", Part::One), "2");
+        assert_eq!(example_answer(&day, "day02.txt", Part::Two), "4");
+    }
+
+    #[test]
+    fn test_short_reports() {
+        assert!(is_safe_1(&[5]));
+        assert!(is_safe_1(&[1, 4]));
+        assert!(!is_safe_1(&[1, 5]));
+        assert!(is_safe_2(&[1, 5]));
+        assert!(!is_safe_2(&[1, 5, 9]));
+    }
+
+    #[test]
+    fn test_validate() {
+        let errors = Day02.validate("1 2 3\n\n4 -x\n");
+        let messages: Vec<String> = errors.iter().map(Error::to_string).collect();
+        assert_eq!(messages, ["2: Empty report", "3:3: Invalid number '-x'"]);
+        assert_eq!(Day02.validate("").len(), 1);
+    }
+}
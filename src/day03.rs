@@ -0,0 +1,128 @@
+use std::fmt::Display;
+
+use regex::Regex;
+
+use crate::{
+    error::{Error, Result},
+    solution::Solution,
+    utils::validate,
+};
+
+pub struct Day03;
+
+pub enum Instr {
+    Mul(i32, i32),
+    Do,
+    Dont,
+}
+
+fn parse_instruction(cap: regex::Captures) -> Instr {
+    if cap.get(1).is_some() {
+        let first = cap[2].parse::<i32>().expect("Failed to parse first number");
+        let second = cap[3]
+            .parse::<i32>()
+            .expect("Failed to parse second number");
+        Instr::Mul(first, second)
+    } else if cap.get(4).is_some() {
+        Instr::Do
+    } else if cap.get(5).is_some() {
+        Instr::Dont
+    } else {
+        panic!("Invalid instruction")
+    }
+}
+
+pub fn extract_instructions(text: &str) -> Vec<Instr> {
+    let re = Regex::new(r"(mul)\((\d{1,3}),(\d{1,3})\)|(do)\(\)|(don't)\(\)")
+        .expect("Invalid regex pattern");
+    re.captures_iter(text).map(parse_instruction).collect()
+}
+
+pub fn exec_all_mul_instructions(instructions: &[Instr]) -> Vec<i32> {
+    instructions
+        .iter()
+        .filter_map(|instr| match instr {
+            Instr::Mul(first, second) => Some((first, second)),
+            _ => None,
+        })
+        .map(|(first, second)| first * second)
+        .collect()
+}
+
+pub fn exec_enabled_mul_instructions(instructions: &[Instr]) -> Vec<i32> {
+    let mut enabled = true;
+    let mut results = Vec::new();
+    for instr in instructions {
+        match instr {
+            Instr::Do => enabled = true,
+            Instr::Dont => enabled = false,
+            Instr::Mul(first, second) => {
+                if enabled {
+                    results.push(first * second);
+                }
+            }
+        }
+    }
+    results
+}
+
+fn sum_items(items: Vec<i32>) -> i32 {
+    items.iter().sum()
+}
+
+impl Solution for Day03 {
+    type Input = Vec<Instr>;
+
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Mull It Over";
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(extract_instructions(input))
+    }
+
+    fn validate(&self, input: &str) -> Vec<Error> {
+        let mut errors = validate::non_empty(input);
+        let has_mul = extract_instructions(input)
+            .iter()
+            .any(|instr| matches!(instr, Instr::Mul(..)));
+        if errors.is_empty() && !has_mul {
+            errors.push(Error::parse("No mul(X,Y) instructions found"));
+        }
+        errors
+    }
+
+    fn part1(&self, instructions: &Self::Input) -> impl Display {
+        sum_items(exec_all_mul_instructions(instructions))
+    }
+
+    fn part2(&self, instructions: &Self::Input) -> impl Display {
+        sum_items(exec_enabled_mul_instructions(instructions))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::{example_answer, Part};
+
+    #[test]
+    fn test_example() {
+        let day = Day03;
+        assert_eq!(example_answer(&day, "day03.txt", Part::One), "161");
+        assert_eq!(example_answer(&day, "day03.txt", Part::Two), "48");
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(Day03.validate("xmul(2,4)don't()\n").is_empty());
+        let messages = |input| {
+            let errors = Day03.validate(input);
+            errors.iter().map(Error::to_string).collect::<Vec<_>>()
+        };
+        assert_eq!(messages(""), ["1: Empty input"]);
+        assert_eq!(
+            messages("mul(1234,5)do()\n"),
+            ["No mul(X,Y) instructions found"]
+        );
+    }
+}
@@ -0,0 +1,107 @@
+use std::fmt::Display;
+
+use crate::{
+    error::{Error, Result},
+    solution::Solution,
+    utils::{
+        grid::{Grid, Pos, DIRS8},
+        validate,
+    },
+};
+
+pub struct Day04;
+
+/// Whether `word` is spelled out from `start` in direction `dir`.
+fn spells(grid: &Grid<char>, word: &str, (x, y): Pos, (dx, dy): Pos) -> bool {
+    word.chars()
+        .zip(0..)
+        .all(|(ch, i)| grid.get((x + i * dx, y + i * dy)) == Some(&ch))
+}
+
+/// Number of times XMAS appears in any of the 8 directions.
+pub fn count_xmas(grid: &Grid<char>) -> usize {
+    grid.find_all(|&ch| ch == 'X')
+        .map(|pos| {
+            DIRS8
+                .iter()
+                .filter(|&&dir| spells(grid, "XMAS", pos, dir))
+                .count()
+        })
+        .sum()
+}
+
+/// Whether the `A` at `pos` is the middle of two MAS crossing diagonally, each spelled either way.
+fn has_xmas_pattern(grid: &Grid<char>, (x, y): Pos) -> bool {
+    let is_mas = |(dx, dy): Pos| {
+        spells(grid, "MAS", (x - dx, y - dy), (dx, dy))
+            || spells(grid, "SAM", (x - dx, y - dy), (dx, dy))
+    };
+    is_mas((1, 1)) && is_mas((1, -1))
+}
+
+/// Number of MAS crosses in the grid.
+pub fn count_xmas_patterns(grid: &Grid<char>) -> usize {
+    grid.find_all(|&ch| ch == 'A')
+        .filter(|&pos| has_xmas_pattern(grid, pos))
+        .count()
+}
+
+impl Solution for Day04 {
+    type Input = Grid<char>;
+
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Ceres Search";
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Grid::parse(input)
+    }
+
+    fn validate(&self, input: &str) -> Vec<Error> {
+        validate::grid(input, |ch| "XMAS".contains(ch))
+    }
+
+    fn part1(&self, grid: &Self::Input) -> impl Display {
+        count_xmas(grid)
+    }
+
+    fn part2(&self, grid: &Self::Input) -> impl Display {
+        count_xmas_patterns(grid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::{example_answer, Part};
+
+    #[test]
+    fn test_example() {
+        let day = Day04;
+        assert_eq!(example_answer(&day, "day04.txt", Part::One), "18");
+        assert_eq!(example_answer(&day, "day04.txt", Part::Two), "9");
+    }
+
+    #[test]
+    fn test_small_grids() {
+        let grid = Grid::parse("XMAS\n").unwrap();
+        assert_eq!((count_xmas(&grid), count_xmas_patterns(&grid)), (1, 0));
+        let grid = Grid::parse("A\n").unwrap();
+        assert_eq!(count_xmas_patterns(&grid), 0);
+        assert!(Day04.parse("").is_err());
+        assert!(Day04.parse("XMAS\nXM\n").is_err());
+    }
+
+    #[test]
+    fn test_validate() {
+        let errors = Day04.validate("XMAS\nXM\nSAMx\n");
+        let messages: Vec<String> = errors.iter().map(Error::to_string).collect();
+        assert_eq!(
+            messages,
+            [
+                "2:3: Expected 4 columns, got 2",
+                "3:4: Invalid character 'x' in grid"
+            ]
+        );
+        assert_eq!(Day04.validate("").len(), 1);
+    }
+}
@@ -0,0 +1,161 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+};
+
+use crate::{
+    error::{parse_number, Error, Result},
+    solution::Solution,
+    utils::{
+        cancel,
+        parse::{key_value, list, sections},
+        validate,
+    },
+};
+
+pub struct Day05;
+
+pub type Rules = Vec<(usize, usize)>;
+pub type Update = HashMap<usize, usize>;
+
+pub fn parse_rule(rule: &str) -> Result<(usize, usize)> {
+    let (before, after) = key_value(rule, "|")?;
+    Ok((parse_number(rule, before)?, parse_number(rule, after)?))
+}
+
+pub fn parse_update(update: &str) -> Result<Update> {
+    Ok(list(update, ",")?
+        .into_iter()
+        .enumerate()
+        .map(|(i, page)| (page, i))
+        .collect())
+}
+
+/// Checks that an update has a middle page and lists each page once.
+fn validate_update(update: &str) -> Result<()> {
+    let pages: Vec<usize> = list(update, ",")?;
+    if pages.len().is_multiple_of(2) {
+        return Err(Error::parse(format!(
+            "Expected an odd number of pages, got {}",
+            pages.len()
+        )));
+    }
+    let mut seen = HashSet::new();
+    match pages.iter().find(|&&page| !seen.insert(page)) {
+        Some(page) => Err(Error::parse(format!("Page {} appears twice", page))),
+        None => Ok(()),
+    }
+}
+
+pub fn is_valid_update(rules: &[(usize, usize)], update: &Update) -> bool {
+    for (p1, p2) in rules {
+        if update.contains_key(p1) && update.contains_key(p2) && update[p1] > update[p2] {
+            return false;
+        }
+    }
+    true
+}
+
+/// Swaps pages until the update follows the rules.
+///
+/// Never finishes if the rules between the pages of the update form a cycle, so it checks for
+/// cancellation on every pass.
+pub fn correct_update(rules: &[(usize, usize)], update: &mut Update) {
+    loop {
+        cancel::check();
+        for (p1, p2) in rules {
+            if update.contains_key(p1) && update.contains_key(p2) && update[p1] > update[p2] {
+                let tmp = update[p1];
+                update.insert(*p1, update[p2]);
+                update.insert(*p2, tmp);
+            }
+        }
+        if is_valid_update(rules, update) {
+            break;
+        }
+    }
+}
+
+pub fn extract_middle_page(update: Update) -> usize {
+    let mut us: Vec<(usize, usize)> = update.into_iter().collect();
+    us.sort_by_key(|(_, i)| *i);
+    assert!(us.len() % 2 == 1, "Invalid update length {:?}", us);
+    let middle = us.len() / 2;
+    us[middle].0
+}
+
+impl Solution for Day05 {
+    type Input = (Rules, Vec<Update>);
+
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "Print Queue";
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        let [rules, updates] = sections(input)?;
+        Ok((
+            rules.parse_lines(parse_rule)?,
+            updates.parse_lines(parse_update)?,
+        ))
+    }
+
+    fn validate(&self, input: &str) -> Vec<Error> {
+        let [rules, updates] = match sections(input) {
+            Ok(sections) => sections,
+            Err(e) => return vec![e],
+        };
+        let mut errors = validate::in_block(&rules, |text| validate::each_line(text, parse_rule));
+        errors.extend(validate::in_block(&updates, |text| {
+            validate::each_line(text, validate_update)
+        }));
+        errors
+    }
+
+    fn part1(&self, (rules, updates): &Self::Input) -> impl Display {
+        updates
+            .iter()
+            .filter(|update| is_valid_update(rules, update))
+            .cloned()
+            .map(extract_middle_page)
+            .sum::<usize>()
+    }
+
+    fn part2(&self, (rules, updates): &Self::Input) -> impl Display {
+        updates
+            .iter()
+            .filter(|update| !is_valid_update(rules, update))
+            .cloned()
+            .map(|mut update| {
+                correct_update(rules, &mut update);
+                extract_middle_page(update)
+            })
+            .sum::<usize>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::{example_answer, Part};
+
+    #[test]
+    fn test_example() {
+        let day = Day05;
+        assert_eq!(example_answer(&day, "day05.txt", Part::One), "143");
+        assert_eq!(example_answer(&day, "day05.txt", Part::Two), "123");
+    }
+
+    #[test]
+    fn test_validate() {
+        let errors = Day05.validate("47|53\n97-13\n\n75,47,61\n75,47\n75,47,75\n");
+        let messages: Vec<String> = errors.iter().map(Error::to_string).collect();
+        assert_eq!(
+            messages,
+            [
+                "2: Expected 'key|value', got '97-13'",
+                "5: Expected an odd number of pages, got 2",
+                "6: Page 75 appears twice"
+            ]
+        );
+        assert_eq!(Day05.validate("47|53\n").len(), 1);
+    }
+}
@@ -0,0 +1,277 @@
+use std::{collections::HashSet, fmt::Display};
+
+use crate::{
+    error::{Error, Result},
+    solution::{Part, Solution},
+    utils::{
+        cancel,
+        geom::Dir4,
+        grid::{Grid, Pos},
+        record::Recorder,
+        render::{self, Color, Frame},
+        validate,
+    },
+};
+
+pub struct Day06;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Cell {
+    Empty,
+    Obstacle,
+    Visited(HashSet<Dir4>),
+}
+
+impl Cell {
+    fn visited_from_dir(dir: Dir4) -> Self {
+        Cell::Visited(HashSet::from([dir]))
+    }
+
+    fn update_visited(&mut self, dir: Dir4) {
+        match self {
+            Cell::Visited(dirs) => {
+                dirs.insert(dir);
+            }
+            _ => panic!("Cell is not visited"),
+        }
+    }
+
+    fn is_visited(&self) -> bool {
+        matches!(self, Cell::Visited(..))
+    }
+
+    fn is_visited_dir(&self, dir: Dir4) -> bool {
+        match self {
+            Cell::Visited(dirs) => dirs.contains(&dir),
+            _ => panic!("Cell is not visited"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Lab {
+    grid: Grid<Cell>,
+    guard_pos: Pos,
+    guard_dir: Dir4,
+}
+
+impl Lab {
+    pub fn parse(input: &str) -> Result<Self> {
+        let guard_dir = Dir4::Up;
+        let mut guard_pos = None;
+        let grid = Grid::parse_with(input, |pos, ch| match ch {
+            '.' => Some(Cell::Empty),
+            '#' => Some(Cell::Obstacle),
+            '^' => {
+                guard_pos = Some(pos);
+                Some(Cell::visited_from_dir(guard_dir))
+            }
+            _ => None,
+        })?;
+        let guard_pos = guard_pos.ok_or_else(|| Error::parse("No guard '^' found in grid"))?;
+
+        Ok(Self {
+            grid,
+            guard_pos,
+            guard_dir,
+        })
+    }
+
+    /// Moves the guard one step or turns it, returning whether it is still in the lab and whether
+    /// it is now going the same way as before on its cell, which means it walks in a loop.
+    fn advance_guard(&mut self) -> (bool, bool) {
+        let next_pos = self.guard_dir.step(self.guard_pos);
+        let Some(next_cell) = self.grid.get(next_pos) else {
+            return (false, false);
+        };
+
+        let loop_detected = match next_cell {
+            Cell::Empty => {
+                self.grid[next_pos] = Cell::visited_from_dir(self.guard_dir);
+                self.guard_pos = next_pos;
+                false
+            }
+            Cell::Obstacle => {
+                self.guard_dir = self.guard_dir.turn_right();
+                // A guard boxed in by obstacles loops by turning in place.
+                let has_loop = self.grid[self.guard_pos].is_visited_dir(self.guard_dir);
+                self.grid[self.guard_pos].update_visited(self.guard_dir);
+                has_loop
+            }
+            Cell::Visited { .. } => {
+                let has_loop = self.grid[next_pos].is_visited_dir(self.guard_dir);
+                self.grid[next_pos].update_visited(self.guard_dir);
+                self.guard_pos = next_pos;
+                has_loop
+            }
+        };
+        (true, loop_detected)
+    }
+
+    /// Walks the guard until it leaves the lab, which it never does if it walks in a loop, so it
+    /// checks for cancellation on every step.
+    pub fn predict_full_guard_path(&mut self) {
+        loop {
+            cancel::check();
+            let (advanced, _) = self.advance_guard();
+            if !advanced {
+                break;
+            }
+        }
+    }
+
+    /// Walks the guard until it leaves the lab or walks in a loop, returning whether it loops.
+    pub fn predict_guard_path_until_loop(&mut self) -> bool {
+        loop {
+            cancel::check();
+            let (advanced, loop_detected) = self.advance_guard();
+            if !advanced {
+                return false;
+            }
+            if loop_detected {
+                return true;
+            }
+        }
+    }
+
+    pub fn num_visited_cells(&self) -> usize {
+        self.grid
+            .iter()
+            .filter(|(_, cell)| cell.is_visited())
+            .count()
+    }
+
+    pub fn add_obstacle(&mut self, pos: Pos) {
+        self.grid[pos] = Cell::Obstacle;
+    }
+
+    /// The lab with the visited cells drawn by the directions the guard walked through them.
+    pub fn render(&self) -> Frame {
+        let mut frame = Frame::from_grid(&self.grid, |_, cell| match cell {
+            Cell::Empty => render::Cell::new('.', Color::DARK_GRAY),
+            Cell::Obstacle => render::Cell::new('#', Color::GRAY),
+            Cell::Visited(dirs) => {
+                let horizontal = dirs.iter().any(|dir| dir.is_horizontal());
+                let vertical = dirs.iter().any(|dir| !dir.is_horizontal());
+                let ch = match (horizontal, vertical) {
+                    (true, true) => '+',
+                    (true, false) => '-',
+                    _ => '|',
+                };
+                render::Cell::new(ch, Color::YELLOW).with_highlight(Color::BROWN)
+            }
+        });
+        frame.set(self.guard_pos, self.guard_dir.to_char(), Color::RED);
+        frame.highlight(self.guard_pos, Color::RED);
+        frame
+    }
+
+    pub fn empty_cells(&self) -> impl Iterator<Item = Pos> + '_ {
+        self.grid
+            .iter()
+            .filter(|(_, cell)| matches!(cell, Cell::Empty))
+            .map(|(pos, _)| pos)
+    }
+}
+
+impl Solution for Day06 {
+    type Input = Lab;
+
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Guard Gallivant";
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Lab::parse(input)
+    }
+
+    fn validate(&self, input: &str) -> Vec<Error> {
+        let mut errors = validate::grid(input, |ch| ".#^".contains(ch));
+        errors.extend(validate::exactly_one(input, '^', "guard"));
+        if errors.is_empty() {
+            let mut lab = Lab::parse(input).expect("The grid and the guard are checked");
+            let (x, y) = lab.guard_pos;
+            if lab.predict_guard_path_until_loop() {
+                errors.push(Error::at(
+                    y as usize + 1,
+                    x as usize + 1,
+                    "The guard walks in a loop and never leaves the lab",
+                ));
+            }
+        }
+        errors
+    }
+
+    fn part1(&self, lab: &Self::Input) -> impl Display {
+        let mut lab = lab.clone();
+        lab.predict_full_guard_path();
+        lab.num_visited_cells()
+    }
+
+    fn part2(&self, lab: &Self::Input) -> impl Display {
+        let mut num_loops = 0;
+        for pos in lab.empty_cells() {
+            let mut obstacle_lab = lab.clone();
+            obstacle_lab.add_obstacle(pos);
+            let loop_detected = obstacle_lab.predict_guard_path_until_loop();
+            if loop_detected {
+                num_loops += 1;
+            }
+        }
+        num_loops
+    }
+
+    fn render(&self, lab: &Self::Input) -> Vec<(String, Frame)> {
+        let mut lab = lab.clone();
+        lab.predict_full_guard_path();
+        vec![("guard".to_string(), lab.render())]
+    }
+
+    /// Records the guard's walk through the unchanged lab, which both parts start from.
+    fn record(&self, lab: &Self::Input, _part: Part, recorder: &mut Recorder) {
+        let mut lab = lab.clone();
+        recorder.record(|| lab.render());
+        while lab.advance_guard().0 {
+            recorder.record(|| lab.render());
+        }
+        recorder.finish(|| lab.render());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::example_answer;
+
+    #[test]
+    fn test_example() {
+        let day = Day06;
+        assert_eq!(example_answer(&day, "day06.txt", Part::One), "41");
+        assert_eq!(example_answer(&day, "day06.txt", Part::Two), "6");
+    }
+
+    #[test]
+    fn test_validate() {
+        let errors = Day06.validate("..#.\n.^>.\n#^.\n");
+        let messages: Vec<String> = errors.iter().map(Error::to_string).collect();
+        assert_eq!(
+            messages,
+            [
+                "2:3: Invalid character '>' in grid",
+                "3:4: Expected 4 columns, got 3",
+                "3:2: Another guard '^', the first one is at 2:2"
+            ]
+        );
+        let errors = Day06.validate(".#.\n#^#\n.#.\n");
+        let messages: Vec<String> = errors.iter().map(Error::to_string).collect();
+        assert_eq!(
+            messages,
+            ["2:2: The guard walks in a loop and never leaves the lab"]
+        );
+    }
+
+    #[test]
+    fn test_boxed_in_guard() {
+        let mut lab = Lab::parse(".#.\n#^#\n.#.\n").unwrap();
+        assert!(lab.predict_guard_path_until_loop());
+    }
+}
@@ -0,0 +1,283 @@
+use std::fmt::Display;
+
+use crate::{
+    error::{parse_number, Error, Result},
+    solution::Solution,
+    utils::{
+        parse::{key_value, parse_lines},
+        validate,
+    },
+};
+
+pub struct Day07;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    Add,
+    Multiply,
+    Concat,
+}
+
+impl Operation {
+    fn apply(&self, a: i64, b: i64) -> i64 {
+        match self {
+            Operation::Add => a + b,
+            Operation::Multiply => a * b,
+            Operation::Concat => format!("{}{}", a, b).parse().unwrap(),
+        }
+    }
+}
+
+struct OperationPermutations {
+    n: usize,
+    include_concat: bool,
+    current: Vec<Operation>,
+    done: bool,
+}
+
+impl OperationPermutations {
+    fn new(n: usize, include_concat: bool) -> Self {
+        OperationPermutations {
+            n,
+            include_concat,
+            current: vec![Operation::Add; n],
+            done: false,
+        }
+    }
+}
+
+impl Iterator for OperationPermutations {
+    type Item = Vec<Operation>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let result = self.current.clone();
+
+        for i in (0..self.n).rev() {
+            self.current[i] = match self.current[i] {
+                Operation::Add => Operation::Multiply,
+                Operation::Multiply => {
+                    if self.include_concat {
+                        Operation::Concat
+                    } else {
+                        if i == 0 {
+                            self.done = true;
+                        }
+                        Operation::Add
+                    }
+                }
+                Operation::Concat => {
+                    if i == 0 {
+                        self.done = true;
+                    }
+                    Operation::Add
+                }
+            };
+
+            if self.current[i] != Operation::Add {
+                break;
+            }
+        }
+
+        Some(result)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Equation {
+    result: i64,
+    numbers: Vec<i64>,
+}
+
+impl Equation {
+    pub fn from_line(line: &str) -> Result<Self> {
+        let (result, numbers) = key_value(line, ":")?;
+        let result = parse_number(line, result)?;
+        let numbers = numbers
+            .split_whitespace()
+            .map(|n| parse_number(line, n))
+            .collect::<Result<Vec<_>>>()?;
+        if numbers.is_empty() {
+            return Err(Error::parse("Expected at least one number after ':'"));
+        }
+        Ok(Equation { result, numbers })
+    }
+
+    pub fn is_correct(&self, ops: &[Operation]) -> bool {
+        assert!(ops.len() + 1 == self.numbers.len());
+        let mut result = self.numbers[0];
+        for (op, &number) in ops.iter().zip(self.numbers.iter().skip(1)) {
+            result = op.apply(result, number);
+        }
+        result == self.result
+    }
+
+    pub fn can_be_correct(&self, include_concat: bool) -> bool {
+        for ops in OperationPermutations::new(self.numbers.len() - 1, include_concat) {
+            if self.is_correct(&ops) {
+                return true;
+            }
+        }
+        false
+    }
+}
+
+impl Solution for Day07 {
+    type Input = Vec<Equation>;
+
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "Bridge Repair";
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parse_lines(input, Equation::from_line)
+    }
+
+    fn validate(&self, input: &str) -> Vec<Error> {
+        let mut errors = validate::non_empty(input);
+        errors.extend(validate::each_line(input, |line| {
+            let equation = Equation::from_line(line)?;
+            // Concatenation only makes sense for numbers without a sign.
+            if equation.result < 0 || equation.numbers.iter().any(|&n| n < 0) {
+                return Err(Error::parse("Negative numbers are not supported"));
+            }
+            Ok(equation)
+        }));
+        errors
+    }
+
+    fn part1(&self, equations: &Self::Input) -> impl Display {
+        equations
+            .iter()
+            .filter(|eq| eq.can_be_correct(false))
+            .map(|eq| eq.result)
+            .sum::<i64>()
+    }
+
+    fn part2(&self, equations: &Self::Input) -> impl Display {
+        equations
+            .iter()
+            .filter(|eq| eq.can_be_correct(true))
+            .map(|eq| eq.result)
+            .sum::<i64>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::{example_answer, Part};
+
+    #[test]
+    fn test_example() {
+        let day = Day07;
+        assert_eq!(example_answer(&day, "day07.txt", Part::One), "3749");
+        assert_eq!(example_answer(&day, "day07.txt", Part::Two), "11387");
+    }
+
+    #[test]
+    fn test_validate() {
+        let errors = Day07.validate("190: 10 19\n83 17 5\n7:\n3: 5 -2\n");
+        let messages: Vec<String> = errors.iter().map(Error::to_string).collect();
+        assert_eq!(
+            messages,
+            [
+                "2: Expected 'key:value', got '83 17 5'",
+                "3: Expected at least one number after ':'",
+                "4: Negative numbers are not supported"
+            ]
+        );
+        assert_eq!(Day07.validate("").len(), 1);
+    }
+
+    #[test]
+    fn test_operation_permutations_no_concat() {
+        let mut permutations = OperationPermutations::new(2, false);
+        assert_eq!(
+            permutations.next(),
+            Some(vec![Operation::Add, Operation::Add])
+        );
+        assert_eq!(
+            permutations.next(),
+            Some(vec![Operation::Add, Operation::Multiply])
+        );
+        assert_eq!(
+            permutations.next(),
+            Some(vec![Operation::Multiply, Operation::Add])
+        );
+        assert_eq!(
+            permutations.next(),
+            Some(vec![Operation::Multiply, Operation::Multiply])
+        );
+        assert_eq!(permutations.next(), None);
+    }
+
+    #[test]
+    fn test_operation_permutations_with_concat() {
+        let mut permutations = OperationPermutations::new(2, true);
+        assert_eq!(
+            permutations.next(),
+            Some(vec![Operation::Add, Operation::Add])
+        );
+        assert_eq!(
+            permutations.next(),
+            Some(vec![Operation::Add, Operation::Multiply])
+        );
+        assert_eq!(
+            permutations.next(),
+            Some(vec![Operation::Add, Operation::Concat])
+        );
+        assert_eq!(
+            permutations.next(),
+            Some(vec![Operation::Multiply, Operation::Add])
+        );
+        assert_eq!(
+            permutations.next(),
+            Some(vec![Operation::Multiply, Operation::Multiply])
+        );
+        assert_eq!(
+            permutations.next(),
+            Some(vec![Operation::Multiply, Operation::Concat])
+        );
+        assert_eq!(
+            permutations.next(),
+            Some(vec![Operation::Concat, Operation::Add])
+        );
+        assert_eq!(
+            permutations.next(),
+            Some(vec![Operation::Concat, Operation::Multiply])
+        );
+        assert_eq!(
+            permutations.next(),
+            Some(vec![Operation::Concat, Operation::Concat])
+        );
+        assert_eq!(permutations.next(), None);
+    }
+
+    #[test]
+    fn test_equation_is_correct() {
+        let equation = Equation {
+            result: 6,
+            numbers: vec![1, 2, 3],
+        };
+        assert!(equation.is_correct(&[Operation::Add, Operation::Add]));
+        assert!(!equation.is_correct(&[Operation::Multiply, Operation::Add]));
+    }
+
+    #[test]
+    fn test_equation_can_be_correct() {
+        let equation = Equation {
+            result: 6,
+            numbers: vec![1, 2, 3],
+        };
+        assert!(equation.can_be_correct(false));
+
+        let equation = Equation {
+            result: 10,
+            numbers: vec![1, 2, 3],
+        };
+        assert!(!equation.can_be_correct(false));
+    }
+}
@@ -0,0 +1,162 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+};
+
+use crate::{
+    error::{Error, Result},
+    solution::Solution,
+    utils::{
+        geom::Vec2,
+        grid::{Grid, Pos},
+        math::gcd,
+        validate,
+    },
+};
+
+pub struct Day08;
+
+#[derive(Debug, Clone)]
+pub struct AntennaGrid {
+    grid: Grid<char>,
+    antenna_positions: HashMap<char, Vec<Pos>>,
+    antinode_positions: HashSet<Pos>,
+}
+
+impl AntennaGrid {
+    pub fn from_char_grid(grid: Grid<char>) -> Self {
+        let mut antenna_positions: HashMap<char, Vec<Pos>> = HashMap::new();
+        for (pos, &c) in grid.iter() {
+            if c != '.' {
+                antenna_positions.entry(c).or_default().push(pos);
+            }
+        }
+
+        Self {
+            grid,
+            antenna_positions,
+            antinode_positions: HashSet::new(),
+        }
+    }
+
+    fn is_in_grid(&self, pos: Pos) -> bool {
+        self.grid.in_bounds(pos)
+    }
+
+    fn compute_antinodes_part1(&self, pos1: Pos, pos2: Pos) -> Vec<Pos> {
+        let (p1, p2) = (Vec2::from(pos1), Vec2::from(pos2));
+        let dir = p2 - p1;
+        [p2 + dir, p1 - dir]
+            .into_iter()
+            .map(Pos::from)
+            .filter(|&an| self.is_in_grid(an))
+            .collect()
+    }
+
+    fn compute_antinodes_part2(&self, pos1: Pos, pos2: Pos) -> Vec<Pos> {
+        // Every grid position on the line counts, so step by the smallest integer offset along it.
+        let (p1, p2) = (Vec2::from(pos1), Vec2::from(pos2));
+        let dir = p2 - p1;
+        let step = Vec2::new(dir.x / gcd(dir.x, dir.y), dir.y / gcd(dir.x, dir.y));
+        let mut ans = Vec::new();
+        for step in [step, -step] {
+            let mut an = p1;
+            while self.is_in_grid(Pos::from(an)) {
+                ans.push(Pos::from(an));
+                an += step;
+            }
+        }
+        ans
+    }
+
+    /// The antinodes of every pair of antennas, none if the frequency has a single antenna.
+    fn compute_antinodes_for_antennas(&self, positions: &[Pos], part1: bool) -> Vec<Pos> {
+        let mut antinodes = Vec::new();
+        for i in 0..positions.len() {
+            for j in i + 1..positions.len() {
+                let pos1 = positions[i];
+                let pos2 = positions[j];
+                let new_antinodes = if part1 {
+                    self.compute_antinodes_part1(pos1, pos2)
+                } else {
+                    self.compute_antinodes_part2(pos1, pos2)
+                };
+                antinodes.extend(new_antinodes);
+            }
+        }
+        antinodes
+    }
+
+    pub fn compute_all_antinodes(&mut self, part1: bool) {
+        for positions in self.antenna_positions.values() {
+            let antinodes = self.compute_antinodes_for_antennas(positions, part1);
+            for antinode in antinodes {
+                self.antinode_positions.insert(antinode);
+            }
+        }
+    }
+
+    pub fn num_antinodes(&self) -> usize {
+        self.antinode_positions.len()
+    }
+}
+
+impl Solution for Day08 {
+    type Input = AntennaGrid;
+
+    const DAY: u8 = 8;
+    const TITLE: &'static str = "Resonant Collinearity";
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(AntennaGrid::from_char_grid(Grid::parse(input)?))
+    }
+
+    fn validate(&self, input: &str) -> Vec<Error> {
+        validate::grid(input, |ch| ch == '.' || ch.is_ascii_alphanumeric())
+    }
+
+    fn part1(&self, grid: &Self::Input) -> impl Display {
+        let mut grid = grid.clone();
+        grid.compute_all_antinodes(true);
+        grid.num_antinodes()
+    }
+
+    fn part2(&self, grid: &Self::Input) -> impl Display {
+        let mut grid = grid.clone();
+        grid.compute_all_antinodes(false);
+        grid.num_antinodes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::{example_answer, Part};
+
+    #[test]
+    fn test_example() {
+        let day = Day08;
+        assert_eq!(example_answer(&day, "day08.txt", Part::One), "14");
+        assert_eq!(example_answer(&day, "day08.txt", Part::Two), "34");
+    }
+
+    #[test]
+    fn test_single_antenna() {
+        let grid = Day08.parse("..a\n.A.\n..a\n").unwrap();
+        assert_eq!(Day08.part1(&grid).to_string(), "0");
+        assert_eq!(Day08.part2(&grid).to_string(), "3");
+    }
+
+    #[test]
+    fn test_validate() {
+        let errors = Day08.validate("..a.\n.#A.\n..\n");
+        let messages: Vec<String> = errors.iter().map(Error::to_string).collect();
+        assert_eq!(
+            messages,
+            [
+                "2:2: Invalid character '#' in grid",
+                "3:3: Expected 4 columns, got 2"
+            ]
+        );
+    }
+}
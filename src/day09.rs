@@ -0,0 +1,409 @@
+use std::{
+    cmp::{max, min},
+    collections::BTreeMap,
+    fmt::Display,
+};
+
+use crate::{
+    error::{Error, Result},
+    solution::{Implementation, Part, Solution},
+};
+
+pub struct Day09;
+
+#[derive(Debug, Clone)]
+pub struct Disk {
+    blocks: Vec<Option<i64>>,
+}
+
+impl Disk {
+    pub fn from_layout_string(layout_string: &str) -> Result<Self> {
+        let mut file_id = 0;
+        let mut cur_is_file = true;
+        let mut blocks = Vec::new();
+        for (i, char) in layout_string.trim().chars().enumerate() {
+            let num = char
+                .to_digit(10)
+                .ok_or_else(|| Error::at(1, i + 1, format!("Invalid digit '{}'", char)))?;
+            if cur_is_file {
+                blocks.extend(vec![Some(file_id); num as usize]);
+                file_id += 1;
+                cur_is_file = false;
+            } else {
+                blocks.extend(vec![None; num as usize]);
+                cur_is_file = true;
+            }
+        }
+        Ok(Self { blocks })
+    }
+
+    /// The blocks of the disk, with the ID of the file occupying them or `None` if free.
+    pub fn blocks(&self) -> &[Option<i64>] {
+        &self.blocks
+    }
+
+    /// Moves single blocks from the end of the disk to the leftmost free block.
+    pub fn compact_blocks(&mut self) {
+        // Without any free space the disk is already compact.
+        let Some(mut free_ptr) = self.blocks.iter().position(Option::is_none) else {
+            return;
+        };
+        let mut cur_block = self.blocks.len() - 1;
+        while cur_block > free_ptr {
+            if self.blocks[cur_block].is_some() {
+                assert!(self.blocks[free_ptr].is_none());
+                self.blocks.swap(cur_block, free_ptr);
+                loop {
+                    free_ptr += 1;
+                    if self.blocks[free_ptr].is_none() {
+                        break;
+                    }
+                }
+            }
+            cur_block -= 1;
+        }
+    }
+
+    fn swap_block_ranges(&mut self, blk1_start: usize, blk2_start: usize, blk_len: usize) {
+        let blk1_end = blk1_start + blk_len;
+        let blk2_end = blk2_start + blk_len;
+        assert!(blk1_end <= self.blocks.len());
+        assert!(blk2_end <= self.blocks.len());
+        assert!(min(blk1_end, blk2_end) <= max(blk1_start, blk2_start));
+        for i in 0..blk_len {
+            self.blocks.swap(blk1_start + i, blk2_start + i);
+        }
+    }
+
+    fn iter_empty(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.blocks
+            .iter()
+            .enumerate()
+            .scan(None, |state, (i, block)| match (&state, block) {
+                (None, None) => {
+                    *state = Some(i);
+                    Some(None)
+                }
+                (Some(start), Some(_)) => {
+                    let range = (*start, i);
+                    *state = None;
+                    Some(Some(range))
+                }
+                _ => Some(None),
+            })
+            .flatten()
+    }
+
+    /// Iterates over the files on the disk, from either end.
+    pub fn iter_files(&self) -> FilesIter<'_> {
+        FilesIter {
+            disk: self,
+            ptr: None,
+            done: false,
+        }
+    }
+
+    /// Moves whole files to the leftmost free span that fits them, highest file ID first.
+    pub fn compact_files(&mut self) {
+        let mut cur_file_id = self.iter_files().next_back().unwrap().2;
+        while cur_file_id > 0 {
+            let mut files_iter = self
+                .iter_files()
+                .rev()
+                .skip_while(|(_, _, f_id)| *f_id > cur_file_id);
+            let swp_res = files_iter.find_map(|(f_start, f_end, f_id)| {
+                let f_size = f_end - f_start;
+                let mut empty_iter = self.iter_empty();
+                let e_res = empty_iter.find(|(e_start, e_end)| {
+                    let e_size = e_end - e_start;
+                    e_size >= f_size && *e_start < f_start
+                });
+                e_res.map(|(e_start, _)| (e_start, f_start, f_size, f_id))
+            });
+            match swp_res {
+                Some((e_start, f_start, f_size, f_id)) => {
+                    self.swap_block_ranges(e_start, f_start, f_size);
+                    if f_id == 0 {
+                        break;
+                    }
+                    cur_file_id = f_id - 1;
+                }
+                None => {
+                    cur_file_id -= 1;
+                }
+            }
+        }
+    }
+
+    /// Faster implementation of [`Disk::compact_files`], used for part 2.
+    pub fn compact_files2(&mut self) {
+        let mut free_blocks: BTreeMap<usize, BTreeMap<usize, usize>> = BTreeMap::new();
+        for (start, end) in self.iter_empty() {
+            let len = end - start;
+            for s in 1..=len {
+                free_blocks.entry(s).or_default().insert(start, len);
+            }
+        }
+        let file_positions = self.iter_files().rev().collect::<Vec<_>>();
+        for (fstart, fend, _fid) in file_positions {
+            let flen = fend - fstart;
+            assert!(flen > 0 && flen <= 9);
+            let remove_empty = match free_blocks.get(&flen) {
+                Some(free_blocks) => match free_blocks.first_key_value() {
+                    Some((estart, elen)) => {
+                        if fstart > *estart {
+                            self.swap_block_ranges(*estart, fstart, flen);
+                            Some((*estart, *elen, flen, fstart))
+                        } else {
+                            None
+                        }
+                    }
+                    None => None,
+                },
+                None => None,
+            };
+            if let Some((estart, elen, flen, fstart)) = remove_empty {
+                let new_estart = estart + flen;
+                let new_elen = elen - flen;
+                // Remove the old empty block
+                for s in 1..=elen {
+                    free_blocks.entry(s).or_default().remove(&estart);
+                }
+                // Add the new empty block
+                for s in 1..=new_elen {
+                    free_blocks
+                        .entry(s)
+                        .or_default()
+                        .insert(new_estart, new_elen);
+                }
+                // Add new empty blocks where the file used to be
+                // Get the potential empty block after (to be merged)
+                let next_empty_start = fstart + flen;
+                let next_empty_len = *free_blocks
+                    .get(&1)
+                    .and_then(|x| x.get(&next_empty_start))
+                    .unwrap_or(&0);
+                let new_empty_len = flen + next_empty_len;
+                for s in 1..=new_empty_len {
+                    free_blocks
+                        .entry(s)
+                        .or_default()
+                        .insert(fstart, new_empty_len);
+                }
+            }
+        }
+    }
+
+    pub fn checksum(&self) -> i64 {
+        self.blocks
+            .iter()
+            .enumerate()
+            .filter_map(|(i, x)| x.map(|x| (i, x)))
+            .map(|(i, x)| i as i64 * x)
+            .sum()
+    }
+}
+
+/// Iterator over the files of a [`Disk`] as `(start, end, file_id)` block ranges.
+pub struct FilesIter<'a> {
+    disk: &'a Disk,
+    ptr: Option<usize>,
+    done: bool,
+}
+
+impl Iterator for FilesIter<'_> {
+    type Item = (usize, usize, i64);
+
+    fn next(&mut self) -> Option<(usize, usize, i64)> {
+        if self.done {
+            return None;
+        }
+        let mut ptr = self.ptr.unwrap_or(0);
+        if ptr < self.disk.blocks.len() {
+            while self.disk.blocks[ptr].is_none() {
+                ptr += 1;
+            }
+            let file_id = self.disk.blocks[ptr].expect("Invalid file ID");
+            let mut end = ptr + 1;
+            while end < self.disk.blocks.len() {
+                match self.disk.blocks.get(end) {
+                    Some(Some(id)) if *id == file_id => end += 1,
+                    _ => break,
+                }
+            }
+            let start = ptr;
+            self.ptr = Some(end);
+            return Some((start, end, file_id));
+        }
+        self.done = true;
+        None
+    }
+}
+
+impl DoubleEndedIterator for FilesIter<'_> {
+    fn next_back(&mut self) -> Option<(usize, usize, i64)> {
+        if self.done {
+            return None;
+        }
+        let mut ptr = self.ptr.unwrap_or(self.disk.blocks.len() - 1);
+        while self.disk.blocks[ptr].is_none() {
+            assert!(ptr > 0);
+            ptr -= 1;
+        }
+        assert!(self.disk.blocks[ptr].is_some());
+        let file_id = self.disk.blocks[ptr].expect("Invalid file ID");
+        let end = ptr + 1;
+        while ptr > 0 {
+            match self.disk.blocks[ptr - 1] {
+                Some(id) if id == file_id => ptr -= 1,
+                _ => break,
+            }
+        }
+        let start = ptr;
+        if ptr == 0 {
+            self.done = true;
+        } else {
+            ptr -= 1;
+        }
+        self.ptr = Some(ptr);
+        Some((start, end, file_id))
+    }
+}
+
+impl Solution for Day09 {
+    type Input = Disk;
+
+    const DAY: u8 = 9;
+    const TITLE: &'static str = "Disk Fragmenter";
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Disk::from_layout_string(input)
+    }
+
+    fn validate(&self, input: &str) -> Vec<Error> {
+        let mut lines = input.lines();
+        let Some(layout) = lines.next().filter(|line| !line.trim().is_empty()) else {
+            return vec![Error::parse("Empty disk map").at_line(1)];
+        };
+        let mut errors: Vec<Error> = layout
+            .trim_end()
+            .chars()
+            .enumerate()
+            .filter_map(|(i, ch)| match ch.to_digit(10) {
+                None => Some(Error::at(1, i + 1, format!("Invalid digit '{}'", ch))),
+                Some(0) if i % 2 == 0 => {
+                    Some(Error::at(1, i + 1, format!("File {} has length 0", i / 2)))
+                }
+                _ => None,
+            })
+            .collect();
+        for (i, line) in lines.enumerate() {
+            if !line.trim().is_empty() {
+                errors.push(Error::parse("Expected the disk map on a single line").at_line(i + 2));
+            }
+        }
+        errors
+    }
+
+    fn part1(&self, disk: &Self::Input) -> impl Display {
+        let mut disk = disk.clone();
+        disk.compact_blocks();
+        disk.checksum()
+    }
+
+    fn part2(&self, disk: &Self::Input) -> impl Display {
+        let mut disk = disk.clone();
+        disk.compact_files2();
+        disk.checksum()
+    }
+
+    fn implementations(&self) -> Vec<Implementation<Self>> {
+        vec![Implementation {
+            name: "compact-files",
+            part: Part::Two,
+            solve: |_, disk| {
+                let mut disk = disk.clone();
+                disk.compact_files();
+                disk.checksum().to_string()
+            },
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        generate,
+        solution::{example_answer, Part},
+        utils::property,
+    };
+
+    #[test]
+    fn test_example() {
+        let day = Day09;
+        assert_eq!(example_answer(&day, "day09.txt", Part::One), "1928");
+        assert_eq!(example_answer(&day, "day09.txt", Part::Two), "2858");
+    }
+
+    #[test]
+    fn test_validate() {
+        let errors = Day09.validate("12x4051\n9\n");
+        let messages: Vec<String> = errors.iter().map(Error::to_string).collect();
+        assert_eq!(
+            messages,
+            [
+                "1:3: Invalid digit 'x'",
+                "1:5: File 2 has length 0",
+                "2: Expected the disk map on a single line"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_no_free_space() {
+        let disk = Day09.parse("102\n").unwrap();
+        assert_eq!(Day09.part1(&disk).to_string(), "3");
+        assert_eq!(Day09.part2(&disk).to_string(), "3");
+    }
+
+    /// The disk map without each one of its files, along with the free space after it.
+    fn remove_files(input: &str) -> Vec<String> {
+        let layout = input.trim();
+        (0..layout.len())
+            .step_by(2)
+            .map(|i| {
+                let end = (i + 2).min(layout.len());
+                let start = if end - i == 1 { i.saturating_sub(1) } else { i };
+                format!("{}{}\n", &layout[..start], &layout[end..])
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_compact_files_implementations_agree() {
+        property::check(
+            &Day09,
+            "compact-files",
+            |rng| {
+                let files = rng.between(1, 12) as usize;
+                generate::disk_map(rng, files)
+            },
+            |input| [remove_files(input), property::smaller_digits(input)].concat(),
+            |disk| {
+                let (mut reference, mut fast) = (disk.clone(), disk.clone());
+                reference.compact_files();
+                fast.compact_files2();
+                (reference.blocks() == fast.blocks())
+                    .then_some(())
+                    .ok_or_else(|| {
+                        format!(
+                            "compact_files gives checksum {}, compact_files2 gives {}",
+                            reference.checksum(),
+                            fast.checksum()
+                        )
+                    })
+            },
+        );
+    }
+}
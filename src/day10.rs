@@ -0,0 +1,300 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+};
+
+use crate::{
+    error::{Error, Result},
+    solution::{Implementation, Part, Solution},
+    utils::{
+        grid::{Grid, Pos},
+        render::{Cell, Color, Frame},
+        validate,
+    },
+};
+
+pub struct Day10;
+
+#[derive(Debug)]
+enum Waypoint {
+    Branch(Vec<Waypoint>),
+    Target(Pos),
+    DeadEnd,
+}
+
+impl Waypoint {
+    fn collect_target_positions_rec(&self, target_positions: &mut HashSet<Pos>) {
+        match self {
+            Waypoint::Branch(branches) => {
+                for branch in branches {
+                    branch.collect_target_positions_rec(target_positions);
+                }
+            }
+            Waypoint::Target(pos) => {
+                target_positions.insert(*pos);
+            }
+            Waypoint::DeadEnd => {}
+        }
+    }
+
+    fn score(&self) -> u32 {
+        let mut target_positions = HashSet::new();
+        self.collect_target_positions_rec(&mut target_positions);
+        target_positions.len() as u32
+    }
+
+    fn rating(&self) -> u32 {
+        match self {
+            Waypoint::Branch(branches) => {
+                let mut rating = 0;
+                for branch in branches {
+                    rating += branch.rating();
+                }
+                rating
+            }
+            Waypoint::Target(..) => 1,
+            Waypoint::DeadEnd => 0,
+        }
+    }
+}
+
+pub struct TopoMap {
+    grid: Grid<u8>,
+    start_positions: Vec<Pos>,
+}
+
+impl TopoMap {
+    pub fn parse(input: &str) -> Result<Self> {
+        let grid = Grid::parse_with(input, |_, ch| ch.to_digit(10).map(|h| h as u8))?;
+        let start_positions = grid
+            .iter()
+            .filter(|(_, &height)| height == 0)
+            .map(|(pos, _)| pos)
+            .collect();
+        Ok(Self {
+            grid,
+            start_positions,
+        })
+    }
+
+    fn viable_next_steps(&self, pos: Pos) -> Vec<Pos> {
+        let height = self.grid[pos];
+        if height == 9 {
+            return Vec::new();
+        }
+        self.grid
+            .neighbors4(pos)
+            .filter(|&next| self.grid[next] == height + 1)
+            .collect()
+    }
+
+    fn is_target(&self, pos: Pos) -> bool {
+        self.grid[pos] == 9
+    }
+
+    fn next_waypoint(&self, pos: Pos) -> Waypoint {
+        if self.is_target(pos) {
+            return Waypoint::Target(pos);
+        }
+        let next_steps = self.viable_next_steps(pos);
+        if next_steps.is_empty() {
+            return Waypoint::DeadEnd;
+        }
+        let mut branches = Vec::new();
+        for next_step in next_steps {
+            branches.push(self.next_waypoint(next_step));
+        }
+        Waypoint::Branch(branches)
+    }
+
+    fn trailhead_waypoints(&self) -> Vec<Waypoint> {
+        let mut waypoints = Vec::new();
+        for start_position in &self.start_positions {
+            let waypoint = self.next_waypoint(*start_position);
+            waypoints.push(waypoint);
+        }
+        waypoints
+    }
+
+    pub fn sum_of_trailhead_scores(&self) -> u32 {
+        self.trailhead_waypoints().iter().map(|w| w.score()).sum()
+    }
+
+    pub fn sum_of_trailhead_ratings(&self) -> u32 {
+        self.trailhead_waypoints().iter().map(|w| w.rating()).sum()
+    }
+
+    /// Scores and ratings of all trailheads counted height by height from the summits down,
+    /// instead of with trees of waypoints.
+    pub fn count_trails(&self) -> (u32, u32) {
+        let mut summits: HashMap<Pos, HashSet<Pos>> = HashMap::new();
+        let mut trails: HashMap<Pos, u32> = HashMap::new();
+        for height in (0..=9).rev() {
+            for pos in self.grid.find_all(|&h| h == height) {
+                if height == 9 {
+                    summits.insert(pos, HashSet::from([pos]));
+                    trails.insert(pos, 1);
+                    continue;
+                }
+                let next: Vec<Pos> = self
+                    .grid
+                    .neighbors4(pos)
+                    .filter(|&np| self.grid[np] == height + 1)
+                    .collect();
+                let reached = next.iter().flat_map(|np| summits[np].clone()).collect();
+                summits.insert(pos, reached);
+                trails.insert(pos, next.iter().map(|np| trails[np]).sum());
+            }
+        }
+        self.start_positions
+            .iter()
+            .map(|pos| (summits[pos].len() as u32, trails[pos]))
+            .fold((0, 0), |(s, r), (score, rating)| (s + score, r + rating))
+    }
+
+    /// The positions that lie on any hiking trail from a trailhead to a height of 9.
+    pub fn trail_positions(&self) -> HashSet<Pos> {
+        let mut from_trailhead: HashSet<Pos> = self.start_positions.iter().copied().collect();
+        let mut to_summit: HashSet<Pos> = self.grid.find_all(|&h| h == 9).collect();
+        for height in 1..=9 {
+            let reached: Vec<Pos> = self
+                .grid
+                .find_all(|&h| h == height)
+                .filter(|&pos| {
+                    self.grid
+                        .neighbors4(pos)
+                        .any(|np| self.grid[np] == height - 1 && from_trailhead.contains(&np))
+                })
+                .collect();
+            from_trailhead.extend(reached);
+        }
+        for height in (0..9).rev() {
+            let reached: Vec<Pos> = self
+                .grid
+                .find_all(|&h| h == height)
+                .filter(|&pos| {
+                    self.grid
+                        .neighbors4(pos)
+                        .any(|np| self.grid[np] == height + 1 && to_summit.contains(&np))
+                })
+                .collect();
+            to_summit.extend(reached);
+        }
+        from_trailhead.intersection(&to_summit).copied().collect()
+    }
+
+    /// The heights in shades of gray, with the trails colored from green at the trailheads to
+    /// red at the summits.
+    pub fn render(&self) -> Frame {
+        let trails = self.trail_positions();
+        Frame::from_grid(&self.grid, |pos, &height| {
+            let ch = char::from_digit(height as u32, 10).unwrap_or('?');
+            let cell = Cell::new(ch, Color::gray(40 + height * 20));
+            if trails.contains(&pos) {
+                cell.with_highlight(Color::GREEN.mix(Color::RED, height as f64 / 9.0))
+            } else {
+                cell
+            }
+        })
+    }
+}
+
+impl Solution for Day10 {
+    type Input = TopoMap;
+
+    const DAY: u8 = 10;
+    const TITLE: &'static str = "Hoof It";
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        TopoMap::parse(input)
+    }
+
+    fn validate(&self, input: &str) -> Vec<Error> {
+        validate::grid(input, |ch| ch.is_ascii_digit())
+    }
+
+    fn part1(&self, topo_map: &Self::Input) -> impl Display {
+        topo_map.sum_of_trailhead_scores()
+    }
+
+    fn part2(&self, topo_map: &Self::Input) -> impl Display {
+        topo_map.sum_of_trailhead_ratings()
+    }
+
+    fn render(&self, topo_map: &Self::Input) -> Vec<(String, Frame)> {
+        vec![("trails".to_string(), topo_map.render())]
+    }
+
+    fn implementations(&self) -> Vec<Implementation<Self>> {
+        vec![
+            Implementation {
+                name: "counting",
+                part: Part::One,
+                solve: |_, topo_map| topo_map.count_trails().0.to_string(),
+            },
+            Implementation {
+                name: "counting",
+                part: Part::Two,
+                solve: |_, topo_map| topo_map.count_trails().1.to_string(),
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{generate, solution::example_answer, utils::property};
+
+    #[test]
+    fn test_example() {
+        let day = Day10;
+        assert_eq!(example_answer(&day, "day10.txt", Part::One), "36");
+        assert_eq!(example_answer(&day, "day10.txt", Part::Two), "81");
+    }
+
+    #[test]
+    fn test_validate() {
+        let errors = Day10.validate("0123\n1.34\n876\n");
+        let messages: Vec<String> = errors.iter().map(Error::to_string).collect();
+        assert_eq!(
+            messages,
+            [
+                "2:2: Invalid character '.' in grid",
+                "3:4: Expected 4 columns, got 3"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_waypoint_trees_agree_with_counting() {
+        property::check(
+            &Day10,
+            "trails",
+            |rng| {
+                let (width, height) = (rng.between(1, 10), rng.between(1, 10));
+                generate::topographic_map(rng, width as usize, height as usize)
+            },
+            |input| {
+                [
+                    property::remove_rows_and_columns(input),
+                    property::smaller_digits(input),
+                ]
+                .concat()
+            },
+            |map| {
+                let trees = (
+                    map.sum_of_trailhead_scores(),
+                    map.sum_of_trailhead_ratings(),
+                );
+                let counted = map.count_trails();
+                (trees == counted).then_some(()).ok_or_else(|| {
+                    format!(
+                        "Waypoint trees give (score, rating) {:?}, counting gives {:?}",
+                        trees, counted
+                    )
+                })
+            },
+        );
+    }
+}
@@ -0,0 +1,153 @@
+use std::{collections::HashMap, fmt::Display};
+
+use crate::{
+    error::{parse_number, Error, Result},
+    solution::{parse_param, Param, Solution},
+    utils::validate,
+};
+
+pub struct Day11 {
+    /// Number of times the stones change in part 1.
+    pub part1_blinks: usize,
+    /// The same in part 2.
+    pub part2_blinks: usize,
+}
+
+impl Default for Day11 {
+    fn default() -> Self {
+        Self {
+            part1_blinks: 25,
+            part2_blinks: 75,
+        }
+    }
+}
+
+pub type StonesMap = HashMap<i64, usize>;
+
+pub fn split_stone_val(sval: i64) -> Option<(i64, i64)> {
+    let sval_str = sval.to_string();
+    let num_digits = sval_str.len() as i64;
+    if num_digits % 2 == 0 {
+        let half = num_digits / 2;
+        let slval = sval_str[..half as usize]
+            .parse::<i64>()
+            .expect("Failed to parse left half");
+        let srval = sval_str[half as usize..]
+            .parse::<i64>()
+            .expect("Failed to parse right half");
+        Some((slval, srval))
+    } else {
+        None
+    }
+}
+
+pub fn blink(stones: &mut StonesMap) {
+    let mut new_stones = HashMap::new();
+    stones.iter().for_each(|(sval, num)| {
+        if *sval == 0 {
+            *new_stones.entry(1).or_insert(0) += *num;
+        } else if let Some((slval, srval)) = split_stone_val(*sval) {
+            *new_stones.entry(slval).or_insert(0) += *num;
+            *new_stones.entry(srval).or_insert(0) += *num;
+        } else {
+            *new_stones.entry(*sval * 2024).or_insert(0) += *num;
+        }
+    });
+    *stones = new_stones;
+}
+
+pub fn count_stones_after_blinks(stones: &StonesMap, blinks: usize) -> usize {
+    let mut stones = stones.clone();
+    for _ in 0..blinks {
+        blink(&mut stones);
+    }
+    stones.values().sum()
+}
+
+impl Solution for Day11 {
+    type Input = StonesMap;
+
+    const DAY: u8 = 11;
+    const TITLE: &'static str = "Plutonian Pebbles";
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        let mut stones = StonesMap::new();
+        for (i, line) in input.lines().enumerate() {
+            for s in line.split_whitespace() {
+                let stone = parse_number(line, s).map_err(|e| e.at_line(i + 1))?;
+                *stones.entry(stone).or_default() += 1;
+            }
+        }
+        Ok(stones)
+    }
+
+    fn validate(&self, input: &str) -> Vec<Error> {
+        let mut errors = validate::non_empty(input);
+        errors.extend(validate::each_line(input, |line| {
+            line.split_whitespace().try_for_each(|s| {
+                let stone: i64 = parse_number(line, s)?;
+                if stone < 0 {
+                    return Err(Error::at_token(line, s, "Negative stone number"));
+                }
+                Ok(())
+            })
+        }));
+        errors
+    }
+
+    fn part1(&self, stones: &Self::Input) -> impl Display {
+        count_stones_after_blinks(stones, self.part1_blinks)
+    }
+
+    fn part2(&self, stones: &Self::Input) -> impl Display {
+        count_stones_after_blinks(stones, self.part2_blinks)
+    }
+
+    fn params(&self) -> Vec<Param<Self>> {
+        vec![
+            Param {
+                name: "part1_blinks",
+                description: "Number of blinks in part 1",
+                get: |day| day.part1_blinks.to_string(),
+                set: |day, value| parse_param(value).map(|v| day.part1_blinks = v),
+            },
+            Param {
+                name: "part2_blinks",
+                description: "Number of blinks in part 2",
+                get: |day| day.part2_blinks.to_string(),
+                set: |day, value| parse_param(value).map(|v| day.part2_blinks = v),
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::{example_answer, Part};
+
+    #[test]
+    fn test_example() {
+        // Part 2 has no published answer for the example; this is the value the solution gives.
+        let day = Day11::default();
+        assert_eq!(example_answer(&day, "day11.txt", Part::One), "55312");
+        assert_eq!(
+            example_answer(&day, "day11.txt", Part::Two),
+            "65601038650482"
+        );
+    }
+
+    #[test]
+    fn test_split_stone_val() {
+        assert_eq!(split_stone_val(1000), Some((10, 0)));
+        // Add more test cases as needed
+    }
+
+    #[test]
+    fn test_validate() {
+        let errors = Day11::default().validate("125 -17 x\n");
+        let messages: Vec<String> = errors.iter().map(Error::to_string).collect();
+        assert_eq!(messages, ["1:5: Negative stone number"]);
+        assert_eq!(Day11::default().validate("").len(), 1);
+    }
+}
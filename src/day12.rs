@@ -0,0 +1,214 @@
+use std::{
+    collections::{BTreeMap, HashSet},
+    fmt::Display,
+};
+
+use crate::{
+    error::{Error, Result},
+    solution::Solution,
+    utils::{
+        geom::Dir4,
+        graph::Graph,
+        grid::Grid,
+        render::{Cell, Color, Frame},
+        validate,
+    },
+};
+
+pub struct Day12;
+
+type PlotsSet = HashSet<(i64, i64, char)>;
+
+pub struct Region {
+    plot_type: char,
+    plots: PlotsSet,
+}
+
+fn sides_in_line(mut lines: Vec<i64>) -> usize {
+    lines.sort();
+    let mut sides = 1;
+    if lines.len() > 1 {
+        for i in 1..lines.len() {
+            if lines[i] - lines[i - 1] > 1 {
+                sides += 1;
+            }
+        }
+    }
+    sides
+}
+
+impl Region {
+    pub fn area(&self) -> usize {
+        self.plots.len()
+    }
+
+    pub fn perimeter(&self) -> usize {
+        let mut perimeter = 0;
+        for (x, y, _) in &self.plots {
+            if !self.plots.contains(&(x - 1, *y, self.plot_type)) {
+                perimeter += 1;
+            }
+            if !self.plots.contains(&(x + 1, *y, self.plot_type)) {
+                perimeter += 1;
+            }
+            if !self.plots.contains(&(*x, y - 1, self.plot_type)) {
+                perimeter += 1;
+            }
+            if !self.plots.contains(&(*x, y + 1, self.plot_type)) {
+                perimeter += 1;
+            }
+        }
+        perimeter
+    }
+
+    pub fn price(&self) -> usize {
+        self.area() * self.perimeter()
+    }
+
+    pub fn number_of_sides(&self) -> usize {
+        let c = self.plot_type;
+        let mut top_plots: BTreeMap<i64, Vec<i64>> = BTreeMap::new();
+        let mut bottom_plots: BTreeMap<i64, Vec<i64>> = BTreeMap::new();
+        let mut left_plots: BTreeMap<i64, Vec<i64>> = BTreeMap::new();
+        let mut right_plots: BTreeMap<i64, Vec<i64>> = BTreeMap::new();
+        self.plots.iter().for_each(|(x, y, _)| {
+            if !self.plots.contains(&(*x, y - 1, c)) {
+                // Top side
+                top_plots.entry(*y).or_default().push(*x);
+            }
+            if !self.plots.contains(&(*x, y + 1, c)) {
+                // Bottom side
+                bottom_plots.entry(*y).or_default().push(*x);
+            }
+            if !self.plots.contains(&(x - 1, *y, c)) {
+                // Left side
+                left_plots.entry(*x).or_default().push(*y);
+            }
+            if !self.plots.contains(&(x + 1, *y, c)) {
+                // Right side
+                right_plots.entry(*x).or_default().push(*y);
+            }
+        });
+        let mut sides = 0;
+        top_plots.into_values().for_each(|lines| {
+            sides += sides_in_line(lines);
+        });
+        bottom_plots.into_values().for_each(|lines| {
+            sides += sides_in_line(lines);
+        });
+        left_plots.into_values().for_each(|lines| {
+            sides += sides_in_line(lines);
+        });
+        right_plots.into_values().for_each(|lines| {
+            sides += sides_in_line(lines);
+        });
+        sides
+    }
+
+    pub fn discounted_price(&self) -> usize {
+        self.area() * self.number_of_sides()
+    }
+}
+
+pub fn build_regions(grid: &Grid<char>) -> Vec<Region> {
+    // Plots are connected if they are next to each other and grow the same type of plant.
+    let mut graph = Graph::new();
+    for (pos, &c) in grid.iter() {
+        graph.add_vertex(pos);
+        for np in grid.neighbors4(pos).filter(|&np| grid[np] == c) {
+            graph.add_edge(pos, np);
+        }
+    }
+    graph
+        .components()
+        .into_iter()
+        .map(|component| {
+            let plot_type = grid[*graph.vertex(component[0])];
+            let plots = component
+                .into_iter()
+                .map(|id| {
+                    let (x, y) = *graph.vertex(id);
+                    (x, y, plot_type)
+                })
+                .collect();
+            Region { plot_type, plots }
+        })
+        .collect()
+}
+
+/// Each region in its own color, with fences drawn around it.
+pub fn render_regions(regions: &[Region]) -> Frame {
+    let plots = || regions.iter().flat_map(|region| region.plots.iter());
+    let width = plots().map(|&(x, _, _)| x + 1).max().unwrap_or(0);
+    let height = plots().map(|&(_, y, _)| y + 1).max().unwrap_or(0);
+    let mut frame = Frame::filled(
+        width as usize,
+        height as usize,
+        Cell::new(' ', Color::BLACK),
+    );
+    for (i, region) in regions.iter().enumerate() {
+        for &(x, y, c) in &region.plots {
+            frame.set((x, y), c, Color::palette(i));
+            for side in Dir4::ALL {
+                let (nx, ny) = side.step((x, y));
+                if !region.plots.contains(&(nx, ny, c)) {
+                    frame.edge((x, y), side, Color::BLACK);
+                }
+            }
+        }
+    }
+    frame
+}
+
+impl Solution for Day12 {
+    type Input = Vec<Region>;
+
+    const DAY: u8 = 12;
+    const TITLE: &'static str = "Garden Groups";
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(build_regions(&Grid::parse(input)?))
+    }
+
+    fn validate(&self, input: &str) -> Vec<Error> {
+        validate::grid(input, |ch| ch.is_ascii_uppercase())
+    }
+
+    fn part1(&self, regions: &Self::Input) -> impl Display {
+        regions.iter().map(|r| r.price()).sum::<usize>()
+    }
+
+    fn part2(&self, regions: &Self::Input) -> impl Display {
+        regions.iter().map(|r| r.discounted_price()).sum::<usize>()
+    }
+
+    fn render(&self, regions: &Self::Input) -> Vec<(String, Frame)> {
+        vec![("regions".to_string(), render_regions(regions))]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::{example_answer, Part};
+
+    #[test]
+    fn test_example() {
+        let day = Day12;
+        assert_eq!(example_answer(&day, "day12.txt", Part::One), "1930");
+        assert_eq!(example_answer(&day, "day12.txt", Part::Two), "1206");
+    }
+
+    #[test]
+    fn test_validate() {
+        let errors = Day12.validate("AAB\nA.B\nCC\n");
+        let messages: Vec<String> = errors.iter().map(Error::to_string).collect();
+        assert_eq!(
+            messages,
+            [
+                "2:2: Invalid character '.' in grid",
+                "3:3: Expected 3 columns, got 2"
+            ]
+        );
+    }
+}
@@ -0,0 +1,361 @@
+use std::fmt::Display;
+
+use crate::{
+    error::{Error, Result},
+    solution::{parse_param, Implementation, Param, Part, Solution},
+    utils::{
+        math::{ext_gcd, solve_2x2},
+        parse::{blocks, fixed_integers},
+        validate,
+    },
+};
+
+pub struct Day13 {
+    /// How much further away the prizes are in part 2, along both axes.
+    pub prize_offset: u64,
+}
+
+impl Default for Day13 {
+    fn default() -> Self {
+        Self {
+            prize_offset: 10000000000000,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ClawMachine {
+    prize: (u64, u64),
+    a_dir: (u64, u64),
+    b_dir: (u64, u64),
+}
+
+fn cost(a: u64, b: u64) -> u64 {
+    3 * a + b
+}
+
+impl ClawMachine {
+    pub fn new(
+        prize_x: u64,
+        prize_y: u64,
+        a_dir_x: u64,
+        a_dir_y: u64,
+        b_dir_x: u64,
+        b_dir_y: u64,
+    ) -> Self {
+        ClawMachine {
+            prize: (prize_x, prize_y),
+            a_dir: (a_dir_x, a_dir_y),
+            b_dir: (b_dir_x, b_dir_y),
+        }
+    }
+
+    pub fn with_prize_offset(&self, prize_offset: u64) -> Self {
+        ClawMachine {
+            prize: (self.prize.0 + prize_offset, self.prize.1 + prize_offset),
+            ..self.clone()
+        }
+    }
+
+    /// Whether pressing the buttons `a` and `b` times moves the claw exactly onto the prize.
+    fn reaches_prize(&self, a: u64, b: u64) -> bool {
+        let x = a as u128 * self.a_dir.0 as u128 + b as u128 * self.b_dir.0 as u128;
+        let y = a as u128 * self.a_dir.1 as u128 + b as u128 * self.b_dir.1 as u128;
+        (x, y) == (self.prize.0 as u128, self.prize.1 as u128)
+    }
+
+    pub fn solve_min_tokens(&self, max_presses_each: Option<u64>) -> Option<u64> {
+        let [prize_x, prize_y, a_dir_x, a_dir_y, b_dir_x, b_dir_y] = [
+            self.prize.0,
+            self.prize.1,
+            self.a_dir.0,
+            self.a_dir.1,
+            self.b_dir.0,
+            self.b_dir.1,
+        ]
+        .map(|v| v as i64);
+        let (a, b) = match solve_2x2(a_dir_x, b_dir_x, a_dir_y, b_dir_y, prize_x, prize_y) {
+            Some(presses) => presses,
+            // The buttons move the claw along the same line, so there may be several ways.
+            None if a_dir_x * b_dir_y == a_dir_y * b_dir_x => {
+                return self.solve_min_tokens_collinear(max_presses_each)
+            }
+            None => return None,
+        };
+        let max = max_presses_each.unwrap_or(u64::MAX) as i128;
+        let in_range = |presses: i64| (0..=max).contains(&(presses as i128));
+        (in_range(a) && in_range(b)).then(|| cost(a as u64, b as u64))
+    }
+
+    /// The cheapest way to reach the prize, trying every number of presses up to
+    /// `max_presses_each`.
+    pub fn min_tokens_brute_force(&self, max_presses_each: u64) -> Option<u64> {
+        (0..=max_presses_each)
+            .flat_map(|a| (0..=max_presses_each).map(move |b| (a, b)))
+            .filter(|&(a, b)| self.reaches_prize(a, b))
+            .map(|(a, b)| cost(a, b))
+            .min()
+    }
+
+    /// The cheapest way to reach the prize when both buttons move along the same line.
+    ///
+    /// All solutions of `a * u + b * v == w` along one axis are `a0 + k * v / g` and
+    /// `b0 - k * u / g`, and the cost is linear in `k`, so the best `k` is at one end of the range
+    /// that keeps both press counts within bounds.
+    fn solve_min_tokens_collinear(&self, max_presses_each: Option<u64>) -> Option<u64> {
+        let (u, v, w) = if self.a_dir.0 != 0 || self.b_dir.0 != 0 {
+            (self.a_dir.0, self.b_dir.0, self.prize.0)
+        } else if self.a_dir.1 != 0 || self.b_dir.1 != 0 {
+            (self.a_dir.1, self.b_dir.1, self.prize.1)
+        } else {
+            return (self.prize == (0, 0)).then_some(0);
+        };
+        let [u, v, w] = [u, v, w].map(|n| n as i64);
+        let (g, s, t) = ext_gcd(u, v);
+        if w % g != 0 {
+            return None;
+        }
+        let (a0, b0) = (s as i128 * (w / g) as i128, t as i128 * (w / g) as i128);
+        let (da, db) = ((v / g) as i128, (u / g) as i128);
+        let max = max_presses_each.map(|max| max as i128);
+        let floor_div = |n: i128, d: i128| n.div_euclid(d);
+        let ceil_div = |n: i128, d: i128| -(-n).div_euclid(d);
+
+        // a = a0 + k * da and b = b0 - k * db must both be in 0..=max.
+        let mut lo = None::<i128>;
+        let mut hi = None::<i128>;
+        let mut bound = |new_lo: Option<i128>, new_hi: Option<i128>| {
+            lo = lo.max(new_lo);
+            hi = match (hi, new_hi) {
+                (Some(h), Some(n)) => Some(h.min(n)),
+                (h, n) => h.or(n),
+            };
+        };
+        if da > 0 {
+            bound(
+                Some(ceil_div(-a0, da)),
+                max.map(|max| floor_div(max - a0, da)),
+            );
+        } else if a0 < 0 || max.is_some_and(|max| a0 > max) {
+            return None;
+        }
+        if db > 0 {
+            bound(
+                max.map(|max| ceil_div(b0 - max, db)),
+                Some(floor_div(b0, db)),
+            );
+        } else if b0 < 0 || max.is_some_and(|max| b0 > max) {
+            return None;
+        }
+        if let (Some(lo), Some(hi)) = (lo, hi) {
+            if lo > hi {
+                return None;
+            }
+        }
+        // The cost 3 * a + b changes by 3 * da - db with each step of k.
+        let k = if 3 * da - db >= 0 { lo? } else { hi? };
+        let (a, b) = (a0 + k * da, b0 - k * db);
+        let (a, b) = (u64::try_from(a).ok()?, u64::try_from(b).ok()?);
+        self.reaches_prize(a, b).then(|| cost(a, b))
+    }
+}
+
+fn parse_coordinates(line: &str, label: &str) -> Result<(u64, u64)> {
+    if !line.starts_with(label) {
+        return Err(Error::parse(format!(
+            "Expected '{}', got '{}'",
+            label, line
+        )));
+    }
+    let [x, y] = fixed_integers(line)?;
+    Ok((x, y))
+}
+
+pub fn parse_claw_machine(block: &str) -> Result<ClawMachine> {
+    let lines: Vec<&str> = block.lines().collect();
+    let &[button_a_str, button_b_str, prize_str] = lines.as_slice() else {
+        return Err(Error::parse(format!(
+            "Expected 3 lines per claw machine, got {}",
+            lines.len()
+        ))
+        .at_line(1));
+    };
+    let (button_a_x, button_a_y) =
+        parse_coordinates(button_a_str, "Button A:").map_err(|e| e.at_line(1))?;
+    let (button_b_x, button_b_y) =
+        parse_coordinates(button_b_str, "Button B:").map_err(|e| e.at_line(2))?;
+    let (prize_x, prize_y) = parse_coordinates(prize_str, "Prize:").map_err(|e| e.at_line(3))?;
+    Ok(ClawMachine::new(
+        prize_x, prize_y, button_a_x, button_a_y, button_b_x, button_b_y,
+    ))
+}
+
+/// Every problem with a claw machine, where [`parse_claw_machine`] stops at the first.
+fn validate_claw_machine(block: &str) -> Vec<Error> {
+    let lines: Vec<&str> = block.lines().collect();
+    let mut errors = Vec::new();
+    if lines.len() != 3 {
+        errors.push(
+            Error::parse(format!(
+                "Expected 3 lines per claw machine, got {}",
+                lines.len()
+            ))
+            .at_line(1),
+        );
+    }
+    for (i, (line, label)) in lines
+        .iter()
+        .zip(["Button A:", "Button B:", "Prize:"])
+        .enumerate()
+    {
+        if let Err(e) = parse_coordinates(line, label) {
+            errors.push(e.at_line(i + 1));
+        }
+    }
+    errors
+}
+
+pub fn total_cost(claw_machines: &[ClawMachine], max_presses_each: Option<u64>) -> u64 {
+    claw_machines
+        .iter()
+        .filter_map(|claw_machine| claw_machine.solve_min_tokens(max_presses_each))
+        .sum()
+}
+
+impl Solution for Day13 {
+    type Input = Vec<ClawMachine>;
+
+    const DAY: u8 = 13;
+    const TITLE: &'static str = "Claw Contraption";
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        blocks(input)
+            .iter()
+            .map(|block| block.parse_with(parse_claw_machine))
+            .collect()
+    }
+
+    fn validate(&self, input: &str) -> Vec<Error> {
+        blocks(input)
+            .iter()
+            .flat_map(|block| validate::in_block(block, validate_claw_machine))
+            .collect()
+    }
+
+    fn part1(&self, claw_machines: &Self::Input) -> impl Display {
+        total_cost(claw_machines, Some(100))
+    }
+
+    fn part2(&self, claw_machines: &Self::Input) -> impl Display {
+        let claw_machines: Vec<_> = claw_machines
+            .iter()
+            .map(|claw_machine| claw_machine.with_prize_offset(self.prize_offset))
+            .collect();
+        total_cost(&claw_machines, None)
+    }
+
+    fn implementations(&self) -> Vec<Implementation<Self>> {
+        vec![Implementation {
+            name: "brute-force",
+            part: Part::One,
+            solve: |_, claw_machines| {
+                let total: u64 = claw_machines
+                    .iter()
+                    .filter_map(|claw_machine| claw_machine.min_tokens_brute_force(100))
+                    .sum();
+                total.to_string()
+            },
+        }]
+    }
+
+    fn params(&self) -> Vec<Param<Self>> {
+        vec![Param {
+            name: "prize_offset",
+            description: "Added to both coordinates of each prize in part 2",
+            get: |day| day.prize_offset.to_string(),
+            set: |day, value| parse_param(value).map(|v| day.prize_offset = v),
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        generate,
+        solution::{example_answer, Part},
+        utils::property,
+    };
+
+    #[test]
+    fn test_example() {
+        // Part 2 has no published answer for the example; this is the value the solution gives.
+        let day = Day13::default();
+        assert_eq!(example_answer(&day, "day13.txt", Part::One), "480");
+        assert_eq!(example_answer(&day, "day13.txt", Part::Two), "875318608908");
+    }
+
+    #[test]
+    fn test_collinear_buttons() {
+        let machine = ClawMachine::new(12, 24, 2, 4, 3, 6);
+        assert_eq!(machine.solve_min_tokens(None), Some(4));
+        assert_eq!(machine.solve_min_tokens(Some(3)), Some(11));
+        assert_eq!(machine.solve_min_tokens(Some(2)), None);
+        assert_eq!(
+            ClawMachine::new(1, 2, 2, 4, 4, 8).solve_min_tokens(None),
+            None
+        );
+        assert_eq!(
+            ClawMachine::new(12, 25, 2, 4, 3, 6).solve_min_tokens(None),
+            None
+        );
+    }
+
+    #[test]
+    fn test_validate() {
+        let input = "Button A: X+94, Y+34\nButton B: X+22\nPrize: X=8400, Y=5400\n\n\
+                     Button A: X+26, Y+66\nPrize: X=12748, Y=12176\n";
+        let errors = Day13::default().validate(input);
+        let messages: Vec<String> = errors.iter().map(Error::to_string).collect();
+        assert_eq!(
+            messages,
+            [
+                "2: Expected 2 numbers, got 1",
+                "5: Expected 3 lines per claw machine, got 2",
+                "6: Expected 'Button B:', got 'Prize: X=12748, Y=12176'"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_solver_agrees_with_brute_force() {
+        property::check(
+            &Day13::default(),
+            "min-tokens",
+            |rng| {
+                let count = rng.between(1, 4) as usize;
+                generate::claw_machines(rng, count, 12)
+            },
+            |input| {
+                [
+                    property::remove_blocks(input),
+                    property::smaller_numbers(input),
+                ]
+                .concat()
+            },
+            |machines| {
+                for machine in machines {
+                    let solved = machine.solve_min_tokens(Some(100));
+                    let brute_force = machine.min_tokens_brute_force(100);
+                    if solved != brute_force {
+                        return Err(format!(
+                            "{:?}: solved {:?}, brute force {:?}",
+                            machine, solved, brute_force
+                        ));
+                    }
+                }
+                Ok(())
+            },
+        );
+    }
+}
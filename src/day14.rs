@@ -0,0 +1,309 @@
+use std::{collections::HashMap, fmt::Display};
+
+use crate::{
+    error::{Error, Result},
+    solution::{parse_param, parse_param_at_least, Param, Part, Solution},
+    utils::{
+        geom::Vec2,
+        grid::Grid,
+        math::crt,
+        parse::{fixed_integers, parse_lines},
+        record::Recorder,
+        render::{Cell, Color, Frame},
+        validate,
+    },
+};
+
+pub struct Day14 {
+    /// Size of the room the robots move in.
+    pub width: i64,
+    pub height: i64,
+    /// How long the robots move before the safety factor of part 1 is measured.
+    pub seconds: i64,
+}
+
+impl Default for Day14 {
+    fn default() -> Self {
+        Self {
+            width: 101,
+            height: 103,
+            seconds: 100,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Robot {
+    position: Vec2,
+    velocity: Vec2,
+}
+
+pub fn parse_robot(line: &str) -> Result<Robot> {
+    if !line.starts_with("p=") || !line.contains(" v=") {
+        return Err(Error::parse("Expected 'p=X,Y v=X,Y'"));
+    }
+    let [px, py, vx, vy] = fixed_integers(line)?;
+    Ok(Robot {
+        position: Vec2::new(px, py),
+        velocity: Vec2::new(vx, vy),
+    })
+}
+
+pub struct Tiles {
+    robots: Vec<Robot>,
+    width: i64,
+    height: i64,
+}
+
+impl Tiles {
+    pub fn new(robots: Vec<Robot>, width: i64, height: i64) -> Tiles {
+        Tiles {
+            robots,
+            width,
+            height,
+        }
+    }
+
+    pub fn predict_positions_after(&mut self, seconds: i64) {
+        let size = Vec2::new(self.width, self.height);
+        for robot in self.robots.iter_mut() {
+            robot.position = (robot.position + robot.velocity * seconds).rem_euclid(size);
+        }
+    }
+
+    fn pos_to_quadrant(&self, (x, y): (usize, usize)) -> Option<usize> {
+        let mx = ((self.width - 1) / 2) as usize;
+        let my = ((self.height - 1) / 2) as usize;
+        if x < mx {
+            // Quadrant 1 or 3
+            if y < my {
+                return Some(1);
+            } else if y > my {
+                return Some(3);
+            }
+        } else if x > mx {
+            // Quadrant 2 or 4
+            if y < my {
+                return Some(2);
+            } else if y > my {
+                return Some(4);
+            }
+        }
+        None
+    }
+
+    pub fn counts_per_quadrant(&self) -> HashMap<usize, usize> {
+        let mut counts = HashMap::new();
+        for robot in self.robots.iter() {
+            let pos = (robot.position.x as usize, robot.position.y as usize);
+            if let Some(quadrant) = self.pos_to_quadrant(pos) {
+                let count = counts.entry(quadrant).or_insert(0);
+                *count += 1;
+            }
+        }
+        counts
+    }
+
+    /// The room with the number of robots on each tile.
+    pub fn render(&self) -> Frame {
+        let mut counts = Grid::new(self.width as usize, self.height as usize, 0);
+        for robot in &self.robots {
+            counts[robot.position.into()] += 1;
+        }
+        Frame::from_grid(&counts, |_, &count| match count {
+            0 => Cell::new('.', Color::DARK_GRAY),
+            1..=9 => Cell::new(char::from_digit(count, 10).unwrap(), Color::BLACK)
+                .with_highlight(Color::GREEN),
+            _ => Cell::new('+', Color::BLACK).with_highlight(Color::GREEN),
+        })
+    }
+
+    pub fn highest_quadrant_concentration(&self) -> f64 {
+        let counts = self.counts_per_quadrant();
+        let max_counts_per_quadrant = *counts.values().max().unwrap();
+        max_counts_per_quadrant as f64 / self.robots.len() as f64
+    }
+}
+
+impl Solution for Day14 {
+    type Input = Vec<Robot>;
+
+    const DAY: u8 = 14;
+    const TITLE: &'static str = "Restroom Redoubt";
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parse_lines(input, parse_robot)
+    }
+
+    fn validate(&self, input: &str) -> Vec<Error> {
+        let mut errors = validate::non_empty(input);
+        errors.extend(validate::each_line(input, |line| {
+            let Vec2 { x, y } = parse_robot(line)?.position;
+            if !(0..self.width).contains(&x) || !(0..self.height).contains(&y) {
+                return Err(Error::parse(format!(
+                    "Position {},{} is outside of the {}x{} room",
+                    x, y, self.width, self.height
+                )));
+            }
+            Ok(())
+        }));
+        errors
+    }
+
+    fn part1(&self, robots: &Self::Input) -> impl Display {
+        let mut tiles = Tiles::new(robots.clone(), self.width, self.height);
+        tiles.predict_positions_after(self.seconds);
+        let counts = tiles.counts_per_quadrant();
+        counts.values().product::<usize>()
+    }
+
+    fn part2(&self, robots: &Self::Input) -> impl Display {
+        match self.picture_time(robots) {
+            Some(time) => time.to_string(),
+            None => "no picture".to_string(),
+        }
+    }
+
+    fn render(&self, robots: &Self::Input) -> Vec<(String, Frame)> {
+        let picture = self.picture_time(robots).map(|t| ("tree".to_string(), t));
+        [(format!("robots-{}", self.seconds), self.seconds)]
+            .into_iter()
+            .chain(picture)
+            .map(|(name, seconds)| {
+                let mut tiles = Tiles::new(robots.to_vec(), self.width, self.height);
+                tiles.predict_positions_after(seconds);
+                (name, tiles.render())
+            })
+            .collect()
+    }
+
+    /// Records the robots moving for `seconds` in part 1, and until the picture in part 2.
+    fn record(&self, robots: &Self::Input, part: Part, recorder: &mut Recorder) {
+        let seconds = match part {
+            Part::One => self.seconds,
+            // Without a picture only the starting positions are recorded.
+            Part::Two => self.picture_time(robots).unwrap_or(0),
+        };
+        let mut tiles = Tiles::new(robots.to_vec(), self.width, self.height);
+        recorder.record(|| tiles.render());
+        for _ in 0..seconds {
+            tiles.predict_positions_after(1);
+            recorder.record(|| tiles.render());
+        }
+        recorder.finish(|| tiles.render());
+    }
+
+    fn params(&self) -> Vec<Param<Self>> {
+        vec![
+            Param {
+                name: "width",
+                description: "Width of the room",
+                get: |day| day.width.to_string(),
+                set: |day, value| parse_param_at_least(value, 1).map(|v| day.width = v),
+            },
+            Param {
+                name: "height",
+                description: "Height of the room",
+                get: |day| day.height.to_string(),
+                set: |day, value| parse_param_at_least(value, 1).map(|v| day.height = v),
+            },
+            Param {
+                name: "seconds",
+                description: "Seconds the robots move before part 1 is measured",
+                get: |day| day.seconds.to_string(),
+                set: |day, value| parse_param(value).map(|v| day.seconds = v),
+            },
+        ]
+    }
+}
+
+impl Day14 {
+    /// The first time the robots arrange into a picture, if the times they cluster on each axis
+    /// ever coincide.
+    pub fn picture_time(&self, robots: &[Robot]) -> Option<i64> {
+        // The x coordinates repeat every `width` seconds and the y coordinates every `height`
+        // seconds. The picture is where the robots cluster on both axes at the same time.
+        let time_x = (0..self.width)
+            .min_by_key(|&t| spread(robots, t, |r| (r.position.x, r.velocity.x), self.width))
+            .unwrap();
+        let time_y = (0..self.height)
+            .min_by_key(|&t| spread(robots, t, |r| (r.position.y, r.velocity.y), self.height))
+            .unwrap();
+        let (seconds, _) = crt(&[(time_x, self.width), (time_y, self.height)])?;
+        Some(seconds)
+    }
+}
+
+/// How far the robots are spread out along one axis after `seconds`, as the sum of squared
+/// distances from the mean (proportional to the variance).
+fn spread(robots: &[Robot], seconds: i64, axis: impl Fn(&Robot) -> (i64, i64), size: i64) -> i64 {
+    let coords: Vec<i64> = robots
+        .iter()
+        .map(|robot| {
+            let (pos, vel) = axis(robot);
+            (pos + vel * seconds).rem_euclid(size)
+        })
+        .collect();
+    let n = coords.len() as i64;
+    let sum: i64 = coords.iter().sum();
+    let sum_sq: i64 = coords.iter().map(|c| c * c).sum();
+    sum_sq - sum * sum / n.max(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::example_answer;
+
+    #[test]
+    fn test_example() {
+        // Part 2 looks for a picture, which the example doesn't contain.
+        let day = Day14 {
+            width: 11,
+            height: 7,
+            ..Day14::default()
+        };
+        assert_eq!(example_answer(&day, "day14.txt", Part::One), "12");
+    }
+
+    #[test]
+    fn test_params() {
+        use crate::solution::Runnable;
+
+        let mut day = Day14::default();
+        let err = day.set_param("width", "0").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid value for parameter 'width' of day 14: Expected at least 1, got '0'"
+        );
+        assert!(day.set_param("height", "-3").is_err());
+        assert_eq!((day.width, day.height), (101, 103));
+
+        // The robots meet along x after 9 seconds and along y after 0 seconds, and in a 10x4
+        // room an odd and an even time never coincide.
+        day.set_param("width", "10").unwrap();
+        day.set_param("height", "4").unwrap();
+        let robots = ["p=0,0 v=0,0", "p=1,0 v=1,1"].map(|line| parse_robot(line).unwrap());
+        assert_eq!(day.picture_time(&robots), None);
+        assert_eq!(day.part2(&robots.to_vec()).to_string(), "no picture");
+    }
+
+    #[test]
+    fn test_validate() {
+        let day = Day14 {
+            width: 11,
+            height: 7,
+            ..Day14::default()
+        };
+        let errors = day.validate("p=0,4 v=3,-3\np=11,3 v=1,1\np=2,0 v=2\n");
+        let messages: Vec<String> = errors.iter().map(Error::to_string).collect();
+        assert_eq!(
+            messages,
+            [
+                "2: Position 11,3 is outside of the 11x7 room",
+                "3: Expected 4 numbers, got 3"
+            ]
+        );
+        assert_eq!(day.validate("").len(), 1);
+    }
+}
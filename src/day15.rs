@@ -0,0 +1,311 @@
+use std::{collections::HashSet, fmt::Display};
+
+use crate::{
+    error::{Error, Result},
+    solution::{Part, Solution},
+    utils::{
+        geom::{Dir4, Vec2},
+        grid::{Grid, Pos},
+        parse::sections,
+        record::Recorder,
+        render::{Cell, Color, Frame},
+        validate,
+    },
+};
+
+pub struct Day15;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum GridCell {
+    Wall,
+    Open,
+    Package,
+    WPackageL,
+    WPackageR,
+}
+
+type AffectedPackages = (Pos, Vec<(Pos, GridCell)>);
+
+#[derive(Debug, Clone)]
+pub struct Warehouse {
+    grid: Grid<GridCell>,
+    robot_position: Pos,
+}
+
+impl Warehouse {
+    pub fn parse(input: &str) -> Result<Self> {
+        let mut robot_position = None;
+        let grid = Grid::parse_with(input, |pos, ch| match ch {
+            '#' => Some(GridCell::Wall),
+            'O' => Some(GridCell::Package),
+            '.' => Some(GridCell::Open),
+            '@' => {
+                robot_position = Some(pos);
+                Some(GridCell::Open)
+            }
+            _ => None,
+        })?;
+        let robot_position =
+            robot_position.ok_or_else(|| Error::parse("No robot '@' found in grid"))?;
+        Ok(Self {
+            grid,
+            robot_position,
+        })
+    }
+
+    /// The second warehouse, where everything except the robot is twice as wide.
+    pub fn widen(&self) -> Self {
+        let rows = self
+            .grid
+            .rows()
+            .map(|row| {
+                row.iter()
+                    .flat_map(|cell| match cell {
+                        GridCell::Package => [GridCell::WPackageL, GridCell::WPackageR],
+                        cell => [*cell, *cell],
+                    })
+                    .collect()
+            })
+            .collect();
+        let (x, y) = self.robot_position;
+        Self {
+            grid: Grid::from_rows(rows).expect("Widened grid is rectangular"),
+            robot_position: (2 * x, y),
+        }
+    }
+
+    fn search_affected_packages(&self, movement: Dir4) -> Option<AffectedPackages> {
+        let (x, y) = self.robot_position;
+        let Vec2 { x: dx, y: dy } = movement.delta();
+        let mut affected_packages = Vec::new();
+        let mut front = HashSet::from_iter([(x, y)]);
+        let mut can_move = true;
+        'outer: loop {
+            let mut new_front = HashSet::new();
+            for (fx, fy) in front.iter() {
+                let new_fx = fx + dx;
+                let new_fy = fy + dy;
+                match self.grid[(new_fx, new_fy)] {
+                    GridCell::Wall => {
+                        can_move = false;
+                        break 'outer;
+                    }
+                    GridCell::Open => {}
+                    GridCell::Package => {
+                        affected_packages.push(((new_fx, new_fy), GridCell::Package));
+                        new_front.insert((new_fx, new_fy));
+                    }
+                    GridCell::WPackageL => {
+                        if movement.is_horizontal() {
+                            affected_packages.push(((new_fx, new_fy), GridCell::WPackageL));
+                            new_front.insert((new_fx, new_fy));
+                        } else {
+                            assert!(self.grid[(new_fx + 1, new_fy)] == GridCell::WPackageR);
+                            affected_packages.push(((new_fx, new_fy), GridCell::WPackageL));
+                            affected_packages.push(((new_fx + 1, new_fy), GridCell::WPackageR));
+                            new_front.insert((new_fx, new_fy));
+                            new_front.insert((new_fx + 1, new_fy));
+                        }
+                    }
+                    GridCell::WPackageR => {
+                        if movement.is_horizontal() {
+                            affected_packages.push(((new_fx, new_fy), GridCell::WPackageR));
+                            new_front.insert((new_fx, new_fy));
+                        } else {
+                            assert!(self.grid[(new_fx - 1, new_fy)] == GridCell::WPackageL);
+                            affected_packages.push(((new_fx, new_fy), GridCell::WPackageR));
+                            affected_packages.push(((new_fx - 1, new_fy), GridCell::WPackageL));
+                            new_front.insert((new_fx, new_fy));
+                            new_front.insert((new_fx - 1, new_fy));
+                        }
+                    }
+                }
+            }
+            if new_front.is_empty() {
+                break;
+            }
+            front = new_front;
+        }
+        if can_move {
+            Some(((dx, dy), affected_packages))
+        } else {
+            None
+        }
+    }
+
+    pub fn move_robot(&mut self, movement: Dir4) {
+        if let Some(((dx, dy), affected_packages)) = self.search_affected_packages(movement) {
+            let (x, y) = self.robot_position;
+            let new_x = x + dx;
+            let new_y = y + dy;
+            for (package_pos, _) in affected_packages.iter() {
+                self.grid[*package_pos] = GridCell::Open;
+            }
+            for ((package_x, package_y), cell) in affected_packages.iter() {
+                self.grid[(package_x + dx, package_y + dy)] = *cell;
+            }
+            self.robot_position = (new_x, new_y);
+        }
+    }
+
+    pub fn package_coordinates(&self) -> Vec<usize> {
+        self.grid
+            .iter()
+            .filter(|(_, cell)| matches!(cell, GridCell::Package | GridCell::WPackageL))
+            .map(|((x, y), _)| y as usize * 100 + x as usize)
+            .collect()
+    }
+
+    /// The walls, the boxes and the robot.
+    pub fn render(&self) -> Frame {
+        let mut frame = Frame::from_grid(&self.grid, |_, cell| match cell {
+            GridCell::Wall => Cell::new('#', Color::GRAY),
+            GridCell::Open => Cell::new('.', Color::DARK_GRAY),
+            GridCell::Package => Cell::new('O', Color::ORANGE),
+            GridCell::WPackageL => Cell::new('[', Color::ORANGE),
+            GridCell::WPackageR => Cell::new(']', Color::ORANGE),
+        });
+        frame.set(self.robot_position, '@', Color::RED);
+        frame
+    }
+}
+
+/// The warehouse after the robot has made all its moves.
+pub fn warehouse_after_moves(warehouse: &Warehouse, movements: &[Dir4]) -> Warehouse {
+    let mut warehouse = warehouse.clone();
+    for movement in movements {
+        warehouse.move_robot(*movement);
+    }
+    warehouse
+}
+
+pub fn sum_gps_coords_after_moves(warehouse: &Warehouse, movements: &[Dir4]) -> usize {
+    warehouse_after_moves(warehouse, movements)
+        .package_coordinates()
+        .iter()
+        .sum()
+}
+
+pub fn parse_movements(input: &str) -> Result<Vec<Dir4>> {
+    let mut movements = Vec::new();
+    for (y, line) in input.lines().enumerate() {
+        for (x, ch) in line.chars().enumerate() {
+            let movement = Dir4::from_char(ch).ok_or_else(|| {
+                Error::at(y + 1, x + 1, format!("Invalid movement character '{}'", ch))
+            })?;
+            movements.push(movement);
+        }
+    }
+    Ok(movements)
+}
+
+impl Solution for Day15 {
+    /// The warehouse, its wide version and the robot's movements.
+    type Input = (Warehouse, Warehouse, Vec<Dir4>);
+
+    const DAY: u8 = 15;
+    const TITLE: &'static str = "Warehouse Woes";
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        let [grid, movements] = sections(input)?;
+        let warehouse = grid.parse_with(Warehouse::parse)?;
+        let wide_warehouse = warehouse.widen();
+        let movements = movements.parse_with(parse_movements)?;
+        Ok((warehouse, wide_warehouse, movements))
+    }
+
+    fn validate(&self, input: &str) -> Vec<Error> {
+        let [grid, movements] = match sections(input) {
+            Ok(sections) => sections,
+            Err(e) => return vec![e],
+        };
+        let mut errors = validate::in_block(&grid, |text| {
+            let mut errors = validate::grid(text, |ch| "#.O@".contains(ch));
+            errors.extend(validate::walled(text, '#'));
+            errors.extend(validate::exactly_one(text, '@', "robot"));
+            errors
+        });
+        errors.extend(validate::in_block(&movements, |text| {
+            text.lines()
+                .enumerate()
+                .flat_map(|(y, line)| {
+                    line.chars()
+                        .enumerate()
+                        .filter(|&(_, ch)| Dir4::from_char(ch).is_none())
+                        .map(move |(x, ch)| {
+                            Error::at(y + 1, x + 1, format!("Invalid movement character '{}'", ch))
+                        })
+                })
+                .collect()
+        }));
+        errors
+    }
+
+    fn part1(&self, (warehouse, _, movements): &Self::Input) -> impl Display {
+        sum_gps_coords_after_moves(warehouse, movements)
+    }
+
+    fn part2(&self, (_, wide_warehouse, movements): &Self::Input) -> impl Display {
+        sum_gps_coords_after_moves(wide_warehouse, movements)
+    }
+
+    fn render(&self, (warehouse, wide_warehouse, movements): &Self::Input) -> Vec<(String, Frame)> {
+        vec![
+            (
+                "warehouse".to_string(),
+                warehouse_after_moves(warehouse, movements).render(),
+            ),
+            (
+                "wide-warehouse".to_string(),
+                warehouse_after_moves(wide_warehouse, movements).render(),
+            ),
+        ]
+    }
+
+    /// Records the robot's moves in the warehouse of the part.
+    fn record(
+        &self,
+        (warehouse, wide_warehouse, movements): &Self::Input,
+        part: Part,
+        recorder: &mut Recorder,
+    ) {
+        let mut warehouse = match part {
+            Part::One => warehouse.clone(),
+            Part::Two => wide_warehouse.clone(),
+        };
+        recorder.record(|| warehouse.render());
+        for &movement in movements {
+            warehouse.move_robot(movement);
+            recorder.record(|| warehouse.render());
+        }
+        recorder.finish(|| warehouse.render());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::example_answer;
+
+    #[test]
+    fn test_example() {
+        let day = Day15;
+        assert_eq!(example_answer(&day, "day15.txt", Part::One), "10092");
+        assert_eq!(example_answer(&day, "day15.txt", Part::Two), "9021");
+    }
+
+    #[test]
+    fn test_validate() {
+        let errors = Day15.validate("#####\n#.@.#\n#O@x#\n####\n\n<^x\n>v\n");
+        let messages: Vec<String> = errors.iter().map(Error::to_string).collect();
+        assert_eq!(
+            messages,
+            [
+                "3:4: Invalid character 'x' in grid",
+                "4:5: Expected 5 columns, got 4",
+                "3:3: Another robot '@', the first one is at 2:3",
+                "6:3: Invalid movement character 'x'"
+            ]
+        );
+    }
+}
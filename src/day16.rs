@@ -0,0 +1,238 @@
+use std::{collections::HashSet, fmt::Display};
+
+use crate::{
+    error::{Error, Result},
+    solution::Solution,
+    utils::{
+        geom::Dir4,
+        grid::{Grid, Pos},
+        render::{Cell, Color, Frame},
+        search::{bfs, dijkstra},
+        validate,
+    },
+};
+
+pub struct Day16;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Action {
+    RotateLeft,
+    RotateRight,
+    Forward,
+}
+
+impl Action {
+    fn incremental_score(&self) -> i64 {
+        match self {
+            Action::RotateLeft | Action::RotateRight => 1000,
+            Action::Forward => 1,
+        }
+    }
+
+    fn is_forward(&self) -> bool {
+        match self {
+            Action::RotateLeft | Action::RotateRight => false,
+            Action::Forward => true,
+        }
+    }
+
+    fn update_direction(&self, dir: Dir4) -> Dir4 {
+        match self {
+            Action::RotateLeft => dir.turn_left(),
+            Action::RotateRight => dir.turn_right(),
+            Action::Forward => dir,
+        }
+    }
+}
+
+/// Position and facing direction of the reindeer.
+type State = (Pos, Dir4);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    Wall,
+    Empty,
+}
+
+pub struct Maze {
+    grid: Grid<Tile>,
+    start_pos: Pos,
+    end_pos: Pos,
+}
+
+impl Maze {
+    pub fn parse(input: &str) -> Result<Self> {
+        let mut start_pos = None;
+        let mut end_pos = None;
+        let grid = Grid::parse_with(input, |pos, ch| match ch {
+            '#' => Some(Tile::Wall),
+            '.' => Some(Tile::Empty),
+            'S' => {
+                start_pos = Some(pos);
+                Some(Tile::Empty)
+            }
+            'E' => {
+                end_pos = Some(pos);
+                Some(Tile::Empty)
+            }
+            _ => None,
+        })?;
+        Ok(Maze {
+            grid,
+            start_pos: start_pos.ok_or_else(|| Error::parse("No start 'S' found in maze"))?,
+            end_pos: end_pos.ok_or_else(|| Error::parse("No end 'E' found in maze"))?,
+        })
+    }
+
+    fn is_empty(&self, pos: Pos) -> bool {
+        self.grid.get(pos) == Some(&Tile::Empty)
+    }
+
+    /// Whether there is any path from the start to the end, which the solver assumes.
+    fn end_is_reachable(&self) -> bool {
+        let search = bfs(self.start_pos, |&pos| {
+            Dir4::ALL
+                .into_iter()
+                .map(move |dir| dir.step(pos))
+                .filter(|&next| self.is_empty(next))
+        });
+        search.distance(&self.end_pos).is_some()
+    }
+
+    fn next_states(&self, &(pos, dir): &State) -> Vec<(State, i64)> {
+        [Action::Forward, Action::RotateLeft, Action::RotateRight]
+            .into_iter()
+            .filter_map(|action| {
+                let next_dir = action.update_direction(dir);
+                let next_pos = if action.is_forward() {
+                    next_dir.step(pos)
+                } else {
+                    pos
+                };
+                self.is_empty(next_pos)
+                    .then_some(((next_pos, next_dir), action.incremental_score()))
+            })
+            .collect()
+    }
+
+    /// The lowest score to reach the end, and the number of tiles on any of the best paths.
+    pub fn find_paths(&self) -> (i64, usize) {
+        let (best_score, best_tiles) = self.best_tiles();
+        (best_score, best_tiles.len())
+    }
+
+    /// The lowest score to reach the end, and the tiles on any of the best paths.
+    pub fn best_tiles(&self) -> (i64, HashSet<Pos>) {
+        let search = dijkstra((self.start_pos, Dir4::Right), |state| {
+            self.next_states(state)
+        });
+        let end_states: Vec<State> = Dir4::ALL.iter().map(|&dir| (self.end_pos, dir)).collect();
+        let best_score = end_states
+            .iter()
+            .filter_map(|state| search.distance(state))
+            .min()
+            .expect("No path found");
+        let best_end_states = end_states
+            .iter()
+            .filter(|state| search.distance(state) == Some(best_score));
+        let best_tiles: HashSet<Pos> = search
+            .states_on_optimal_paths(best_end_states)
+            .into_iter()
+            .map(|(pos, _)| pos)
+            .collect();
+        (best_score, best_tiles)
+    }
+
+    /// The maze with the tiles of the best paths highlighted.
+    pub fn render(&self) -> Frame {
+        let (_, best_tiles) = self.best_tiles();
+        let mut frame = Frame::from_grid(&self.grid, |_, tile| match tile {
+            Tile::Wall => Cell::new('#', Color::GRAY),
+            Tile::Empty => Cell::new('.', Color::DARK_GRAY),
+        });
+        for pos in best_tiles {
+            frame.set(pos, 'O', Color::BLACK);
+            frame.highlight(pos, Color::YELLOW);
+        }
+        frame.set(self.start_pos, 'S', Color::WHITE);
+        frame.highlight(self.start_pos, Color::GREEN);
+        frame.set(self.end_pos, 'E', Color::WHITE);
+        frame.highlight(self.end_pos, Color::RED);
+        frame
+    }
+}
+
+impl Solution for Day16 {
+    type Input = Maze;
+
+    const DAY: u8 = 16;
+    const TITLE: &'static str = "Reindeer Maze";
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Maze::parse(input)
+    }
+
+    fn validate(&self, input: &str) -> Vec<Error> {
+        let mut errors = validate::grid(input, |ch| "#.SE".contains(ch));
+        errors.extend(validate::walled(input, '#'));
+        errors.extend(validate::exactly_one(input, 'S', "start"));
+        errors.extend(validate::exactly_one(input, 'E', "end"));
+        if errors.is_empty() {
+            if let Ok(maze) = Maze::parse(input) {
+                if !maze.end_is_reachable() {
+                    errors.push(Error::parse(
+                        "The end 'E' can't be reached from the start 'S'",
+                    ));
+                }
+            }
+        }
+        errors
+    }
+
+    fn part1(&self, maze: &Self::Input) -> impl Display {
+        let (min_score, _) = maze.find_paths();
+        min_score
+    }
+
+    fn part2(&self, maze: &Self::Input) -> impl Display {
+        let (_, num_best_tiles) = maze.find_paths();
+        num_best_tiles
+    }
+
+    fn render(&self, maze: &Self::Input) -> Vec<(String, Frame)> {
+        vec![("best-paths".to_string(), maze.render())]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::{example_answer, Part};
+
+    #[test]
+    fn test_example() {
+        let day = Day16;
+        assert_eq!(example_answer(&day, "day16.txt", Part::One), "7036");
+        assert_eq!(example_answer(&day, "day16.txt", Part::Two), "45");
+    }
+
+    #[test]
+    fn test_validate() {
+        let errors = Day16.validate("#####\n#S#E#\n#####\n");
+        let messages: Vec<String> = errors.iter().map(Error::to_string).collect();
+        assert_eq!(
+            messages,
+            ["The end 'E' can't be reached from the start 'S'"]
+        );
+        let errors = Day16.validate("#####\n#S.S#\n.#E##\n");
+        let messages: Vec<String> = errors.iter().map(Error::to_string).collect();
+        assert_eq!(
+            messages,
+            [
+                "3:1: Expected a wall '#' on the border, got '.'",
+                "3:3: Expected a wall '#' on the border, got 'E'",
+                "2:4: Another start 'S', the first one is at 2:2"
+            ]
+        );
+    }
+}
@@ -0,0 +1,428 @@
+use std::fmt::Display;
+
+use itertools::Itertools;
+
+use crate::{
+    error::{parse_number, Error, Result},
+    solution::Solution,
+    utils::{
+        cancel,
+        parse::{key_value, sections},
+    },
+};
+
+pub struct Day17;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OpCode {
+    Adv,
+    Bxl,
+    Bst,
+    Jnz,
+    Bxc,
+    Out,
+    Bdv,
+    Cdv,
+}
+
+impl TryFrom<u8> for OpCode {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self> {
+        match value {
+            0 => Ok(OpCode::Adv),
+            1 => Ok(OpCode::Bxl),
+            2 => Ok(OpCode::Bst),
+            3 => Ok(OpCode::Jnz),
+            4 => Ok(OpCode::Bxc),
+            5 => Ok(OpCode::Out),
+            6 => Ok(OpCode::Bdv),
+            7 => Ok(OpCode::Cdv),
+            _ => Err(Error::parse(format!("Invalid opcode {}", value))),
+        }
+    }
+}
+
+impl OpCode {
+    /// Whether the operand of the instruction is a combo operand rather than a literal.
+    fn takes_combo(&self) -> bool {
+        !matches!(self, OpCode::Bxl | OpCode::Jnz | OpCode::Bxc)
+    }
+
+    fn get_operand_value(&self, computer: &Computer, opid: u8) -> u64 {
+        match self {
+            OpCode::Adv => Operand::new_combo(opid).value(computer),
+            OpCode::Bxl => Operand::new_literal(opid).value(computer),
+            OpCode::Bst => Operand::new_combo(opid).value(computer),
+            OpCode::Jnz => Operand::new_literal(opid).value(computer),
+            OpCode::Bxc => Operand::new_literal(opid).value(computer),
+            OpCode::Out => Operand::new_combo(opid).value(computer),
+            OpCode::Bdv => Operand::new_combo(opid).value(computer),
+            OpCode::Cdv => Operand::new_combo(opid).value(computer),
+        }
+    }
+
+    fn eval(&self, computer: &mut Computer, opid: u8) -> (bool, Option<u8>) {
+        let operand_value = self.get_operand_value(computer, opid);
+        match self {
+            OpCode::Adv => {
+                let num = computer.register_a;
+                let denum = u64::pow(2, operand_value as u32);
+                let result = num / denum;
+                computer.register_a = result;
+                (true, None)
+            }
+            OpCode::Bxl => {
+                let lhs = computer.register_b;
+                let rhs = operand_value;
+                let result = lhs ^ rhs;
+                computer.register_b = result;
+                (true, None)
+            }
+            OpCode::Bst => {
+                let result = operand_value % 8;
+                computer.register_b = result;
+                (true, None)
+            }
+            OpCode::Jnz => {
+                if computer.register_a != 0 {
+                    computer.instruction_pointer = operand_value as usize;
+                    (false, None)
+                } else {
+                    (true, None)
+                }
+            }
+            OpCode::Bxc => {
+                let lhs = computer.register_b;
+                let rhs = computer.register_c;
+                let result = lhs ^ rhs;
+                computer.register_b = result;
+                (true, None)
+            }
+            OpCode::Out => (true, Some((operand_value % 8) as u8)),
+            OpCode::Bdv => {
+                let num = computer.register_a;
+                let denum = u64::pow(2, operand_value as u32);
+                let result = num / denum;
+                computer.register_b = result;
+                (true, None)
+            }
+            OpCode::Cdv => {
+                let num = computer.register_a;
+                let denum = u64::pow(2, operand_value as u32);
+                let result = num / denum;
+                computer.register_c = result;
+                (true, None)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operand {
+    Literal(u8),
+    Combo(u8),
+}
+
+impl Operand {
+    fn new_literal(value: u8) -> Self {
+        Operand::Literal(value)
+    }
+
+    fn new_combo(value: u8) -> Self {
+        Operand::Combo(value)
+    }
+
+    fn value(&self, computer: &Computer) -> u64 {
+        match self {
+            Operand::Literal(value) => *value as u64,
+            Operand::Combo(value) => match value {
+                0..=3 => *value as u64,
+                4 => computer.register_a,
+                5 => computer.register_b,
+                6 => computer.register_c,
+                _ => unreachable!("Combo operand {} is rejected by Computer::new", value),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Computer {
+    register_a: u64,
+    register_b: u64,
+    register_c: u64,
+    instruction_pointer: usize,
+    program: Vec<u8>,
+}
+
+/// Checks that an instruction can run: a 3-bit opcode and operand, and no reserved combo operand.
+fn check_instruction(opcode: u8, operand: Option<u8>) -> Result<()> {
+    let opcode = OpCode::try_from(opcode)?;
+    match operand {
+        Some(operand @ 8..) => Err(Error::parse(format!("Invalid operand {}", operand))),
+        Some(7) if opcode.takes_combo() => Err(Error::parse("Combo operand 7 is reserved")),
+        _ => Ok(()),
+    }
+}
+
+fn parse_program(line: &str) -> Result<Vec<u8>> {
+    let (name, program) = key_value(line, ":")?;
+    if name != "Program" {
+        return Err(Error::parse(format!("Expected 'Program', got '{}'", name)));
+    }
+    let tokens: Vec<&str> = program.split(',').collect();
+    let program = tokens
+        .iter()
+        .map(|&s| match parse_number(line, s)? {
+            n @ 0..=7 => Ok(n),
+            n => Err(Error::at_token(
+                line,
+                s,
+                format!("Invalid 3-bit number {}", n),
+            )),
+        })
+        .collect::<Result<Vec<u8>>>()?;
+    for (i, instruction) in program.chunks(2).enumerate() {
+        check_instruction(instruction[0], instruction.get(1).copied())
+            .map_err(|e| Error::at_token(line, tokens[2 * i + 1], e.to_string()))?;
+    }
+    Ok(program)
+}
+
+impl Computer {
+    /// A computer with the given registers, checking that every instruction of `program` can run.
+    pub fn new(registers: [u64; 3], program: Vec<u8>) -> Result<Self> {
+        if program.is_empty() {
+            return Err(Error::parse("Empty program"));
+        }
+        for instruction in program.chunks(2) {
+            check_instruction(instruction[0], instruction.get(1).copied())?;
+        }
+        let [register_a, register_b, register_c] = registers;
+        Ok(Computer {
+            register_a,
+            register_b,
+            register_c,
+            instruction_pointer: 0,
+            program,
+        })
+    }
+
+    /// The values of registers A, B and C.
+    pub fn registers(&self) -> [u64; 3] {
+        [self.register_a, self.register_b, self.register_c]
+    }
+
+    pub fn program(&self) -> &[u8] {
+        &self.program
+    }
+
+    /// A copy of the computer, reset to the start of the program, with register A set to `a`.
+    pub fn with_register_a(&self, a: u64) -> Self {
+        Computer {
+            register_a: a,
+            instruction_pointer: 0,
+            ..self.clone()
+        }
+    }
+
+    pub fn parse(input: &str) -> Result<Self> {
+        let [registers, program] = sections(input)?;
+        let mut names = ["Register A", "Register B", "Register C"].into_iter();
+        let registers = registers.parse_lines(|line| {
+            let (name, value) = key_value(line, ":")?;
+            let expected = names
+                .next()
+                .ok_or_else(|| Error::parse("Expected 3 registers"))?;
+            if name != expected {
+                return Err(Error::parse(format!(
+                    "Expected '{}', got '{}'",
+                    expected, name
+                )));
+            }
+            parse_number(line, value)
+        })?;
+        let [register_a, register_b, register_c] = registers[..] else {
+            return Err(Error::parse("Expected 3 registers"));
+        };
+        let program = program.parse_lines(parse_program)?.concat();
+
+        Computer::new([register_a, register_b, register_c], program)
+    }
+
+    /// Runs the program until it halts and returns its output.
+    ///
+    /// Programs that jump back unconditionally never halt, so it checks for cancellation on every
+    /// instruction.
+    pub fn run(&mut self) -> Vec<u8> {
+        let mut output = Vec::new();
+        while self.instruction_pointer < self.program.len() - 1 {
+            cancel::check();
+            let opcode = OpCode::try_from(self.program[self.instruction_pointer])
+                .expect("Opcodes are checked by Computer::new");
+            let opid = self.program[self.instruction_pointer + 1];
+            let (incr_ip, out) = opcode.eval(self, opid);
+            if incr_ip {
+                self.instruction_pointer += 2;
+            }
+            if let Some(out) = out {
+                output.push(out);
+            }
+        }
+        output
+    }
+}
+
+/// The lowest value of register A for which the program outputs itself, if there is one.
+pub fn reconstruct_program(computer: &Computer) -> Option<u64> {
+    /* Idea is to build up a stack of 3-bit blocks that make up a.
+    Iterating backwards through the program we test which 3-bit block
+    reproduces the program output to the end, then put that block on the stack.
+    If no block works, retract the last block and try with a higher block value.
+    */
+    let pn = computer.program.len();
+    let mut a_stack = Vec::new();
+    let mut min_ai = 0;
+    loop {
+        cancel::check();
+        let i = a_stack.len();
+        let pi = pn - i - 1;
+        let a = a_from_stack(&a_stack);
+        let mut found_next = false;
+        for ai in min_ai..8 {
+            let a_test = (a << 3) | ai as u64;
+            if check_a(a_test, pi, computer) {
+                a_stack.push(ai);
+                found_next = true;
+                break;
+            }
+        }
+        if !found_next {
+            // Retract the blocks that have no higher value left to try, and give up once none
+            // are left.
+            let last_ai = loop {
+                let ai = a_stack.pop()?;
+                if ai < 7 {
+                    break ai;
+                }
+            };
+            min_ai = last_ai + 1;
+        } else {
+            min_ai = 0;
+            if pi == 0 {
+                break;
+            }
+        }
+    }
+    Some(a_from_stack(&a_stack))
+}
+
+fn a_from_stack(a_stack: &[u8]) -> u64 {
+    let mut a = 0;
+    for &val in a_stack.iter() {
+        a <<= 3;
+        a |= val as u64;
+    }
+    a
+}
+
+fn check_a(a: u64, pi: usize, computer: &Computer) -> bool {
+    let mut comp = computer.with_register_a(a);
+    let output = comp.run();
+    let psegment = &comp.program[pi..];
+    if output.len() != psegment.len() {
+        return false;
+    }
+    output.iter().zip(psegment.iter()).all(|(o, p)| *o == *p)
+}
+
+impl Solution for Day17 {
+    type Input = Computer;
+
+    const DAY: u8 = 17;
+    const TITLE: &'static str = "Chronospatial Computer";
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Computer::parse(input)
+    }
+
+    fn validate(&self, input: &str) -> Vec<Error> {
+        let computer = match Computer::parse(input) {
+            Ok(computer) => computer,
+            Err(e) => return vec![e],
+        };
+        match computer.program[..] {
+            // The computer halts before an opcode without operand, so it never runs.
+            [.., opcode] if computer.program.len() % 2 == 1 => {
+                let [_, program] = sections(input).expect("Computer::parse checks the sections");
+                vec![Error::parse(format!(
+                    "The last opcode {} has no operand and never runs",
+                    opcode
+                ))
+                .at_line(program.line)]
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    fn part1(&self, computer: &Self::Input) -> impl Display {
+        computer.clone().run().iter().join(",")
+    }
+
+    fn part2(&self, computer: &Self::Input) -> impl Display {
+        match reconstruct_program(computer) {
+            Some(a) => a.to_string(),
+            None => "no answer".to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::{example_answer, Part};
+
+    #[test]
+    fn test_example() {
+        let day = Day17;
+        assert_eq!(
+            example_answer(&day, "day17.txt", Part::One),
+            "4,6,3,5,6,3,5,2,1,0"
+        );
+        assert_eq!(example_answer(&day, "day17-2.txt", Part::Two), "117440");
+    }
+
+    #[test]
+    fn test_invalid_programs() {
+        let err =
+            Computer::parse("Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 0,3,5,7\n")
+                .unwrap_err();
+        assert_eq!(err.to_string(), "5:16: Combo operand 7 is reserved");
+        assert!(Computer::new([0, 0, 0], vec![1, 7]).is_ok());
+        assert!(Computer::new([0, 0, 0], vec![8, 0]).is_err());
+        assert!(Computer::new([0, 0, 0], vec![]).is_err());
+
+        let computer =
+            Computer::parse(&std::fs::read_to_string("examples/day17.txt").unwrap()).unwrap();
+        assert_eq!(reconstruct_program(&computer), None);
+        assert_eq!(Day17.part2(&computer).to_string(), "no answer");
+    }
+
+    #[test]
+    fn test_validate() {
+        let registers = "Register A: 1\nRegister B: 0\nRegister C: 0\n\n";
+        assert!(Day17
+            .validate(&format!("{}Program: 0,3\n", registers))
+            .is_empty());
+        let messages = |program| {
+            let errors = Day17.validate(&format!("{}{}", registers, program));
+            errors.iter().map(Error::to_string).collect::<Vec<_>>()
+        };
+        assert_eq!(
+            messages("Program: 0,3,5\n"),
+            ["5: The last opcode 5 has no operand and never runs"]
+        );
+        assert_eq!(messages("Program: 0,9\n"), ["5:12: Invalid 3-bit number 9"]);
+    }
+}
@@ -0,0 +1,289 @@
+use std::fmt::Display;
+
+use crate::{
+    error::{parse_number, Error, Result},
+    solution::{parse_param, parse_param_at_least, Param, Solution},
+    utils::{
+        graph::UnionFind,
+        grid::{Grid, Pos},
+        parse::{key_value, parse_lines},
+        render::{Cell, Color, Frame},
+        search::astar,
+        validate,
+    },
+};
+
+pub struct Day18 {
+    /// Width and height of the memory space.
+    pub memory_size: usize,
+    /// Number of bytes that have fallen when part 1 is measured.
+    pub num_fallen_bytes: usize,
+}
+
+impl Default for Day18 {
+    fn default() -> Self {
+        Self {
+            memory_size: 71,
+            num_fallen_bytes: 1024,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Byte {
+    Free,
+    Corrupted,
+}
+
+#[derive(Debug, Clone)]
+pub struct Memory {
+    grid: Grid<Byte>,
+    start_pos: Pos,
+    end_pos: Pos,
+}
+
+impl Memory {
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            grid: Grid::new(width, height, Byte::Free),
+            start_pos: (0, 0),
+            end_pos: (width as i64 - 1, height as i64 - 1),
+        }
+    }
+
+    pub fn corrupt_at(&mut self, x: usize, y: usize) {
+        self.grid[(x as i64, y as i64)] = Byte::Corrupted;
+    }
+
+    fn heuristic_distance(&self, (x, y): Pos) -> usize {
+        let (tx, ty) = self.end_pos;
+        let dx = tx.abs_diff(x);
+        let dy = ty.abs_diff(y);
+        (dx + dy) as usize
+    }
+
+    pub fn find_shortest_path(&self) -> Option<usize> {
+        let path = self.shortest_path()?;
+        Some(path.len() - 1)
+    }
+
+    /// One shortest path from the start to the exit, both included.
+    pub fn shortest_path(&self) -> Option<Vec<Pos>> {
+        let (_, path) = astar(
+            self.start_pos,
+            |&pos| {
+                self.grid
+                    .neighbors4(pos)
+                    .filter(|&np| self.grid[np] != Byte::Corrupted)
+                    .map(|np| (np, 1))
+                    .collect::<Vec<_>>()
+            },
+            |&pos| self.heuristic_distance(pos),
+            |&pos| pos == self.end_pos,
+        )?;
+        Some(path)
+    }
+
+    /// The corrupted bytes, with a shortest path to the exit if there is one.
+    pub fn render(&self) -> Frame {
+        let mut frame = Frame::from_grid(&self.grid, |_, byte| match byte {
+            Byte::Free => Cell::new('.', Color::DARK_GRAY),
+            Byte::Corrupted => Cell::new('#', Color::RED),
+        });
+        if let Some(path) = self.shortest_path() {
+            for &pos in &path {
+                frame.set(pos, 'O', Color::BLACK);
+            }
+            frame.path(&path, Color::GREEN);
+        }
+        frame
+    }
+}
+
+impl Day18 {
+    pub fn memory_after_fallen_bytes(&self, corrupt_coordinates: &[(usize, usize)]) -> Memory {
+        let mut memory = Memory::new(self.memory_size, self.memory_size);
+        for &(x, y) in corrupt_coordinates.iter().take(self.num_fallen_bytes) {
+            memory.corrupt_at(x, y);
+        }
+        memory
+    }
+
+    /// The first byte after which the exit can't be reached anymore.
+    ///
+    /// Works backwards from the memory with every byte fallen: freeing the bytes in reverse order
+    /// only ever connects regions, so a [`UnionFind`] answers each step without a new search.
+    pub fn first_blocking_byte(
+        &self,
+        corrupt_coordinates: &[(usize, usize)],
+    ) -> Option<(usize, usize)> {
+        let size = self.memory_size;
+        // The time each byte falls, if it does.
+        let mut fallen_at: Grid<Option<usize>> = Grid::new(size, size, None);
+        for (time, &(x, y)) in corrupt_coordinates.iter().enumerate() {
+            fallen_at[(x as i64, y as i64)].get_or_insert(time);
+        }
+        let index = |(x, y): Pos| y as usize * size + x as usize;
+        let mut regions = UnionFind::new(size * size);
+        let free = |regions: &mut UnionFind, pos: Pos, time: usize| {
+            for np in fallen_at.neighbors4(pos) {
+                if fallen_at[np].is_none_or(|fallen| fallen >= time) {
+                    regions.union(index(pos), index(np));
+                }
+            }
+        };
+        for pos in fallen_at.positions() {
+            if fallen_at[pos].is_none() {
+                free(&mut regions, pos, usize::MAX);
+            }
+        }
+
+        let (start, end) = (index((0, 0)), index((size as i64 - 1, size as i64 - 1)));
+        let mut blocking = None;
+        for (time, &(x, y)) in corrupt_coordinates.iter().enumerate().rev() {
+            if regions.connected(start, end) {
+                break;
+            }
+            blocking = Some((x, y));
+            if fallen_at[(x as i64, y as i64)] == Some(time) {
+                free(&mut regions, (x as i64, y as i64), time);
+            }
+        }
+        blocking.filter(|_| regions.connected(start, end))
+    }
+
+    fn parse_coordinates(&self, line: &str) -> Result<(usize, usize)> {
+        let (x, y) = key_value(line, ",")?;
+        let (x, y) = (parse_number(line, x)?, parse_number(line, y)?);
+        if x >= self.memory_size || y >= self.memory_size {
+            return Err(Error::parse(format!(
+                "Coordinates {},{} are outside of the {}x{} memory space",
+                x, y, self.memory_size, self.memory_size
+            )));
+        }
+        Ok((x, y))
+    }
+}
+
+impl Solution for Day18 {
+    type Input = Vec<(usize, usize)>;
+
+    const DAY: u8 = 18;
+    const TITLE: &'static str = "RAM Run";
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parse_lines(input, |line| self.parse_coordinates(line))
+    }
+
+    fn validate(&self, input: &str) -> Vec<Error> {
+        let mut errors = validate::non_empty(input);
+        errors.extend(validate::each_line(input, |line| {
+            self.parse_coordinates(line)
+        }));
+        if !errors.is_empty() {
+            return errors;
+        }
+        let corrupt_coordinates = self.parse(input).expect("Every line parses");
+        let memory = self.memory_after_fallen_bytes(&corrupt_coordinates);
+        if memory.shortest_path().is_none() {
+            errors.push(Error::parse(format!(
+                "The exit can't be reached after {} bytes have fallen",
+                self.num_fallen_bytes
+            )));
+        } else if self.first_blocking_byte(&corrupt_coordinates).is_none() {
+            errors.push(Error::parse("The bytes never cut off the exit"));
+        }
+        errors
+    }
+
+    fn part1(&self, corrupt_coordinates: &Self::Input) -> impl Display {
+        let memory = self.memory_after_fallen_bytes(corrupt_coordinates);
+        memory.find_shortest_path().unwrap()
+    }
+
+    fn part2(&self, corrupt_coordinates: &Self::Input) -> impl Display {
+        let (x, y) = self
+            .first_blocking_byte(corrupt_coordinates)
+            .expect("Path is never blocked");
+        format!("{},{}", x, y)
+    }
+
+    fn render(&self, corrupt_coordinates: &Self::Input) -> Vec<(String, Frame)> {
+        let mut frames = vec![(
+            "path".to_string(),
+            self.memory_after_fallen_bytes(corrupt_coordinates).render(),
+        )];
+        if let Some((x, y)) = self.first_blocking_byte(corrupt_coordinates) {
+            let mut memory = Memory::new(self.memory_size, self.memory_size);
+            for &(bx, by) in corrupt_coordinates {
+                memory.corrupt_at(bx, by);
+                if (bx, by) == (x, y) {
+                    break;
+                }
+            }
+            let mut frame = memory.render();
+            frame.highlight((x as i64, y as i64), Color::YELLOW);
+            frames.push(("blocked".to_string(), frame));
+        }
+        frames
+    }
+
+    fn params(&self) -> Vec<Param<Self>> {
+        vec![
+            Param {
+                name: "memory_size",
+                description: "Width and height of the memory space",
+                get: |day| day.memory_size.to_string(),
+                set: |day, value| parse_param_at_least(value, 1).map(|v| day.memory_size = v),
+            },
+            Param {
+                name: "num_fallen_bytes",
+                description: "Bytes that have fallen when part 1 is measured",
+                get: |day| day.num_fallen_bytes.to_string(),
+                set: |day, value| parse_param(value).map(|v| day.num_fallen_bytes = v),
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::{example_answer, Part};
+
+    #[test]
+    fn test_example() {
+        let day = Day18 {
+            memory_size: 7,
+            num_fallen_bytes: 12,
+        };
+        assert_eq!(example_answer(&day, "day18.txt", Part::One), "22");
+        assert_eq!(example_answer(&day, "day18.txt", Part::Two), "6,1");
+    }
+
+    #[test]
+    fn test_validate() {
+        let day = Day18 {
+            memory_size: 3,
+            num_fallen_bytes: 2,
+        };
+        let messages = |input| {
+            let errors = day.validate(input);
+            errors.iter().map(Error::to_string).collect::<Vec<_>>()
+        };
+        assert!(messages("1,0\n1,1\n1,2\n").is_empty());
+        assert_eq!(
+            messages("1,0\n3,1\nx,2\n"),
+            [
+                "2: Coordinates 3,1 are outside of the 3x3 memory space",
+                "3:1: Invalid number 'x'"
+            ]
+        );
+        assert_eq!(
+            messages("0,1\n1,0\n1,1\n"),
+            ["The exit can't be reached after 2 bytes have fallen"]
+        );
+        assert_eq!(messages("1,0\n1,1\n"), ["The bytes never cut off the exit"]);
+        assert_eq!(messages(""), ["1: Empty input"]);
+    }
+}
@@ -0,0 +1,184 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+};
+
+use crate::{
+    error::{Error, Result},
+    solution::Solution,
+    utils::{
+        parse::{list, sections},
+        validate,
+    },
+};
+
+pub struct Day19;
+
+fn pattern_len_from_stack(stack: &[(String, usize)]) -> usize {
+    stack.iter().map(|(s, _)| s.len()).sum()
+}
+
+fn reconstruct_pattern(
+    pattern: &str,
+    available_patterns: &HashSet<String>,
+    cache: &mut HashMap<String, usize>,
+) -> usize {
+    let mut pattern_stack = Vec::new();
+    let mut num_combinations = 0;
+    let mut min_next_len = 1;
+    loop {
+        let start_i = pattern_len_from_stack(&pattern_stack);
+        let mut found_next = false;
+        // Check cache
+        let rest_of_pattern = pattern[start_i..].to_string();
+        if rest_of_pattern.len() >= min_next_len && cache.contains_key(&rest_of_pattern) {
+            let cached_count = cache.get(&rest_of_pattern).unwrap();
+            found_next = true;
+            pattern_stack.push((rest_of_pattern, *cached_count));
+        } else {
+            // Continue if no cache hit
+            let mut next_len = min_next_len;
+            while start_i + next_len <= pattern.len() {
+                let next_pattern = &pattern[start_i..start_i + next_len];
+                if available_patterns.contains(next_pattern) {
+                    pattern_stack.push((next_pattern.to_string(), 1));
+                    found_next = true;
+                    break;
+                }
+                next_len += 1;
+            }
+        }
+        if !found_next {
+            if pattern_stack.is_empty() {
+                break;
+            }
+            let (last_pattern, _) = pattern_stack.pop().unwrap();
+            min_next_len = last_pattern.len() + 1;
+        } else if pattern_len_from_stack(&pattern_stack) == pattern.len() {
+            let (last_pattern, new_combinations) = pattern_stack.pop().unwrap();
+            num_combinations += new_combinations;
+            min_next_len = last_pattern.len() + 1;
+        } else {
+            min_next_len = 1;
+        }
+    }
+    if num_combinations > 0 {
+        cache.insert(pattern.to_string(), num_combinations);
+    }
+    num_combinations
+}
+
+fn cached_reconstruct_pattern(
+    pattern: &str,
+    available_patterns: &HashSet<String>,
+    cache: &mut HashMap<String, usize>,
+) -> usize {
+    let n = pattern.len();
+    for pa in (0..n).rev() {
+        let pattern_remaining = &pattern[pa..];
+        reconstruct_pattern(pattern_remaining, available_patterns, cache);
+    }
+    reconstruct_pattern(pattern, available_patterns, cache)
+}
+
+pub fn count_combinations(available_patterns: &HashSet<String>, patterns: &[String]) -> Vec<usize> {
+    let mut cache = HashMap::new();
+    patterns
+        .iter()
+        .map(|pattern| cached_reconstruct_pattern(pattern, available_patterns, &mut cache))
+        .collect()
+}
+
+/// Checks that `stripes`, a slice of `line`, is a non-empty sequence of stripe colors.
+fn check_stripes(line: &str, stripes: &str) -> Result<()> {
+    if stripes.is_empty() {
+        return Err(Error::at_token(line, stripes, "Empty pattern"));
+    }
+    match stripes
+        .char_indices()
+        .find(|&(_, ch)| !"wubrg".contains(ch))
+    {
+        Some((i, ch)) => Err(Error::at_token(
+            line,
+            &stripes[i..i + ch.len_utf8()],
+            format!("Invalid stripe color '{}'", ch),
+        )),
+        None => Ok(()),
+    }
+}
+
+impl Solution for Day19 {
+    type Input = (HashSet<String>, Vec<String>);
+
+    const DAY: u8 = 19;
+    const TITLE: &'static str = "Linen Layout";
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        let [towels, designs] = sections(input)?;
+        if towels.lines().count() > 1 {
+            return Err(Error::parse("Expected the towel patterns on a single line").at_line(2));
+        }
+        let available_pattern_strings: HashSet<String> =
+            list(towels.text, ",")?.into_iter().collect();
+        let patterns: Vec<String> = designs.lines().map(|line| line.to_string()).collect();
+
+        Ok((available_pattern_strings, patterns))
+    }
+
+    fn validate(&self, input: &str) -> Vec<Error> {
+        let [towels, designs] = match sections(input) {
+            Ok(sections) => sections,
+            Err(e) => return vec![e],
+        };
+        let mut errors = validate::in_block(&towels, |text| {
+            validate::each_line(text, |line| {
+                line.split(',')
+                    .try_for_each(|towel| check_stripes(line, towel.trim()))
+            })
+        });
+        if towels.lines().count() > 1 {
+            errors.push(Error::parse("Expected the towel patterns on a single line").at_line(2));
+        }
+        errors.extend(validate::in_block(&designs, |text| {
+            validate::each_line(text, |line| check_stripes(line, line))
+        }));
+        errors
+    }
+
+    fn part1(&self, (available_patterns, patterns): &Self::Input) -> impl Display {
+        count_combinations(available_patterns, patterns)
+            .into_iter()
+            .filter(|&num_combinations| num_combinations > 0)
+            .count()
+    }
+
+    fn part2(&self, (available_patterns, patterns): &Self::Input) -> impl Display {
+        count_combinations(available_patterns, patterns)
+            .into_iter()
+            .sum::<usize>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::{example_answer, Part};
+
+    #[test]
+    fn test_example() {
+        let day = Day19;
+        assert_eq!(example_answer(&day, "day19.txt", Part::One), "6");
+        assert_eq!(example_answer(&day, "day19.txt", Part::Two), "16");
+    }
+
+    #[test]
+    fn test_validate() {
+        let errors = Day19.validate("r, wr, , bx\n\nbrwrr\nbgÄr\n");
+        let messages: Vec<String> = errors.iter().map(Error::to_string).collect();
+        assert_eq!(
+            messages,
+            ["1:7: Empty pattern", "4:3: Invalid stripe color 'Ä'"]
+        );
+        assert_eq!(Day19.validate("r, wr\n").len(), 1);
+    }
+}
@@ -0,0 +1,376 @@
+use std::{collections::HashSet, fmt::Display};
+
+use crate::{
+    error::{Error, Result},
+    solution::{parse_param, parse_param_at_least, Implementation, Param, Part, Solution},
+    utils::{
+        cancel,
+        grid::{Grid, Pos},
+        render::{Cell, Color, Frame},
+        search::bfs,
+        validate,
+    },
+};
+
+pub struct Day20 {
+    /// Only cheats saving at least this many picoseconds are counted.
+    pub min_speedup: i64,
+    /// Longest a cheat can last in part 1, in picoseconds.
+    pub part1_cheat_radius: usize,
+    /// The same in part 2.
+    pub part2_cheat_radius: usize,
+}
+
+impl Default for Day20 {
+    fn default() -> Self {
+        Self {
+            min_speedup: 100,
+            part1_cheat_radius: 2,
+            part2_cheat_radius: 20,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    Empty,
+    Wall,
+}
+
+#[derive(Debug)]
+pub struct Track {
+    track: Grid<Tile>,
+    start_pos: Pos,
+    end_pos: Pos,
+}
+
+impl Track {
+    pub fn parse(input: &str) -> Result<Self> {
+        let mut start_pos = None;
+        let mut end_pos = None;
+        let track = Grid::parse_with(input, |pos, ch| match ch {
+            '.' => Some(Tile::Empty),
+            '#' => Some(Tile::Wall),
+            'S' => {
+                start_pos = Some(pos);
+                Some(Tile::Empty)
+            }
+            'E' => {
+                end_pos = Some(pos);
+                Some(Tile::Empty)
+            }
+            _ => None,
+        })?;
+        Ok(Self {
+            track,
+            start_pos: start_pos.ok_or_else(|| Error::parse("No start 'S' found in track"))?,
+            end_pos: end_pos.ok_or_else(|| Error::parse("No end 'E' found in track"))?,
+        })
+    }
+
+    /// Checks that the track is a single corridor from the start to the end, without branches,
+    /// dead ends or separate loops, since cheats are measured along that one path.
+    fn corridor_errors(&self) -> Vec<Error> {
+        let mut errors = Vec::new();
+        for pos in self.free_positions() {
+            let neighbors = self
+                .track
+                .neighbors4(pos)
+                .filter(|&np| self.track[np] == Tile::Empty)
+                .count();
+            let expected = if pos == self.start_pos || pos == self.end_pos {
+                1
+            } else {
+                2
+            };
+            if neighbors != expected {
+                let (x, y) = pos;
+                errors.push(Error::at(
+                    y as usize + 1,
+                    x as usize + 1,
+                    format!(
+                        "Expected a single track, got a tile with {} neighbors on the track instead of {}",
+                        neighbors, expected
+                    ),
+                ));
+            }
+        }
+        let search = bfs(self.start_pos, |&pos| {
+            self.track
+                .neighbors4(pos)
+                .filter(|&np| self.track[np] == Tile::Empty)
+                .collect::<Vec<_>>()
+        });
+        if let Some((x, y)) = self
+            .free_positions()
+            .into_iter()
+            .find(|pos| search.distance(pos).is_none())
+        {
+            errors.push(Error::at(
+                y as usize + 1,
+                x as usize + 1,
+                "Track can't be reached from the start 'S'",
+            ));
+        }
+        errors
+    }
+
+    /// Number of steps from the start to every free position of the track.
+    pub fn build_path_map(&self) -> Grid<usize> {
+        let search = bfs(self.start_pos, |&pos| {
+            self.track
+                .neighbors4(pos)
+                .filter(|&np| self.track[np] != Tile::Wall)
+                .collect::<Vec<_>>()
+        });
+        if search.distance(&self.end_pos).is_none() {
+            panic!("No path found");
+        }
+        let mut visited_steps = self.track.map(|_| usize::MAX);
+        for (&pos, &steps) in search.distances() {
+            visited_steps[pos] = steps;
+        }
+        visited_steps
+    }
+
+    /// The start and end of every cheat of at most `max_dist` steps that saves at least
+    /// `min_speedup` picoseconds, found by looking at the positions around each free position.
+    ///
+    /// The work grows with the square of `max_dist`, so it checks for cancellation at every free
+    /// position.
+    pub fn cheats(&self, max_dist: usize, min_speedup: i64) -> Vec<(Pos, Pos)> {
+        let steps = self.build_path_map();
+        let max_dist = max_dist as i64;
+        let mut cheats = Vec::new();
+        for (x, y) in self.free_positions() {
+            cancel::check();
+            for dy in -max_dist..=max_dist {
+                let rest = max_dist - dy.abs();
+                for dx in -rest..=rest {
+                    let end = (x + dx, y + dy);
+                    let Some(&end_steps) = steps.get(end).filter(|&&s| s != usize::MAX) else {
+                        continue;
+                    };
+                    let speedup = end_steps as i64 - steps[(x, y)] as i64 - dx.abs() - dy.abs();
+                    if speedup >= min_speedup {
+                        cheats.push(((x, y), end));
+                    }
+                }
+            }
+        }
+        cheats
+    }
+
+    /// The track shaded by the time to reach each position, with the given cheats drawn as red
+    /// lines between their start and end.
+    pub fn render(&self, cheats: &[(Pos, Pos)]) -> Frame {
+        let steps = self.build_path_map();
+        let total = steps[self.end_pos].max(1) as f64;
+        let mut frame = Frame::from_grid(&self.track, |pos, tile| match tile {
+            Tile::Wall => Cell::new('#', Color::DARK_GRAY),
+            Tile::Empty => {
+                let color = Color::BLUE.mix(Color::GREEN, steps[pos] as f64 / total);
+                Cell::new('.', color)
+            }
+        });
+        for &(start, end) in cheats {
+            frame.highlight(start, Color::ORANGE);
+            frame.highlight(end, Color::RED);
+            frame.line(&[start, end], Color::RED);
+        }
+        frame.set(self.start_pos, 'S', Color::WHITE);
+        frame.set(self.end_pos, 'E', Color::WHITE);
+        frame
+    }
+
+    fn free_positions(&self) -> Vec<Pos> {
+        self.track
+            .iter()
+            .filter(|(_, &tile)| tile == Tile::Empty)
+            .map(|(pos, _)| pos)
+            .collect()
+    }
+
+    /// Every pair of free positions at most `max_dist` apart, checking for cancellation at each
+    /// first position since there are quadratically many pairs.
+    fn double_iter_free(&self, max_dist: usize) -> impl Iterator<Item = (Pos, Pos, usize)> + '_ {
+        let free = self.free_positions();
+        let mut evaluated: HashSet<(Pos, Pos)> = HashSet::new();
+        let mut free_pairs: Vec<(Pos, Pos, usize)> = Vec::new();
+        for p1 in free.iter() {
+            cancel::check();
+            for p2 in free.iter() {
+                let p1 = *p1;
+                let p2 = *p2;
+                let (p1, p2) = sort_points(p1, p2);
+                if evaluated.contains(&(p1, p2)) {
+                    continue;
+                }
+                evaluated.insert((p1, p2));
+                let (x1, y1) = p1;
+                let (x2, y2) = p2;
+                let dist = (x1.abs_diff(x2) + y1.abs_diff(y2)) as usize;
+                if dist <= max_dist {
+                    free_pairs.push((p1, p2, dist));
+                }
+            }
+        }
+        free_pairs.into_iter()
+    }
+}
+
+fn sort_points(p1: Pos, p2: Pos) -> (Pos, Pos) {
+    if p1 < p2 {
+        (p1, p2)
+    } else {
+        (p2, p1)
+    }
+}
+
+/// Number of cheats of at most `max_dist` steps that save at least `min_speedup` picoseconds.
+pub fn count_cheats(track: &Track, max_dist: usize, min_speedup: i64) -> usize {
+    track.cheats(max_dist, min_speedup).len()
+}
+
+/// The same as [`count_cheats`], by measuring the distance between every pair of free positions,
+/// which is quadratic in the length of the track.
+pub fn count_cheats_all_pairs(track: &Track, max_dist: usize, min_speedup: i64) -> usize {
+    let visited_steps = track.build_path_map();
+    track
+        .double_iter_free(max_dist)
+        .map(|(p1, p2, dist)| {
+            let steps_diff = visited_steps[p1].abs_diff(visited_steps[p2]) as i64;
+            steps_diff - dist as i64
+        })
+        .filter(|speedup| *speedup >= min_speedup)
+        .count()
+}
+
+impl Solution for Day20 {
+    type Input = Track;
+
+    const DAY: u8 = 20;
+    const TITLE: &'static str = "Race Condition";
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Track::parse(input)
+    }
+
+    fn validate(&self, input: &str) -> Vec<Error> {
+        let mut errors = validate::grid(input, |ch| "#.SE".contains(ch));
+        errors.extend(validate::walled(input, '#'));
+        errors.extend(validate::exactly_one(input, 'S', "start"));
+        errors.extend(validate::exactly_one(input, 'E', "end"));
+        if errors.is_empty() {
+            if let Ok(track) = Track::parse(input) {
+                errors.extend(track.corridor_errors());
+            }
+        }
+        errors
+    }
+
+    fn part1(&self, track: &Self::Input) -> impl Display {
+        count_cheats(track, self.part1_cheat_radius, self.min_speedup)
+    }
+
+    fn part2(&self, track: &Self::Input) -> impl Display {
+        count_cheats(track, self.part2_cheat_radius, self.min_speedup)
+    }
+
+    fn render(&self, track: &Self::Input) -> Vec<(String, Frame)> {
+        let cheats = track.cheats(self.part1_cheat_radius, self.min_speedup);
+        vec![("cheats".to_string(), track.render(&cheats))]
+    }
+
+    fn implementations(&self) -> Vec<Implementation<Self>> {
+        vec![
+            Implementation {
+                name: "all-pairs",
+                part: Part::One,
+                solve: |day, track| {
+                    count_cheats_all_pairs(track, day.part1_cheat_radius, day.min_speedup)
+                        .to_string()
+                },
+            },
+            Implementation {
+                name: "all-pairs",
+                part: Part::Two,
+                solve: |day, track| {
+                    count_cheats_all_pairs(track, day.part2_cheat_radius, day.min_speedup)
+                        .to_string()
+                },
+            },
+        ]
+    }
+
+    fn params(&self) -> Vec<Param<Self>> {
+        vec![
+            Param {
+                name: "min_speedup",
+                description: "Fewest picoseconds a cheat must save to be counted",
+                get: |day| day.min_speedup.to_string(),
+                set: |day, value| parse_param_at_least(value, 1).map(|v| day.min_speedup = v),
+            },
+            Param {
+                name: "part1_cheat_radius",
+                description: "Longest a cheat can last in part 1",
+                get: |day| day.part1_cheat_radius.to_string(),
+                set: |day, value| parse_param(value).map(|v| day.part1_cheat_radius = v),
+            },
+            Param {
+                name: "part2_cheat_radius",
+                description: "Longest a cheat can last in part 2",
+                get: |day| day.part2_cheat_radius.to_string(),
+                set: |day, value| parse_param(value).map(|v| day.part2_cheat_radius = v),
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::example_answer;
+
+    #[test]
+    fn test_example() {
+        let day = Day20 {
+            min_speedup: 50,
+            ..Day20::default()
+        };
+        assert_eq!(example_answer(&day, "day20.txt", Part::One), "1");
+        assert_eq!(example_answer(&day, "day20.txt", Part::Two), "285");
+    }
+
+    #[test]
+    fn test_all_pairs_agrees() {
+        let track = Track::parse(&std::fs::read_to_string("examples/day20.txt").unwrap()).unwrap();
+        for max_dist in [2, 5, 20] {
+            for min_speedup in [1, 10, 50, 70] {
+                assert_eq!(
+                    count_cheats(&track, max_dist, min_speedup),
+                    count_cheats_all_pairs(&track, max_dist, min_speedup),
+                    "max_dist {}, min_speedup {}",
+                    max_dist,
+                    min_speedup
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_validate() {
+        let errors = Day20::default().validate("######\n#S..E#\n#.####\n######\n");
+        let messages: Vec<String> = errors.iter().map(Error::to_string).collect();
+        assert_eq!(
+            messages,
+            [
+                "2:2: Expected a single track, got a tile with 2 neighbors on the track instead of 1",
+                "3:2: Expected a single track, got a tile with 1 neighbors on the track instead of 2"
+            ]
+        );
+        let errors = Day20::default().validate("########\n#SE#..##\n####..##\n########\n");
+        let messages: Vec<String> = errors.iter().map(Error::to_string).collect();
+        assert_eq!(messages, ["2:5: Track can't be reached from the start 'S'"]);
+    }
+}
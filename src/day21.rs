@@ -0,0 +1,368 @@
+use std::{collections::HashMap, fmt::Display};
+
+use crate::{
+    error::{Error, Result},
+    solution::{parse_param, Param, Solution},
+    utils::{geom::Dir4, validate},
+};
+
+pub struct Day21 {
+    /// Robots at directional keypads between the user and the numeric keypad, in part 1.
+    pub part1_robots: usize,
+    /// The same in part 2.
+    pub part2_robots: usize,
+}
+
+impl Default for Day21 {
+    fn default() -> Self {
+        Self {
+            part1_robots: 2,
+            part2_robots: 25,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NumButton {
+    Num(u8),
+    A,
+}
+
+/// Position of the empty corner of the numeric keypad.
+const NUM_KEYPAD_GAP: (usize, usize) = (0, 3);
+/// Position of the empty corner of the directional keypad.
+const DIR_KEYPAD_GAP: (usize, usize) = (0, 0);
+
+/// The shortest button sequences that move a robot arm from `from` to `to` on a keypad and press
+/// the button there, without ever pointing the arm at the `gap`.
+fn keypad_paths(
+    from: (usize, usize),
+    to: (usize, usize),
+    gap: (usize, usize),
+) -> Vec<Vec<DirButton>> {
+    let ((x1, y1), (x2, y2)) = (from, to);
+    let horiz_dir = if x2 > x1 { Dir4::Right } else { Dir4::Left };
+    let vert_dir = if y2 > y1 { Dir4::Down } else { Dir4::Up };
+    let horiz = vec![DirButton::from(horiz_dir); x1.abs_diff(x2)];
+    let vert = vec![DirButton::from(vert_dir); y1.abs_diff(y2)];
+    let path_horiz_first = [horiz.clone(), vert.clone(), vec![DirButton::A]].concat();
+    let path_vert_first = [vert, horiz, vec![DirButton::A]].concat();
+    if (x1, y2) == gap {
+        vec![path_horiz_first]
+    } else if (x2, y1) == gap {
+        vec![path_vert_first]
+    } else if path_horiz_first == path_vert_first {
+        vec![path_horiz_first]
+    } else {
+        vec![path_horiz_first, path_vert_first]
+    }
+}
+
+#[derive(Default)]
+pub struct NumPathFinder(HashMap<(NumButton, NumButton), Vec<Vec<DirButton>>>);
+
+impl NumPathFinder {
+    pub fn find_paths(&mut self, from: NumButton, to: NumButton) -> Vec<Vec<DirButton>> {
+        if let Some(paths) = self.0.get(&(from, to)) {
+            return paths.clone();
+        }
+        let dirs = keypad_paths(from.to_coord(), to.to_coord(), NUM_KEYPAD_GAP);
+        self.0.insert((from, to), dirs.clone());
+        dirs
+    }
+}
+
+impl NumButton {
+    pub fn to_coord(self) -> (usize, usize) {
+        match self {
+            NumButton::Num(0) => (1, 3),
+            NumButton::Num(1) => (0, 2),
+            NumButton::Num(2) => (1, 2),
+            NumButton::Num(3) => (2, 2),
+            NumButton::Num(4) => (0, 1),
+            NumButton::Num(5) => (1, 1),
+            NumButton::Num(6) => (2, 1),
+            NumButton::Num(7) => (0, 0),
+            NumButton::Num(8) => (1, 0),
+            NumButton::Num(9) => (2, 0),
+            NumButton::A => (2, 3),
+            _ => panic!("Invalid button"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DirButton {
+    Up,
+    Down,
+    Left,
+    Right,
+    A,
+}
+
+impl From<Dir4> for DirButton {
+    fn from(dir: Dir4) -> Self {
+        match dir {
+            Dir4::Up => DirButton::Up,
+            Dir4::Right => DirButton::Right,
+            Dir4::Down => DirButton::Down,
+            Dir4::Left => DirButton::Left,
+        }
+    }
+}
+
+impl DirButton {
+    pub fn to_coord(self) -> (usize, usize) {
+        match self {
+            DirButton::Up => (1, 0),
+            DirButton::Left => (0, 1),
+            DirButton::Down => (1, 1),
+            DirButton::Right => (2, 1),
+            DirButton::A => (2, 0),
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct DirPathFinder(HashMap<(DirButton, DirButton), Vec<Vec<DirButton>>>);
+
+impl DirPathFinder {
+    pub fn find_paths(&mut self, from: DirButton, to: DirButton) -> Vec<Vec<DirButton>> {
+        if let Some(paths) = self.0.get(&(from, to)) {
+            return paths.clone();
+        }
+        let dirs = keypad_paths(from.to_coord(), to.to_coord(), DIR_KEYPAD_GAP);
+        self.0.insert((from, to), dirs.clone());
+        dirs
+    }
+}
+
+pub struct Code {
+    code: String,
+    num_code: usize,
+}
+
+impl Code {
+    pub fn from_string(code: &str) -> Result<Self> {
+        if let Some((i, c)) = code
+            .char_indices()
+            .find(|&(_, c)| c != 'A' && !c.is_ascii_digit())
+        {
+            return Err(Error::parse(format!("Invalid code character '{}'", c)).at_column(i + 1));
+        }
+        if code.len() != 4 || !code.ends_with('A') {
+            return Err(Error::parse(format!(
+                "Expected three digits followed by 'A', got '{}'",
+                code
+            )));
+        }
+        let num_code: usize = code[..3]
+            .parse()
+            .map_err(|_| Error::parse(format!("Invalid numeric part in '{}'", code)))?;
+        Ok(Self {
+            code: code.to_string(),
+            num_code,
+        })
+    }
+
+    pub fn compute_min_steps(&self, num_robots: usize) -> Option<usize> {
+        Some(min_key_length(&self.code, num_robots))
+    }
+
+    pub fn compute_complexity(&self, num_robots: usize) -> Option<usize> {
+        let min_steps = self.compute_min_steps(num_robots).unwrap();
+        let complexity = min_steps * self.num_code;
+        Some(complexity)
+    }
+}
+
+fn build_code_paths(code: &str) -> Vec<Vec<Vec<DirButton>>> {
+    let mut path_finder = NumPathFinder::default();
+    let mut cur_key = NumButton::A;
+    let mut paths = Vec::new();
+    for c in code.chars() {
+        let key = match c {
+            'A' => NumButton::A,
+            n if n.is_ascii_digit() => NumButton::Num(n.to_digit(10).unwrap() as u8),
+            _ => panic!("Invalid code char"),
+        };
+        let path = path_finder.find_paths(cur_key, key);
+        paths.push(path);
+        cur_key = key;
+    }
+    paths
+}
+
+fn build_keys_rec(
+    keys: &[DirButton],
+    index: usize,
+    prev_key: DirButton,
+    curr_path: Vec<DirButton>,
+    result: &mut Vec<Vec<DirButton>>,
+    finder: &mut DirPathFinder,
+) {
+    if index == keys.len() {
+        result.push(curr_path);
+        return;
+    }
+    let curr_key = keys[index];
+    let paths = finder.find_paths(prev_key, curr_key);
+    for path in paths {
+        let mut new_path = curr_path.clone();
+        new_path.extend(path);
+        build_keys_rec(keys, index + 1, curr_key, new_path, result, finder);
+    }
+}
+
+fn find_shortest_keys(
+    keys: Vec<DirButton>,
+    depth: usize,
+    cache: &mut HashMap<(Vec<DirButton>, usize), usize>,
+    finder: &mut DirPathFinder,
+) -> usize {
+    if depth == 0 {
+        return keys.len();
+    }
+    if let Some(&cached) = cache.get(&(keys.clone(), depth)) {
+        return cached;
+    }
+    let mut total = 0;
+    let mut sub_keys = Vec::new();
+    let mut current_sub_key = Vec::new();
+    for key in keys.iter().copied() {
+        current_sub_key.push(key);
+        if key == DirButton::A && !current_sub_key.is_empty() {
+            sub_keys.push(current_sub_key.clone());
+            current_sub_key.clear();
+        }
+    }
+    if !current_sub_key.is_empty() {
+        sub_keys.push(current_sub_key);
+    }
+    for sub_key in sub_keys {
+        let mut sequences = Vec::new();
+        build_keys_rec(
+            &sub_key,
+            0,
+            DirButton::A,
+            Vec::new(),
+            &mut sequences,
+            finder,
+        );
+        let mut min_steps = usize::MAX;
+        for sequence in sequences {
+            let steps = find_shortest_keys(sequence, depth - 1, cache, finder);
+            if steps < min_steps {
+                min_steps = steps;
+            }
+        }
+        total += min_steps;
+    }
+    cache.insert((keys, depth), total);
+    total
+}
+
+pub fn min_key_length(code: &str, max_depth: usize) -> usize {
+    let mut dir_finder = DirPathFinder::default();
+    let mut cache = HashMap::new();
+
+    let code_paths = build_code_paths(code);
+
+    let mut count = 0;
+    for code_point_seqs in code_paths {
+        let mut min_steps = usize::MAX;
+        for code_seq in code_point_seqs {
+            let steps = find_shortest_keys(code_seq, max_depth, &mut cache, &mut dir_finder);
+            if steps < min_steps {
+                min_steps = steps;
+            }
+        }
+        count += min_steps;
+    }
+    count
+}
+
+pub fn total_complexity(codes: &[Code], num_robots: usize) -> usize {
+    codes
+        .iter()
+        .map(|code| code.compute_complexity(num_robots).unwrap())
+        .sum()
+}
+
+impl Solution for Day21 {
+    type Input = Vec<Code>;
+
+    const DAY: u8 = 21;
+    const TITLE: &'static str = "Keypad Conundrum";
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| Code::from_string(line).map_err(|e| e.at_line(i + 1)))
+            .collect()
+    }
+
+    fn validate(&self, input: &str) -> Vec<Error> {
+        let mut errors = validate::non_empty(input);
+        errors.extend(validate::each_line(input, Code::from_string));
+        errors
+    }
+
+    fn part1(&self, codes: &Self::Input) -> impl Display {
+        total_complexity(codes, self.part1_robots)
+    }
+
+    fn part2(&self, codes: &Self::Input) -> impl Display {
+        total_complexity(codes, self.part2_robots)
+    }
+
+    fn params(&self) -> Vec<Param<Self>> {
+        vec![
+            Param {
+                name: "part1_robots",
+                description: "Robots at directional keypads in part 1",
+                get: |day| day.part1_robots.to_string(),
+                set: |day, value| parse_param(value).map(|v| day.part1_robots = v),
+            },
+            Param {
+                name: "part2_robots",
+                description: "Robots at directional keypads in part 2",
+                get: |day| day.part2_robots.to_string(),
+                set: |day, value| parse_param(value).map(|v| day.part2_robots = v),
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::{example_answer, Part};
+
+    #[test]
+    fn test_example() {
+        // Part 2 has no published answer for the example; this is the value the solution gives.
+        let day = Day21::default();
+        assert_eq!(example_answer(&day, "day21.txt", Part::One), "126384");
+        assert_eq!(
+            example_answer(&day, "day21.txt", Part::Two),
+            "154115708116294"
+        );
+    }
+
+    #[test]
+    fn test_validate() {
+        let errors = Day21::default().validate("029A\n98B\n17A\n0A2A\n");
+        let messages: Vec<String> = errors.iter().map(Error::to_string).collect();
+        assert_eq!(
+            messages,
+            [
+                "2:3: Invalid code character 'B'",
+                "3: Expected three digits followed by 'A', got '17A'",
+                "4: Invalid numeric part in '0A2A'"
+            ]
+        );
+        assert_eq!(Day21::default().validate("").len(), 1);
+    }
+}
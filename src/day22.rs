@@ -0,0 +1,175 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+};
+
+use crate::{
+    error::{parse_number, Error, Result},
+    solution::{parse_param_at_least, Param, Solution},
+    utils::{cancel, parse::parse_lines, validate},
+};
+
+pub struct Day22 {
+    /// Number of secret numbers each buyer generates.
+    pub iterations: usize,
+}
+
+impl Default for Day22 {
+    fn default() -> Self {
+        Self { iterations: 2000 }
+    }
+}
+
+fn mix(a: usize, b: usize) -> usize {
+    a ^ b
+}
+
+fn prune(number: usize) -> usize {
+    number % 16777216
+}
+
+pub fn next_secret_number(number: usize) -> usize {
+    let mut result = prune(mix(number, number * 64));
+    result = prune(mix(result, result / 32));
+    prune(mix(result, result * 2048))
+}
+
+pub fn iterated_secret_numbers(start: usize, n: usize) -> usize {
+    let mut num = start;
+    for _ in 0..n {
+        num = next_secret_number(num);
+    }
+    num
+}
+
+pub fn price(rand_num: usize) -> i64 {
+    (rand_num % 10) as i64
+}
+
+pub fn price_sequence(start: usize, n: usize) -> Vec<i64> {
+    let mut num = start;
+    let mut result = Vec::new();
+    for _ in 0..n {
+        let next_num = next_secret_number(num);
+        result.push(price(next_num));
+        num = next_num;
+    }
+    result
+}
+
+pub fn diff_sequence(start: usize, n: usize) -> Vec<i64> {
+    let mut num = start;
+    let mut result = Vec::new();
+    let mut p = price(num);
+    for _ in 0..n {
+        let next_num = next_secret_number(num);
+        let np = price(next_num);
+        let diff = np - p;
+        result.push(diff);
+        num = next_num;
+        p = np;
+    }
+    result
+}
+
+impl Solution for Day22 {
+    type Input = Vec<usize>;
+
+    const DAY: u8 = 22;
+    const TITLE: &'static str = "Monkey Market";
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parse_lines(input, |line| parse_number(line, line))
+    }
+
+    fn validate(&self, input: &str) -> Vec<Error> {
+        let mut errors = validate::non_empty(input);
+        errors.extend(validate::each_line(input, |line| {
+            let number: usize = parse_number(line, line)?;
+            if prune(number) != number {
+                return Err(Error::parse(format!(
+                    "Secret number {} is larger than the pruning modulus",
+                    number
+                )));
+            }
+            Ok(number)
+        }));
+        errors
+    }
+
+    fn part1(&self, secret_numbers: &Self::Input) -> impl Display {
+        secret_numbers
+            .iter()
+            .map(|&start| iterated_secret_numbers(start, self.iterations))
+            .sum::<usize>()
+    }
+
+    fn part2(&self, secret_numbers: &Self::Input) -> impl Display {
+        // The bananas each sequence of four price changes earns, summed over the buyers. Each buyer
+        // sells at the first time the sequence appears, so later ones are skipped.
+        let mut payoffs: HashMap<[i64; 4], i64> = HashMap::new();
+        for &start in secret_numbers {
+            // With many iterations every buyer takes a while, so this checks between buyers.
+            cancel::check();
+            let prices = price_sequence(start, self.iterations);
+            let diffs = diff_sequence(start, self.iterations);
+            let mut seen = HashSet::new();
+            for (i, window) in diffs.windows(4).enumerate() {
+                let pattern = [window[0], window[1], window[2], window[3]];
+                if seen.insert(pattern) {
+                    *payoffs.entry(pattern).or_default() += prices[i + 3];
+                }
+            }
+        }
+        *payoffs
+            .values()
+            .max()
+            .expect("Buyers see at least four price changes")
+    }
+
+    fn params(&self) -> Vec<Param<Self>> {
+        vec![Param {
+            name: "iterations",
+            description: "Secret numbers generated by each buyer",
+            get: |day| day.iterations.to_string(),
+            set: |day, value| parse_param_at_least(value, 4).map(|v| day.iterations = v),
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::{example_answer, Part};
+
+    #[test]
+    fn test_example() {
+        let day = Day22::default();
+        assert_eq!(example_answer(&day, "day22.txt", Part::One), "37327623");
+        assert_eq!(example_answer(&day, "day22-2.txt", Part::Two), "23");
+    }
+
+    #[test]
+    fn test_params() {
+        use crate::solution::Runnable;
+
+        let mut day = Day22::default();
+        assert!(day.set_param("iterations", "3").is_err());
+        day.set_param("iterations", "4").unwrap();
+        assert_eq!(day.part2(&vec![1, 2]).to_string(), "5");
+    }
+
+    #[test]
+    fn test_validate() {
+        let errors = Day22::default().validate("1\n-2\n16777216\n");
+        let messages: Vec<String> = errors.iter().map(Error::to_string).collect();
+        assert_eq!(
+            messages,
+            [
+                "2:1: Invalid number '-2'",
+                "3: Secret number 16777216 is larger than the pruning modulus"
+            ]
+        );
+        assert_eq!(Day22::default().validate("").len(), 1);
+    }
+}
@@ -0,0 +1,383 @@
+use std::{
+    collections::{BTreeSet, HashSet},
+    fmt::Display,
+};
+
+use itertools::Itertools;
+
+use crate::{
+    error::{Error, Result},
+    solution::{Implementation, Part, Solution},
+    utils::{
+        graph::{Graph, VertexId},
+        parse::{key_value, parse_lines},
+        validate,
+    },
+};
+
+pub struct Day23;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Computer {
+    code: [char; 2],
+}
+
+impl Display for Computer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}", self.code[0], self.code[1])
+    }
+}
+
+impl Computer {
+    pub fn new(code: &str) -> Result<Self> {
+        match code.chars().collect::<Vec<_>>()[..] {
+            [a, b] if a.is_ascii_lowercase() && b.is_ascii_lowercase() => Ok(Self { code: [a, b] }),
+            _ => Err(Error::parse(format!("Invalid computer name '{}'", code))),
+        }
+    }
+
+    pub fn starts_with_t(&self) -> bool {
+        self.code[0] == 't'
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct InterconnectedComputers {
+    computers: BTreeSet<Computer>,
+}
+
+impl Display for InterconnectedComputers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.computers.iter().sorted().join(","))?;
+        Ok(())
+    }
+}
+
+impl InterconnectedComputers {
+    pub fn len(&self) -> usize {
+        self.computers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.computers.is_empty()
+    }
+
+    pub fn computers(&self) -> impl Iterator<Item = &Computer> {
+        self.computers.iter()
+    }
+
+    pub fn has_starts_with_t(&self) -> bool {
+        self.computers.iter().any(|c| c.starts_with_t())
+    }
+}
+
+/// Parses an `ab-cd` connection.
+fn parse_connection(line: &str) -> Result<(Computer, Computer)> {
+    let (a, b) = key_value(line, "-")?;
+    Ok((Computer::new(a)?, Computer::new(b)?))
+}
+
+type Clique = BTreeSet<VertexId>;
+
+#[derive(Default)]
+pub struct ComputerGraph {
+    graph: Graph<Computer>,
+}
+
+impl ComputerGraph {
+    /// Parses one `ab-cd` connection per line.
+    pub fn parse(input: &str) -> Result<Self> {
+        let mut graph = ComputerGraph::default();
+        let connections = parse_lines(input, parse_connection)?;
+        for (ca, cb) in connections {
+            graph.add_edge(ca, cb);
+        }
+        Ok(graph)
+    }
+
+    pub fn computers(&self) -> impl Iterator<Item = &Computer> {
+        self.graph.vertices()
+    }
+
+    /// The computers directly connected to `computer`.
+    pub fn neighbors(&self, computer: &Computer) -> impl Iterator<Item = &Computer> {
+        self.graph
+            .id(computer)
+            .into_iter()
+            .flat_map(|id| self.graph.neighbors(id))
+            .map(|&id| self.graph.vertex(id))
+    }
+
+    /// All sets of `k` computers that are all connected to each other.
+    pub fn cliques_of_size(&self, k: usize) -> BTreeSet<InterconnectedComputers> {
+        self.find_k_cliques_rec(k)
+            .iter()
+            .map(|clique| self.to_computers(clique))
+            .collect()
+    }
+
+    /// All sets of three computers that are all connected to each other, found from the common
+    /// neighbors of each pair of connected computers.
+    pub fn triangles(&self) -> BTreeSet<InterconnectedComputers> {
+        let mut triangles = BTreeSet::new();
+        for a in self.graph.ids() {
+            for &b in self.graph.neighbors(a).iter().filter(|&&b| b > a) {
+                for &c in self
+                    .graph
+                    .neighbors(a)
+                    .intersection(self.graph.neighbors(b))
+                {
+                    if c > b {
+                        triangles.insert(self.to_computers(&Clique::from([a, b, c])));
+                    }
+                }
+            }
+        }
+        triangles
+    }
+
+    /// The largest set of computers that are all connected to each other.
+    pub fn largest_clique(&self) -> InterconnectedComputers {
+        let mut max_clique = Clique::new();
+        self.bron_kerbosch_find_cliques_rec(
+            Clique::new(),
+            &self.graph.ids().collect(),
+            &Clique::new(),
+            &mut max_clique,
+        );
+        self.to_computers(&max_clique)
+    }
+
+    pub fn add_edge(&mut self, a: Computer, b: Computer) {
+        self.graph.add_edge(a, b);
+    }
+
+    fn to_computers(&self, clique: &Clique) -> InterconnectedComputers {
+        InterconnectedComputers {
+            computers: clique.iter().map(|&id| *self.graph.vertex(id)).collect(),
+        }
+    }
+
+    fn find_k_cliques_rec(&self, k: usize) -> BTreeSet<Clique> {
+        let mut cliques = BTreeSet::new();
+        for vertex in self.graph.ids() {
+            let current = Clique::from([vertex]);
+            self.extend_clique_rec(current, self.graph.neighbors(vertex), k, &mut cliques);
+        }
+        cliques
+    }
+
+    fn extend_clique_rec(
+        &self,
+        current: Clique,
+        candidates: &Clique,
+        k: usize,
+        cliques: &mut BTreeSet<Clique>,
+    ) {
+        if current.len() == k {
+            cliques.insert(current);
+            return;
+        }
+
+        for &candidate in candidates.iter() {
+            // is candidate connected to all computers in current?
+            let cneighbours = self.graph.neighbors(candidate);
+            let is_fully_connected = current.is_subset(cneighbours);
+            if is_fully_connected {
+                let mut new_current = current.clone();
+                if new_current.insert(candidate) {
+                    self.extend_clique_rec(new_current, cneighbours, k, cliques);
+                }
+            }
+        }
+    }
+
+    fn bron_kerbosch_find_cliques_rec(
+        &self,
+        current: Clique,
+        candidates: &Clique,
+        excluded: &Clique,
+        max_clique: &mut Clique,
+    ) {
+        if candidates.is_empty() && excluded.is_empty() {
+            if current.len() > max_clique.len() {
+                *max_clique = current;
+            }
+            return;
+        }
+
+        let pivot =
+            self.bron_kerbosch_pivot(&candidates.union(excluded).copied().collect(), candidates);
+
+        let mut new_candidates = candidates.clone();
+        let mut new_excluded = excluded.clone();
+        for &c in candidates.difference(self.graph.neighbors(pivot)) {
+            let neighbours = self.graph.neighbors(c);
+            let mut new_current = current.clone();
+            new_current.insert(c);
+            self.bron_kerbosch_find_cliques_rec(
+                new_current,
+                &new_candidates.intersection(neighbours).copied().collect(),
+                &new_excluded.intersection(neighbours).copied().collect(),
+                max_clique,
+            );
+            new_candidates.remove(&c);
+            new_excluded.insert(c);
+        }
+    }
+
+    fn bron_kerbosch_pivot(&self, computers: &Clique, candidates: &Clique) -> VertexId {
+        let mut max_connections = -1;
+        let mut pivot = None;
+        for &computer in computers.iter() {
+            let num_connections = candidates
+                .intersection(self.graph.neighbors(computer))
+                .count() as isize;
+            if num_connections > max_connections {
+                max_connections = num_connections;
+                pivot = Some(computer);
+            }
+        }
+        pivot.unwrap()
+    }
+}
+
+impl Solution for Day23 {
+    type Input = ComputerGraph;
+
+    const DAY: u8 = 23;
+    const TITLE: &'static str = "LAN Party";
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        ComputerGraph::parse(input)
+    }
+
+    fn validate(&self, input: &str) -> Vec<Error> {
+        let mut errors = validate::non_empty(input);
+        errors.extend(validate::each_line(input, |line| {
+            let (a, b) = parse_connection(line)?;
+            if a == b {
+                return Err(Error::parse(format!(
+                    "Computer {} is connected to itself",
+                    a
+                )));
+            }
+            Ok(())
+        }));
+        errors
+    }
+
+    fn part1(&self, graph: &Self::Input) -> impl Display {
+        let found_3_cliques = graph.cliques_of_size(3);
+        let cliques_with_t: HashSet<_> = found_3_cliques
+            .iter()
+            .filter(|g| g.has_starts_with_t())
+            .collect();
+        cliques_with_t.len()
+    }
+
+    fn part2(&self, graph: &Self::Input) -> impl Display {
+        graph.largest_clique().to_string()
+    }
+
+    fn implementations(&self) -> Vec<Implementation<Self>> {
+        vec![Implementation {
+            name: "triangles",
+            part: Part::One,
+            solve: |_, graph| {
+                let triangles = graph.triangles();
+                let with_t = triangles.iter().filter(|g| g.has_starts_with_t());
+                with_t.count().to_string()
+            },
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        generate,
+        solution::{example_answer, Part},
+        utils::property,
+    };
+
+    #[test]
+    fn test_example() {
+        let day = Day23;
+        assert_eq!(example_answer(&day, "day23.txt", Part::One), "7");
+        assert_eq!(example_answer(&day, "day23.txt", Part::Two), "co,de,ka,ta");
+    }
+
+    #[test]
+    fn test_validate() {
+        let errors = Day23.validate("kh-tc\nqp kh\nde-cg-ka\nka-ka\n");
+        let messages: Vec<String> = errors.iter().map(Error::to_string).collect();
+        assert_eq!(
+            messages,
+            [
+                "2: Expected 'key-value', got 'qp kh'",
+                "3: Invalid computer name 'cg-ka'",
+                "4: Computer ka is connected to itself"
+            ]
+        );
+        assert_eq!(Day23.validate("").len(), 1);
+    }
+
+    /// Every set of three computers that are all connected to each other, found by trying all of
+    /// them.
+    fn brute_force_triangles(graph: &ComputerGraph) -> BTreeSet<InterconnectedComputers> {
+        let connected = |a: &Computer, b: &Computer| graph.neighbors(a).any(|n| n == b);
+        let computers: Vec<&Computer> = graph.computers().collect();
+        computers
+            .into_iter()
+            .tuple_combinations()
+            .filter(|(a, b, c)| connected(a, b) && connected(b, c) && connected(a, c))
+            .map(|(a, b, c)| InterconnectedComputers {
+                computers: BTreeSet::from([*a, *b, *c]),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_clique_searches_agree() {
+        property::check(
+            &Day23,
+            "cliques",
+            |rng| {
+                let computers = rng.between(2, 12) as usize;
+                generate::network(rng, computers, 0.5)
+            },
+            property::remove_lines,
+            |graph| {
+                let brute_force = brute_force_triangles(graph);
+                for (name, triangles) in [
+                    ("cliques_of_size(3)", graph.cliques_of_size(3)),
+                    ("triangles", graph.triangles()),
+                ] {
+                    if triangles != brute_force {
+                        return Err(format!(
+                            "{} found {:?}, brute force found {:?}",
+                            name, triangles, brute_force
+                        ));
+                    }
+                }
+                let largest = graph.largest_clique();
+                if !graph.cliques_of_size(largest.len()).contains(&largest) {
+                    return Err(format!(
+                        "Bron-Kerbosch found {}, not a clique of size {} found one by one",
+                        largest,
+                        largest.len()
+                    ));
+                }
+                if !graph.cliques_of_size(largest.len() + 1).is_empty() {
+                    return Err(format!(
+                        "Bron-Kerbosch found {}, but there is a larger clique",
+                        largest
+                    ));
+                }
+                Ok(())
+            },
+        );
+    }
+}
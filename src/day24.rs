@@ -0,0 +1,454 @@
+use std::{
+    collections::{BTreeSet, HashMap, HashSet},
+    fmt::Display,
+};
+
+use itertools::Itertools;
+
+use crate::{
+    error::{Error, Result},
+    solution::Solution,
+    utils::{
+        parse::{key_value, sections, Block},
+        validate,
+    },
+};
+
+pub struct Day24;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Operator {
+    And,
+    Or,
+    Xor,
+}
+
+impl Display for Operator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let op_str = match self {
+            Self::And => "AND",
+            Self::Or => "OR",
+            Self::Xor => "XOR",
+        };
+        write!(f, "{}", op_str)
+    }
+}
+
+impl Operator {
+    fn from_str(op: &str) -> Result<Self> {
+        match op {
+            "AND" => Ok(Self::And),
+            "OR" => Ok(Self::Or),
+            "XOR" => Ok(Self::Xor),
+            _ => Err(Error::parse(format!("Invalid operator '{}'", op))),
+        }
+    }
+
+    pub fn eval(&self, operand1: u8, operand2: u8) -> u8 {
+        match self {
+            Self::And => operand1 & operand2,
+            Self::Or => operand1 | operand2,
+            Self::Xor => operand1 ^ operand2,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Operation {
+    operator: Operator,
+    operand1: String,
+    operand2: String,
+    result: String,
+}
+
+impl Operation {
+    fn from_str(line: &str) -> Result<Self> {
+        let (left, result) = line
+            .split_once(" -> ")
+            .ok_or_else(|| Error::parse(format!("Expected 'a OP b -> c', got '{}'", line)))?;
+        let left_parts: Vec<&str> = left.split_whitespace().collect();
+        let &[operand1, operator, operand2] = left_parts.as_slice() else {
+            return Err(Error::parse(format!(
+                "Expected 'a OP b' before '->', got '{}'",
+                left
+            )));
+        };
+        let operator = Operator::from_str(operator)?;
+        let mut operands = [operand1, operand2];
+        operands.sort();
+        for var in [operands[0], operands[1], result] {
+            if var.starts_with(['x', 'y', 'z']) && parse_var(var).is_none() {
+                return Err(Error::parse(format!("Invalid variable '{}'", var)));
+            }
+        }
+        Ok(Self {
+            operator,
+            operand1: operands[0].to_string(),
+            operand2: operands[1].to_string(),
+            result: result.to_string(),
+        })
+    }
+
+    pub fn eval(&self, operand1_value: u8, operand2_value: u8) -> u8 {
+        self.operator.eval(operand1_value, operand2_value)
+    }
+
+    fn is_input_op(&self) -> bool {
+        self.operand1.starts_with("x") && self.operand2.starts_with("y")
+    }
+
+    fn input_offset(&self) -> Option<usize> {
+        if self.is_input_op() {
+            let op1 = parse_var(&self.operand1).expect("Invalid var");
+            let op2 = parse_var(&self.operand2).expect("Invalid var");
+            assert_eq!(op1.1, op2.1);
+            let offset = op1.1;
+            Some(offset)
+        } else {
+            None
+        }
+    }
+
+    fn is_output_op(&self) -> bool {
+        self.result.starts_with("z")
+    }
+
+    fn output_offset(&self) -> Option<usize> {
+        if self.is_output_op() {
+            let op = parse_var(&self.result).expect("Invalid var");
+            let offset = op.1;
+            Some(offset)
+        } else {
+            None
+        }
+    }
+
+    fn is_xor(&self) -> bool {
+        self.operator == Operator::Xor
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Circuit {
+    operations: HashMap<String, Operation>,
+    init_map: HashMap<String, u8>,
+    result_vars: Vec<(String, usize)>,
+}
+
+fn parse_var(var: &str) -> Option<(char, usize)> {
+    let mut chars = var.chars();
+    let var_id = chars.next()?;
+    let var_pos = chars.as_str().parse().ok()?;
+    Some((var_id, var_pos))
+}
+
+impl Circuit {
+    /// Parses the initial wire values and the gates, separated by a blank line.
+    pub fn parse(input: &str) -> Result<Self> {
+        let [init_values, operations] = sections(input)?;
+        let init_values = init_values.parse_lines(parse_init_value)?;
+        let operations = operations.parse_lines(Operation::from_str)?;
+        Ok(Circuit::from_input(init_values, operations))
+    }
+
+    pub fn from_input(init_values: Vec<(String, u8)>, operations: Vec<Operation>) -> Self {
+        let mut operations_map = HashMap::new();
+        let mut init_map = HashMap::new();
+        let mut result_vars = Vec::new();
+        for (var, value) in init_values {
+            init_map.insert(var, value);
+        }
+        for op in operations {
+            if op.is_output_op() {
+                let offset = op.output_offset().expect("Invalid input offset");
+                result_vars.push((op.result.clone(), offset));
+            }
+            operations_map.insert(op.result.clone(), op);
+        }
+        Self {
+            operations: operations_map,
+            init_map,
+            result_vars,
+        }
+    }
+
+    /// The value of the wire `var`, computed from the gates feeding it.
+    pub fn eval_var(&self, var: &String) -> u8 {
+        if let Some(value) = self.init_map.get(var) {
+            return *value;
+        }
+        let operation = self.operations.get(var).expect("Invalid var");
+        let operand1_value = self.eval_var(&operation.operand1);
+        let operand2_value = self.eval_var(&operation.operand2);
+        operation.eval(operand1_value, operand2_value)
+    }
+
+    pub fn eval(&self) -> u64 {
+        let mut result: u64 = 0;
+        self.result_vars.iter().for_each(|(var, offset)| {
+            let value = self.eval_var(var);
+            result |= (value as u64) << offset;
+        });
+        result
+    }
+
+    pub fn max_input_offset(&self) -> usize {
+        self.init_map
+            .keys()
+            .map(|var| parse_var(var.as_str()).expect("Invalid var").1)
+            .max()
+            .unwrap()
+    }
+
+    fn find_xor_with_input_xor(&self, n: usize) -> Option<String> {
+        for (op_name, op) in self.operations.iter() {
+            if op.is_xor() {
+                let arg1_op = self.operations.get(&op.operand1);
+                let arg2_op = self.operations.get(&op.operand2);
+                if let (Some(arg1_op), Some(arg2_op)) = (arg1_op, arg2_op) {
+                    let ops = [arg1_op, arg2_op];
+                    for iop in ops {
+                        if iop.is_xor() && iop.is_input_op() {
+                            let offset = iop.input_offset().expect("Invalid input offset");
+                            if offset == n {
+                                return Some(op_name.clone());
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    pub fn find_results_to_swap(&self) -> BTreeSet<String> {
+        let max_input_offset = self.max_input_offset();
+        let mut swaps = BTreeSet::new();
+        for (res_var, offset) in self.result_vars.iter() {
+            let res_op = self.operations.get(res_var).expect("Invalid result var");
+            if !res_op.is_xor() && *offset <= max_input_offset {
+                let swp = self.find_xor_with_input_xor(*offset);
+                if let Some(swp) = swp {
+                    swaps.insert(swp);
+                    swaps.insert(res_var.clone());
+                }
+            }
+        }
+        swaps
+    }
+
+    fn find_input_xor_var(&self, n: usize) -> Option<String> {
+        for (op_name, op) in self.operations.iter() {
+            if op.is_xor() && op.is_input_op() && op.input_offset().unwrap() == n {
+                return Some(op_name.clone());
+            }
+        }
+        None
+    }
+
+    /// The output wires of the gates that have to be swapped to make the circuit an adder.
+    pub fn swapped_wires(&self) -> BTreeSet<String> {
+        let swaps = self.find_results_to_swap();
+        swaps
+            .union(&self.find_result_input_xor_to_swap())
+            .cloned()
+            .collect()
+    }
+
+    pub fn find_result_input_xor_to_swap(&self) -> BTreeSet<String> {
+        let max_input_offset = self.max_input_offset();
+        let mut swaps = BTreeSet::new();
+        for (res_var, offset) in self.result_vars.iter() {
+            if *offset == 0 || *offset > max_input_offset {
+                continue;
+            }
+            let res_op = self.operations.get(res_var).expect("Invalid result var");
+            if res_op.is_xor() {
+                let op1 = self
+                    .operations
+                    .get(&res_op.operand1)
+                    .expect("Invalid operand");
+                let op2 = self
+                    .operations
+                    .get(&res_op.operand2)
+                    .expect("Invalid operand");
+                for op in [op1, op2] {
+                    if op.is_input_op() && op.input_offset().unwrap() == *offset && !op.is_xor() {
+                        let xor_op_var = self.find_input_xor_var(*offset).unwrap();
+                        swaps.insert(op.result.clone());
+                        swaps.insert(xor_op_var);
+                    }
+                }
+            }
+        }
+        swaps
+    }
+}
+
+fn parse_init_value(line: &str) -> Result<(String, u8)> {
+    let (var, value) = key_value(line, ":")?;
+    if parse_var(var).is_none() {
+        return Err(Error::parse(format!("Invalid variable '{}'", var)));
+    }
+    match value {
+        "0" => Ok((var.to_string(), 0)),
+        "1" => Ok((var.to_string(), 1)),
+        _ => Err(Error::at_token(
+            line,
+            value,
+            format!("Invalid value '{}'", value),
+        )),
+    }
+}
+
+/// Every problem with the gates that [`Operation::from_str`] doesn't catch on its own: wires
+/// driven by two gates, wires nothing drives and loops, which would make the evaluation recurse
+/// forever.
+fn validate_gates(block: &Block, inputs: &HashSet<String>) -> Vec<Error> {
+    let mut errors = Vec::new();
+    let mut gates: Vec<(usize, Operation)> = Vec::new();
+    let mut outputs: HashMap<String, usize> = HashMap::new();
+    for (i, line) in block.lines().enumerate() {
+        let line_number = block.line + i;
+        let gate = match Operation::from_str(line) {
+            Ok(gate) => gate,
+            Err(e) => {
+                errors.push(e.at_line(line_number));
+                continue;
+            }
+        };
+        if let Some(first) = outputs.get(&gate.result) {
+            errors.push(
+                Error::parse(format!(
+                    "Wire '{}' is already the output of line {}",
+                    gate.result, first
+                ))
+                .at_line(line_number),
+            );
+        } else {
+            outputs.insert(gate.result.clone(), line_number);
+        }
+        gates.push((line_number, gate));
+    }
+    for (line_number, gate) in &gates {
+        for operand in [&gate.operand1, &gate.operand2] {
+            if !inputs.contains(operand) && !outputs.contains_key(operand) {
+                errors.push(
+                    Error::parse(format!(
+                        "Wire '{}' is neither an input nor the output of a gate",
+                        operand
+                    ))
+                    .at_line(*line_number),
+                );
+            }
+        }
+    }
+    errors.extend(find_loop(&gates, &outputs));
+    errors
+}
+
+/// Reports a gate on a loop of gates, if there is one.
+fn find_loop(gates: &[(usize, Operation)], outputs: &HashMap<String, usize>) -> Option<Error> {
+    let by_line: HashMap<usize, &Operation> =
+        gates.iter().map(|(line, gate)| (*line, gate)).collect();
+    // Wires whose inputs have all been followed, and wires on the current path.
+    let mut done: HashSet<&str> = HashSet::new();
+    let mut on_path: HashSet<&str> = HashSet::new();
+    for (_, gate) in gates {
+        let mut stack: Vec<(&str, bool)> = vec![(&gate.result, false)];
+        while let Some((wire, expanded)) = stack.pop() {
+            if expanded {
+                on_path.remove(wire);
+                done.insert(wire);
+                continue;
+            }
+            if done.contains(wire) {
+                continue;
+            }
+            let Some(&line) = outputs.get(wire) else {
+                continue;
+            };
+            if !on_path.insert(wire) {
+                return Some(
+                    Error::parse(format!("Gate is part of a loop through '{}'", wire))
+                        .at_line(line),
+                );
+            }
+            let gate = by_line[&line];
+            stack.push((wire, true));
+            stack.push((&gate.operand1, false));
+            stack.push((&gate.operand2, false));
+        }
+    }
+    None
+}
+
+impl Solution for Day24 {
+    type Input = Circuit;
+
+    const DAY: u8 = 24;
+    const TITLE: &'static str = "Crossed Wires";
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Circuit::parse(input)
+    }
+
+    fn validate(&self, input: &str) -> Vec<Error> {
+        let [init_values, gates] = match sections(input) {
+            Ok(sections) => sections,
+            Err(e) => return vec![e],
+        };
+        let mut wires = HashSet::new();
+        let mut errors = validate::in_block(&init_values, |text| {
+            validate::each_line(text, |line| {
+                let (var, _) = key_value(line, ":")?;
+                wires.insert(var.to_string());
+                parse_init_value(line)
+            })
+        });
+        errors.extend(validate_gates(&gates, &wires));
+        errors
+    }
+
+    fn part1(&self, circuit: &Self::Input) -> impl Display {
+        circuit.eval()
+    }
+
+    fn part2(&self, circuit: &Self::Input) -> impl Display {
+        let swaps = circuit.swapped_wires();
+        assert_eq!(swaps.len(), 8);
+        swaps.iter().join(",")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::{example_answer, Part};
+
+    #[test]
+    fn test_example() {
+        // Part 2 assumes the gates form an adder, which the example doesn't.
+        let day = Day24;
+        assert_eq!(example_answer(&day, "day24.txt", Part::One), "2024");
+    }
+
+    #[test]
+    fn test_validate() {
+        let input = "x00: 1\ny00: 2\ny01: 0\n\n\
+                     x00 AND y01 -> z00\nx00 OR abc -> z01\nx00 XOR y00 -> z01\n\
+                     z01 NOR y00 -> def\ndef AND y00 -> ghi\nghi OR y00 -> def\n";
+        let errors = Day24.validate(input);
+        let messages: Vec<String> = errors.iter().map(Error::to_string).collect();
+        assert_eq!(
+            messages,
+            [
+                "2:6: Invalid value '2'",
+                "7: Wire 'z01' is already the output of line 6",
+                "8: Invalid operator 'NOR'",
+                "6: Wire 'abc' is neither an input nor the output of a gate",
+                "9: Gate is part of a loop through 'ghi'"
+            ]
+        );
+    }
+}
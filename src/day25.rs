@@ -0,0 +1,199 @@
+use std::fmt::Display;
+
+use crate::{
+    error::{Error, Result},
+    solution::Solution,
+    utils::{parse::blocks, validate},
+};
+
+pub struct Day25;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SchematicKind {
+    Lock,
+    Key,
+}
+
+#[derive(Debug, Clone)]
+pub struct Schematic {
+    kind: SchematicKind,
+    heights: Vec<usize>,
+    max_height: usize,
+}
+
+impl Schematic {
+    pub fn parse(block: &str) -> Result<Self> {
+        let lines: Vec<&str> = block.lines().collect();
+        if lines.len() < 2 {
+            return Err(Error::parse("Expected a schematic of at least two rows").at_line(1));
+        }
+        let num_columns = lines[0].len();
+        for (i, line) in lines.iter().enumerate() {
+            if line.len() != num_columns {
+                return Err(Error::parse(format!(
+                    "Expected {} columns, got {}",
+                    num_columns,
+                    line.len()
+                ))
+                .at_line(i + 1));
+            }
+            if let Some((j, c)) = line
+                .chars()
+                .enumerate()
+                .find(|&(_, c)| c != '#' && c != '.')
+            {
+                return Err(Error::at(
+                    i + 1,
+                    j + 1,
+                    format!("Invalid character '{}'", c),
+                ));
+            }
+        }
+        let mut heights = vec![0; num_columns];
+        // Lock schematic: Top-down filled
+        // Key schematic: Bottom-up filled
+        let kind = if lines[0].chars().all(|c| c == '#') {
+            SchematicKind::Lock
+        } else {
+            SchematicKind::Key
+        };
+        for line in lines.iter().skip(1) {
+            for (j, c) in line.chars().enumerate() {
+                if c == '#' {
+                    heights[j] += 1;
+                }
+            }
+        }
+        let max_height = lines.len() - 1;
+        Ok(Self {
+            kind,
+            heights,
+            max_height,
+        })
+    }
+
+    /// Checks that a lock has its top row filled and its bottom row empty, and a key the reverse.
+    fn validate_shape(block: &str) -> Vec<Error> {
+        let lines: Vec<&str> = block.lines().collect();
+        let (Some(top), Some(bottom)) = (lines.first(), lines.last()) else {
+            return Vec::new();
+        };
+        let row = |line: &str, ch: char| line.chars().all(|c| c == ch);
+        if (row(top, '#') && row(bottom, '.')) || (row(top, '.') && row(bottom, '#')) {
+            Vec::new()
+        } else {
+            vec![Error::parse(
+                "Expected a lock with only its top row filled or a key with only its bottom row",
+            )]
+        }
+    }
+
+    pub fn fits_with(&self, other: &Self) -> bool {
+        assert_eq!(self.heights.len(), other.heights.len());
+        assert_eq!(self.max_height, other.max_height);
+        assert_ne!(self.kind, other.kind);
+        let max_height = self.max_height;
+        self.heights
+            .iter()
+            .zip(other.heights.iter())
+            .all(|(h1, h2)| h1 + h2 <= max_height)
+    }
+}
+
+impl Solution for Day25 {
+    type Input = (Vec<Schematic>, Vec<Schematic>);
+
+    const DAY: u8 = 25;
+    const TITLE: &'static str = "Code Chronicle";
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        let schematics = blocks(input)
+            .iter()
+            .map(|block| block.parse_with(Schematic::parse))
+            .collect::<Result<Vec<_>>>()?;
+        if let Some(first) = schematics.first() {
+            if schematics.iter().any(|schematic| {
+                schematic.heights.len() != first.heights.len()
+                    || schematic.max_height != first.max_height
+            }) {
+                return Err(Error::parse("Schematics have different sizes"));
+            }
+        }
+        Ok(schematics
+            .into_iter()
+            .partition(|schematic| schematic.kind == SchematicKind::Lock))
+    }
+
+    fn validate(&self, input: &str) -> Vec<Error> {
+        let mut errors = validate::non_empty(input);
+        let mut first_size = None;
+        for block in blocks(input) {
+            let schematic = match block.parse_with(Schematic::parse) {
+                Ok(schematic) => schematic,
+                Err(e) => {
+                    errors.push(e);
+                    continue;
+                }
+            };
+            errors.extend(validate::in_block(&block, Schematic::validate_shape));
+            let size = (schematic.heights.len(), schematic.max_height + 1);
+            match first_size {
+                None => first_size = Some(size),
+                Some((columns, rows)) if (columns, rows) != size => errors.push(
+                    Error::parse(format!(
+                        "Expected a {}x{} schematic like the first one, got {}x{}",
+                        columns, rows, size.0, size.1
+                    ))
+                    .at_line(block.line),
+                ),
+                Some(_) => {}
+            }
+        }
+        errors
+    }
+
+    fn part1(&self, (locks, keys): &Self::Input) -> impl Display {
+        let mut num_fits = 0;
+        for lock in locks.iter() {
+            for key in keys.iter() {
+                if lock.fits_with(key) {
+                    num_fits += 1;
+                }
+            }
+        }
+        num_fits
+    }
+
+    /// There is no second puzzle on the last day.
+    fn part2(&self, _input: &Self::Input) -> impl Display {
+        "-"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::{example_answer, Part};
+
+    #[test]
+    fn test_example() {
+        let day = Day25;
+        assert_eq!(example_answer(&day, "day25.txt", Part::One), "3");
+    }
+
+    #[test]
+    fn test_validate() {
+        let input = "###\n.#.\n...\n\n...\n#.#\n###\n\n###\n#x#\n...\n\n.#.\n##.\n###\n\n##\n..\n";
+        let errors = Day25.validate(input);
+        let messages: Vec<String> = errors.iter().map(Error::to_string).collect();
+        assert_eq!(
+            messages,
+            [
+                "10:2: Invalid character 'x'",
+                "13: Expected a lock with only its top row filled or a key with only its bottom row",
+                "17: Expected a 3x3 schematic like the first one, got 2x2"
+            ]
+        );
+        assert_eq!(Day25.validate("").len(), 1);
+    }
+}
@@ -0,0 +1,198 @@
+use std::{fmt::Display, io, str::FromStr};
+
+/// Errors raised while loading, fetching or parsing puzzle inputs.
+#[derive(Debug)]
+pub enum Error {
+    Io {
+        file: String,
+        source: io::Error,
+    },
+    /// A request to the puzzle website failed or was answered with an error.
+    Http {
+        url: String,
+        reason: String,
+    },
+    Parse {
+        file: Option<String>,
+        line: Option<usize>,
+        column: Option<usize>,
+        reason: String,
+    },
+}
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+impl Error {
+    pub fn io(file: impl Display, source: io::Error) -> Self {
+        Error::Io {
+            file: file.to_string(),
+            source,
+        }
+    }
+
+    pub fn http(url: impl Display, reason: impl Into<String>) -> Self {
+        Error::Http {
+            url: url.to_string(),
+            reason: reason.into(),
+        }
+    }
+
+    /// A parse error without a known position.
+    pub fn parse(reason: impl Into<String>) -> Self {
+        Error::Parse {
+            file: None,
+            line: None,
+            column: None,
+            reason: reason.into(),
+        }
+    }
+
+    /// A parse error at a 1-based line and column.
+    pub fn at(line: usize, column: usize, reason: impl Into<String>) -> Self {
+        Error::Parse {
+            file: None,
+            line: Some(line),
+            column: Some(column),
+            reason: reason.into(),
+        }
+    }
+
+    /// A parse error at `token`, which must be a slice of `line`.
+    pub fn at_token(line: &str, token: &str, reason: impl Into<String>) -> Self {
+        Error::parse(reason).at_column(column_of(line, token))
+    }
+
+    /// Sets the line of the error, unless it is already known.
+    pub fn at_line(mut self, line_number: usize) -> Self {
+        if let Error::Parse { line, .. } = &mut self {
+            line.get_or_insert(line_number);
+        }
+        self
+    }
+
+    /// Sets the column of the error, unless it is already known.
+    pub fn at_column(mut self, column_number: usize) -> Self {
+        if let Error::Parse { column, .. } = &mut self {
+            column.get_or_insert(column_number);
+        }
+        self
+    }
+
+    /// The 1-based line and column of the error, where known.
+    pub fn position(&self) -> (Option<usize>, Option<usize>) {
+        match self {
+            Error::Parse { line, column, .. } => (*line, *column),
+            Error::Io { .. } | Error::Http { .. } => (None, None),
+        }
+    }
+
+    /// Moves the position of the error down by `offset` lines.
+    ///
+    /// Used when a section of the input is parsed on its own.
+    pub fn offset_lines(mut self, offset: usize) -> Self {
+        if let Error::Parse {
+            line: Some(line), ..
+        } = &mut self
+        {
+            *line += offset;
+        }
+        self
+    }
+
+    /// Sets the name of the file the error occurred in, unless it is already known.
+    pub fn in_file(mut self, file_name: impl Display) -> Self {
+        if let Error::Parse { file, .. } = &mut self {
+            file.get_or_insert_with(|| file_name.to_string());
+        }
+        self
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Io { file, source } => write!(f, "{}: {}", file, source),
+            Error::Http { url, reason } => write!(f, "{}: {}", url, reason),
+            Error::Parse {
+                file,
+                line,
+                column,
+                reason,
+            } => {
+                let mut location = Vec::new();
+                if let Some(file) = file {
+                    location.push(file.clone());
+                }
+                if let Some(line) = line {
+                    location.push(line.to_string());
+                    if let Some(column) = column {
+                        location.push(column.to_string());
+                    }
+                }
+                if !location.is_empty() {
+                    write!(f, "{}: ", location.join(":"))?;
+                }
+                write!(f, "{}", reason)?;
+                if let (None, Some(column)) = (line, column) {
+                    write!(f, " (column {})", column)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            Error::Http { .. } | Error::Parse { .. } => None,
+        }
+    }
+}
+
+/// 1-based column of `token` within `line`, which must contain it as a slice.
+pub fn column_of(line: &str, token: &str) -> usize {
+    let offset = (token.as_ptr() as usize).wrapping_sub(line.as_ptr() as usize);
+    if offset <= line.len() {
+        line[..offset].chars().count() + 1
+    } else {
+        1
+    }
+}
+
+/// Parses `token`, a slice of `line`, reporting its column on failure.
+pub fn parse_number<T: FromStr>(line: &str, token: &str) -> Result<T> {
+    token
+        .trim()
+        .parse()
+        .map_err(|_| Error::at_token(line, token, format!("Invalid number '{}'", token)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_display() {
+        let err = Error::parse("Invalid number 'x'")
+            .at_column(4)
+            .at_line(2)
+            .in_file("inputs/day01.txt");
+        assert_eq!(err.to_string(), "inputs/day01.txt:2:4: Invalid number 'x'");
+
+        let err = Error::parse("Empty input").in_file("inputs/day01.txt");
+        assert_eq!(err.to_string(), "inputs/day01.txt: Empty input");
+
+        let err = Error::at(3, 1, "Unexpected line").offset_lines(10);
+        assert_eq!(err.to_string(), "13:1: Unexpected line");
+    }
+
+    #[test]
+    fn test_parse_number() {
+        let line = "p=0,4 v=3,-x";
+        let err = parse_number::<i64>(line, &line[10..]).unwrap_err();
+        assert_eq!(err.to_string(), "Invalid number '-x' (column 11)");
+        assert_eq!(parse_number::<i64>(line, &line[4..5]).unwrap(), 4);
+    }
+}
@@ -0,0 +1,507 @@
+use std::collections::{BTreeSet, HashSet};
+
+use itertools::Itertools;
+
+use crate::utils::rng::Rng;
+
+/// A generator of random inputs for one day, which follow the same structure as the real ones so
+/// that the solvers can be stressed with inputs of any size.
+pub struct Generator {
+    pub day: u8,
+    /// What the size of the input counts.
+    pub size: &'static str,
+    /// The size of the real inputs.
+    pub default_size: usize,
+    generate: fn(&mut Rng, usize) -> String,
+}
+
+impl Generator {
+    /// The input of the given size generated from `seed`, always the same for the same seed.
+    pub fn generate(&self, seed: u64, size: usize) -> String {
+        (self.generate)(&mut Rng::new(seed), size)
+    }
+}
+
+pub const GENERATORS: [Generator; 9] = [
+    Generator {
+        day: 9,
+        size: "files",
+        default_size: 10000,
+        generate: disk_map,
+    },
+    Generator {
+        day: 10,
+        size: "tiles along each side of the map",
+        default_size: 45,
+        generate: |rng, size| topographic_map(rng, size, size),
+    },
+    Generator {
+        day: 13,
+        size: "claw machines",
+        default_size: 320,
+        generate: |rng, size| claw_machines(rng, size, 99),
+    },
+    Generator {
+        day: 14,
+        size: "robots",
+        default_size: 500,
+        generate: |rng, size| {
+            let time = rng.between(0, ROOM_WIDTH * ROOM_HEIGHT - 1);
+            robots(rng, size, ROOM_WIDTH, ROOM_HEIGHT, Some(time))
+        },
+    },
+    Generator {
+        day: 16,
+        size: "cells along each side of the maze",
+        default_size: 70,
+        generate: |rng, size| maze(rng, 2 * size + 1, 2 * size + 1, 0.1),
+    },
+    Generator {
+        day: 19,
+        size: "towels and designs",
+        default_size: 400,
+        generate: |rng, size| towels(rng, size, size),
+    },
+    Generator {
+        day: 21,
+        size: "codes",
+        default_size: 5,
+        generate: codes,
+    },
+    Generator {
+        day: 23,
+        size: "computers",
+        default_size: 520,
+        generate: |rng, size| network(rng, size, 13.0 / size as f64),
+    },
+    Generator {
+        day: 24,
+        size: "bits of the adder",
+        default_size: 45,
+        generate: |rng, size| adder(rng, size, 4.min(max_swaps(size))).input,
+    },
+];
+
+/// Looks up the generator of a day.
+pub fn generator(day: u8) -> Option<&'static Generator> {
+    GENERATORS.iter().find(|generator| generator.day == day)
+}
+
+/// Size of the room of day 14.
+const ROOM_WIDTH: i64 = 101;
+const ROOM_HEIGHT: i64 = 103;
+
+/// A disk map of day 9 with `files` files of 1 to 9 blocks, separated by 0 to 9 free blocks.
+pub fn disk_map(rng: &mut Rng, files: usize) -> String {
+    let mut layout = String::new();
+    for i in 0..files {
+        if i > 0 {
+            layout.push_str(&rng.between(0, 9).to_string());
+        }
+        layout.push_str(&rng.between(1, 9).to_string());
+    }
+    layout.push('\n');
+    layout
+}
+
+/// A `width` by `height` topographic map of day 10.
+///
+/// The heights climb by one from tile to tile along diagonal bands, so there are many hiking
+/// trails, and a tenth of the tiles get a random height to break some of them.
+pub fn topographic_map(rng: &mut Rng, width: usize, height: usize) -> String {
+    let (dx, dy) = (rng.between(0, 1), 1 - rng.between(0, 1) * 2);
+    let offset = rng.between(0, 9);
+    let mut map = String::new();
+    for y in 0..height as i64 {
+        for x in 0..width as i64 {
+            let h = if rng.chance(0.1) {
+                rng.between(0, 9)
+            } else {
+                (dx * x + dy * y + offset).rem_euclid(10)
+            };
+            map.push_str(&h.to_string());
+        }
+        map.push('\n');
+    }
+    map
+}
+
+/// `count` claw machines of day 13 whose buttons move at most `max_step` along each axis.
+///
+/// Half of the prizes can be reached with at most 100 presses of each button, and a quarter of the
+/// machines have buttons that move along the same line.
+pub fn claw_machines(rng: &mut Rng, count: usize, max_step: i64) -> String {
+    let mut blocks = Vec::new();
+    for _ in 0..count {
+        let (a, b) = if rng.chance(0.25) {
+            let base = (rng.between(0, max_step / 3), rng.between(0, max_step / 3));
+            let (ka, kb) = (rng.between(1, 3), rng.between(1, 3));
+            ((base.0 * ka, base.1 * ka), (base.0 * kb, base.1 * kb))
+        } else {
+            (
+                (rng.between(0, max_step), rng.between(0, max_step)),
+                (rng.between(0, max_step), rng.between(0, max_step)),
+            )
+        };
+        let prize = if rng.chance(0.5) {
+            let (pa, pb) = (rng.between(0, 100), rng.between(0, 100));
+            (pa * a.0 + pb * b.0, pa * a.1 + pb * b.1)
+        } else {
+            (
+                rng.between(0, 100 * max_step),
+                rng.between(0, 100 * max_step),
+            )
+        };
+        blocks.push(format!(
+            "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n",
+            a.0, a.1, b.0, b.1, prize.0, prize.1
+        ));
+    }
+    blocks.join("\n")
+}
+
+/// A network of day 23 with `computers` computers, each pair connected with probability
+/// `connected`, and at least one connection.
+pub fn network(rng: &mut Rng, computers: usize, connected: f64) -> String {
+    assert!(
+        (2..=26 * 26).contains(&computers),
+        "There are 2 to 676 computers"
+    );
+    let mut names: Vec<String> = (0..26 * 26)
+        .map(|i| {
+            let letter = |n: usize| (b'a' + n as u8) as char;
+            format!("{}{}", letter(i / 26), letter(i % 26))
+        })
+        .collect();
+    rng.shuffle(&mut names);
+    names.truncate(computers);
+    let mut connections: Vec<String> = names
+        .iter()
+        .tuple_combinations()
+        .filter(|_| rng.chance(connected))
+        .map(|(a, b)| format!("{}-{}\n", a, b))
+        .collect();
+    if connections.is_empty() {
+        connections.push(format!("{}-{}\n", names[0], names[1]));
+    }
+    rng.shuffle(&mut connections);
+    connections.concat()
+}
+
+/// `count` robots of day 14 moving in a `width` by `height` room.
+///
+/// With `picture_at`, half of the robots gather in a square at that time, which is what part 2
+/// looks for.
+pub fn robots(
+    rng: &mut Rng,
+    count: usize,
+    width: i64,
+    height: i64,
+    picture_at: Option<i64>,
+) -> String {
+    let in_picture = if picture_at.is_some() { count / 2 } else { 0 };
+    let side = ((in_picture as f64).sqrt().ceil() as i64).max(1);
+    let mut lines = Vec::new();
+    for i in 0..count {
+        let (vx, vy) = (
+            rng.between(1 - width, width - 1),
+            rng.between(1 - height, height - 1),
+        );
+        let (px, py) = match picture_at {
+            Some(time) if i < in_picture => {
+                let i = i as i64;
+                let x = (width - side) / 2 + i % side;
+                let y = (height - side) / 2 + i / side;
+                (
+                    (x - vx * time).rem_euclid(width),
+                    (y - vy * time).rem_euclid(height),
+                )
+            }
+            _ => (rng.between(0, width - 1), rng.between(0, height - 1)),
+        };
+        lines.push(format!("p={},{} v={},{}\n", px, py, vx, vy));
+    }
+    rng.shuffle(&mut lines);
+    lines.concat()
+}
+
+/// A maze of day 16, `width` by `height` with both odd, the start in the bottom left corner and
+/// the end in the top right one.
+///
+/// The maze is carved as a tree, so there is a single path between any two tiles, then each of
+/// the walls between two corridors is knocked down with probability `loops` to make alternative
+/// paths.
+pub fn maze(rng: &mut Rng, width: usize, height: usize, loops: f64) -> String {
+    assert!(
+        width % 2 == 1 && height % 2 == 1 && width >= 3 && height >= 3,
+        "The maze must have odd dimensions of at least 3"
+    );
+    let mut grid = vec![vec!['#'; width]; height];
+    let start = (1, height - 2);
+    grid[start.1][start.0] = '.';
+    let mut stack = vec![start];
+    while let Some(&(x, y)) = stack.last() {
+        let mut next: Vec<(usize, usize)> = [(0, -2), (2, 0), (0, 2), (-2, 0)]
+            .into_iter()
+            .filter_map(|(dx, dy)| {
+                let nx = x.checked_add_signed(dx).filter(|&nx| nx < width - 1)?;
+                let ny = y.checked_add_signed(dy).filter(|&ny| ny < height - 1)?;
+                (grid[ny][nx] == '#').then_some((nx, ny))
+            })
+            .collect();
+        if next.is_empty() {
+            stack.pop();
+            continue;
+        }
+        rng.shuffle(&mut next);
+        let (nx, ny) = next[0];
+        grid[(y + ny) / 2][(x + nx) / 2] = '.';
+        grid[ny][nx] = '.';
+        stack.push((nx, ny));
+    }
+    for (y, row) in grid.iter_mut().enumerate().take(height - 1).skip(1) {
+        for (x, tile) in row.iter_mut().enumerate().take(width - 1).skip(1) {
+            let between_corridors = (x % 2 == 0) != (y % 2 == 0);
+            if between_corridors && *tile == '#' && rng.chance(loops) {
+                *tile = '.';
+            }
+        }
+    }
+    grid[start.1][start.0] = 'S';
+    grid[1][width - 2] = 'E';
+    grid.iter()
+        .map(|row| row.iter().collect::<String>() + "\n")
+        .collect()
+}
+
+const COLORS: [char; 5] = ['w', 'u', 'b', 'r', 'g'];
+
+/// The towels and designs of day 19: `towels` different patterns of 1 to 8 stripes, and `designs`
+/// designs made by lining up towels, a third of them with a stripe changed so that some can't be
+/// made.
+pub fn towels(rng: &mut Rng, towels: usize, designs: usize) -> String {
+    let mut patterns: Vec<String> = Vec::new();
+    let mut seen = HashSet::new();
+    // There are only so many short patterns, so give up on duplicates eventually.
+    for _ in 0..towels * 10 {
+        if patterns.len() == towels {
+            break;
+        }
+        let len = rng.between(1, 8) as usize;
+        let pattern: String = (0..len).map(|_| *rng.choose(&COLORS)).collect();
+        if seen.insert(pattern.clone()) {
+            patterns.push(pattern);
+        }
+    }
+    let mut text = patterns.join(", ");
+    text.push_str("\n\n");
+    for _ in 0..designs {
+        let len = rng.between(20, 60) as usize;
+        let mut design: Vec<char> = Vec::new();
+        while design.len() < len {
+            design.extend(rng.choose(&patterns).chars());
+        }
+        if rng.chance(1.0 / 3.0) {
+            let i = rng.below(design.len());
+            design[i] = *rng.choose(&COLORS);
+        }
+        text.extend(design);
+        text.push('\n');
+    }
+    text
+}
+
+/// `count` different door codes of day 21, three digits followed by `A`.
+pub fn codes(rng: &mut Rng, count: usize) -> String {
+    assert!(count <= 1000, "There are only 1000 codes");
+    let mut codes: Vec<usize> = (0..1000).collect();
+    rng.shuffle(&mut codes);
+    codes[..count]
+        .iter()
+        .map(|code| format!("{:03}A\n", code))
+        .collect()
+}
+
+/// A circuit of day 24 and the output wires that were swapped in it.
+pub struct Adder {
+    pub input: String,
+    pub swapped: BTreeSet<String>,
+}
+
+impl Adder {
+    /// The swapped wires in the format of the answer of part 2.
+    pub fn answer(&self) -> String {
+        self.swapped.iter().join(",")
+    }
+}
+
+/// The most swaps [`adder`] can make in an adder of `bits` bits.
+pub fn max_swaps(bits: usize) -> usize {
+    // Each swapped bit rules out itself and its two neighbors, out of the bits 1 to bits - 2.
+    bits.saturating_sub(2).div_ceil(3)
+}
+
+/// A ripple-carry adder of day 24 adding two random numbers of `bits` bits, with the outputs of
+/// `swaps` pairs of gates swapped.
+///
+/// Each swap is within the full adder of a single bit, and no two swapped bits are next to each
+/// other, like in the puzzle: the sum output is swapped with the carry or one of the AND gates, or
+/// the two gates on the input bits are swapped with each other.
+pub fn adder(rng: &mut Rng, bits: usize, swaps: usize) -> Adder {
+    assert!(bits > 0, "The adder needs at least one bit");
+    assert!(
+        swaps <= max_swaps(bits),
+        "An adder of {} bits fits at most {} swaps",
+        bits,
+        max_swaps(bits)
+    );
+    let mut names = HashSet::new();
+    let mut wire = |rng: &mut Rng| loop {
+        let name: String = (0..3)
+            .map(|i| {
+                let letters = if i == 0 { 23 } else { 26 };
+                (b'a' + rng.below(letters) as u8) as char
+            })
+            .collect();
+        if names.insert(name.clone()) {
+            return name;
+        }
+    };
+
+    // The gates of each bit as (operand1, operator, operand2, output).
+    let mut gates: Vec<[String; 4]> = Vec::new();
+    let gate = |a: &str, op: &str, b: &str, out: &str| {
+        [
+            a.to_string(),
+            op.to_string(),
+            b.to_string(),
+            out.to_string(),
+        ]
+    };
+    // Indexes of the gates in `gates` that are swap candidates of each bit.
+    let mut candidates: Vec<[usize; 5]> = Vec::new();
+    let mut carry = if bits == 1 {
+        "z01".to_string()
+    } else {
+        wire(rng)
+    };
+    gates.push(gate("x00", "XOR", "y00", "z00"));
+    gates.push(gate("x00", "AND", "y00", &carry));
+    for i in 1..bits {
+        let (x, y, z) = (
+            format!("x{:02}", i),
+            format!("y{:02}", i),
+            format!("z{:02}", i),
+        );
+        let (sum, and, carry_and) = (wire(rng), wire(rng), wire(rng));
+        let next_carry = if i == bits - 1 {
+            format!("z{:02}", bits)
+        } else {
+            wire(rng)
+        };
+        let first = gates.len();
+        gates.push(gate(&x, "XOR", &y, &sum));
+        gates.push(gate(&x, "AND", &y, &and));
+        gates.push(gate(&sum, "XOR", &carry, &z));
+        gates.push(gate(&sum, "AND", &carry, &carry_and));
+        gates.push(gate(&and, "OR", &carry_and, &next_carry));
+        candidates.push([first, first + 1, first + 2, first + 3, first + 4]);
+        carry = next_carry;
+    }
+
+    // Bits 1 to bits - 2, leaving out bit 0 and the last bit whose carry is an output.
+    let mut positions: Vec<usize> = (1..bits.saturating_sub(1)).collect();
+    rng.shuffle(&mut positions);
+    let mut chosen: Vec<usize> = Vec::new();
+    for position in positions {
+        if chosen.len() < swaps && chosen.iter().all(|&c| c.abs_diff(position) > 1) {
+            chosen.push(position);
+        }
+    }
+    let mut swapped = BTreeSet::new();
+    for position in chosen {
+        let [sum, and, z, carry_and, carry] = candidates[position - 1];
+        let (a, b) = *rng.choose(&[(z, and), (z, carry_and), (z, carry), (sum, and)]);
+        let (out_a, out_b) = (gates[a][3].clone(), gates[b][3].clone());
+        swapped.insert(out_a.clone());
+        swapped.insert(out_b.clone());
+        gates[a][3] = out_b;
+        gates[b][3] = out_a;
+    }
+
+    let mut input = String::new();
+    for var in ['x', 'y'] {
+        for i in 0..bits {
+            input.push_str(&format!("{}{:02}: {}\n", var, i, rng.below(2)));
+        }
+    }
+    input.push('\n');
+    rng.shuffle(&mut gates);
+    for [a, op, b, out] in gates {
+        let (a, b) = if rng.chance(0.5) { (a, b) } else { (b, a) };
+        input.push_str(&format!("{} {} {} -> {}\n", a, op, b, out));
+    }
+    Adder { input, swapped }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        day14::Day14,
+        day24::Circuit,
+        runner::lint,
+        solution::{solution, Part},
+    };
+
+    #[test]
+    fn test_generators_are_reproducible_and_valid() {
+        for generator in &GENERATORS {
+            let size = generator.default_size.min(40);
+            let input = generator.generate(1, size);
+            assert_eq!(input, generator.generate(1, size));
+            assert_ne!(input, generator.generate(2, size));
+            let solution = solution(generator.day).unwrap();
+            let errors = lint(solution.as_ref(), &input);
+            assert!(errors.is_empty(), "Day {}: {:?}", generator.day, errors);
+        }
+    }
+
+    #[test]
+    fn test_maze() {
+        let maze = maze(&mut Rng::new(3), 7, 5, 0.0);
+        assert_eq!(maze.lines().count(), 5);
+        assert!(maze.lines().all(|line| line.len() == 7));
+        // A tree of 3 by 2 cells has 5 passages between its 6 cells.
+        assert_eq!(maze.matches(['.', 'S', 'E']).count(), 6 + 5);
+    }
+
+    #[test]
+    fn test_robots_picture() {
+        let day = Day14::default();
+        let input = robots(&mut Rng::new(5), 300, day.width, day.height, Some(4321));
+        let robots = crate::utils::parse::parse_lines(&input, crate::day14::parse_robot).unwrap();
+        assert_eq!(day.picture_time(&robots), Some(4321));
+    }
+
+    #[test]
+    fn test_adder_swaps() {
+        let mut rng = Rng::new(11);
+        for bits in [12, 20, 45] {
+            let adder = adder(&mut rng, bits, 4);
+            assert_eq!(adder.swapped.len(), 8);
+            let circuit = Circuit::parse(&adder.input).unwrap();
+            assert_eq!(circuit.swapped_wires(), adder.swapped);
+
+            let day = solution(24).unwrap();
+            let report = crate::runner::solve_parts(day.as_ref(), &adder.input, &[Part::Two], None);
+            assert_eq!(report.unwrap().1[0].answer, adder.answer());
+        }
+        let adder = adder(&mut rng, 8, 0);
+        assert!(adder.swapped.is_empty());
+        assert_eq!(
+            adder.input.lines().filter(|l| l.contains("->")).count(),
+            2 + 5 * 7
+        );
+    }
+}
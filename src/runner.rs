@@ -0,0 +1,933 @@
+use std::{
+    any::Any,
+    fmt::Display,
+    fs,
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use crate::{
+    answers::{input_name, Answers, Verdict},
+    error::{Error, Result},
+    solution::{Part, Runnable},
+    utils::{
+        cancel,
+        hash::sha256_hex,
+        input::{input_file_name, InputSource},
+        json::Json,
+        record::Recorder,
+        render::RenderFormat,
+        table::{format_csv, format_duration, format_table},
+    },
+};
+
+/// Time limit for solving each part, unless the command line sets another.
+pub const DEFAULT_TIME_LIMIT: Duration = Duration::from_secs(60);
+
+/// Why the solver of a part stopped without an answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Failure {
+    /// It was stopped at the time limit.
+    TimedOut,
+    /// It panicked with this message.
+    Panicked(String),
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Failure::TimedOut => write!(f, "timed out"),
+            Failure::Panicked(message) => write!(f, "panicked: {}", message),
+        }
+    }
+}
+
+/// The answer computed for one part of a day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartResult {
+    pub part: Part,
+    /// The answer, empty if the solver failed.
+    pub answer: String,
+    /// Wall time spent solving the part, without parsing.
+    pub elapsed: Duration,
+    /// Set once the answer has been checked against the registry.
+    pub verdict: Option<Verdict>,
+    /// Set if the solver stopped before finding an answer.
+    pub failure: Option<Failure>,
+}
+
+impl PartResult {
+    /// The answer, followed by the verdict if it has been verified.
+    fn cell(&self) -> String {
+        if let Some(failure) = &self.failure {
+            return failure.to_string();
+        }
+        match &self.verdict {
+            Some(verdict) => format!("{} ({})", self.answer, verdict),
+            None => self.answer.clone(),
+        }
+    }
+
+    /// Why there is no answer, if there is none.
+    pub fn error(&self) -> Option<String> {
+        self.failure.as_ref().map(|failure| match failure {
+            Failure::TimedOut => format!("timed out after {}", format_duration(self.elapsed)),
+            failure => failure.to_string(),
+        })
+    }
+}
+
+/// Outcome of running the selected parts of one day.
+#[derive(Debug, Clone)]
+pub struct DayReport {
+    pub day: u8,
+    pub title: &'static str,
+    /// Where the input was read from.
+    pub input: String,
+    /// SHA-256 of the input as read, with line endings normalized to `\n`, if it could be read.
+    pub input_hash: Option<String>,
+    /// Wall time spent parsing the input, if it could be read.
+    pub parse_time: Option<Duration>,
+    pub results: Result<Vec<PartResult>, String>,
+    /// Problems the validator of the day found in the input, which the parts were run on anyway.
+    pub warnings: Vec<String>,
+}
+
+impl DayReport {
+    /// The answer of the part, if it was solved.
+    pub fn answer(&self, part: Part) -> Option<&str> {
+        self.results
+            .as_ref()
+            .ok()?
+            .iter()
+            .find(|r| r.part == part && r.failure.is_none())
+            .map(|r| r.answer.as_str())
+    }
+
+    /// Checks the answers against the registry, recorded for the input named `input`.
+    pub fn verify(&mut self, answers: &Answers, input: Option<&str>) {
+        if let Ok(results) = &mut self.results {
+            for result in results.iter_mut().filter(|result| result.failure.is_none()) {
+                result.verdict = Some(answers.check(input, self.day, result.part, &result.answer));
+            }
+        }
+    }
+
+    /// Whether the solver of any part timed out or panicked.
+    pub fn has_failures(&self) -> bool {
+        self.results.iter().flatten().any(|r| r.failure.is_some())
+    }
+
+    /// The verdicts of all verified parts.
+    pub fn verdicts(&self) -> impl Iterator<Item = &Verdict> {
+        self.results
+            .iter()
+            .flatten()
+            .filter_map(|r| r.verdict.as_ref())
+    }
+}
+
+/// Every problem the validator of the day finds in `input`, ordered by position, or the error of
+/// parsing it if the validator finds none.
+pub fn lint(solution: &dyn Runnable, input: &str) -> Vec<Error> {
+    let mut errors = solution.validate(input);
+    if errors.is_empty() {
+        errors.extend(solution.parse(input).err());
+    }
+    errors.sort_by_key(Error::position);
+    errors
+}
+
+/// Reads the input of the day from `source` and lints it, see [`lint`].
+pub fn lint_day(solution: &dyn Runnable, source: &InputSource) -> Result<Vec<Error>> {
+    let input = source.read()?;
+    Ok(lint(solution, &input)
+        .into_iter()
+        .map(|e| e.in_file(source))
+        .collect())
+}
+
+/// Parses the input once and solves each of the given parts with it.
+///
+/// Returns the time spent parsing along with the results. The input isn't validated, so that a
+/// problem that only concerns one part doesn't keep the other from running. A part that takes
+/// longer than `limit` is stopped, see
+/// [`cancel::with_deadline`], and a part whose solver panics is recorded with the panic's message
+/// instead of bringing down the other days.
+pub fn solve_parts(
+    solution: &dyn Runnable,
+    input: &str,
+    parts: &[Part],
+    limit: Option<Duration>,
+) -> Result<(Duration, Vec<PartResult>)> {
+    let start = Instant::now();
+    let parsed = solution.parse(input)?;
+    let parse_time = start.elapsed();
+    Ok((
+        parse_time,
+        solve_parsed(solution, parsed.as_ref(), parts, limit),
+    ))
+}
+
+/// Solves each of the given parts with the parsed input, see [`solve_parts`].
+fn solve_parsed(
+    solution: &dyn Runnable,
+    parsed: &dyn Any,
+    parts: &[Part],
+    limit: Option<Duration>,
+) -> Vec<PartResult> {
+    parts
+        .iter()
+        .map(|&part| solve_part(solution, parsed, part, limit))
+        .collect()
+}
+
+/// Solves the part with the parsed input within `limit`, catching a panic of its solver.
+pub(crate) fn solve_part(
+    solution: &dyn Runnable,
+    parsed: &dyn Any,
+    part: Part,
+    limit: Option<Duration>,
+) -> PartResult {
+    let start = Instant::now();
+    let solved = panic::catch_unwind(AssertUnwindSafe(|| {
+        cancel::with_deadline(limit, || solution.solve(parsed, part))
+    }));
+    let (answer, failure) = match solved {
+        Ok(Ok(answer)) => (answer, None),
+        Ok(Err(_)) => (String::new(), Some(Failure::TimedOut)),
+        Err(payload) => (
+            String::new(),
+            Some(Failure::Panicked(panic_message(&*payload))),
+        ),
+    };
+    PartResult {
+        part,
+        answer,
+        elapsed: start.elapsed(),
+        verdict: None,
+        failure,
+    }
+}
+
+/// The message a panic was raised with.
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+/// The input of a day, read from its source and parsed once, so that it can be solved, rendered
+/// and recorded even if the source can only be read once, like stdin.
+pub struct DayInput {
+    pub source: InputSource,
+    /// SHA-256 of the input as read, with line endings normalized to `\n`, if it could be read.
+    pub hash: Option<String>,
+    /// Problems the validator of the day found in the input.
+    pub warnings: Vec<String>,
+    /// The parsed input with the time spent parsing it, or why it couldn't be read or parsed.
+    parsed: Result<(Duration, Box<dyn Any>), String>,
+}
+
+impl DayInput {
+    /// Reads the input of the day from `source`, validates it and parses it.
+    pub fn load(solution: &dyn Runnable, source: &InputSource) -> Self {
+        let mut hash = None;
+        let mut warnings = Vec::new();
+        let parsed = source.read().and_then(|input| {
+            hash = Some(sha256_hex(&input));
+            let mut problems = solution.validate(&input);
+            problems.sort_by_key(Error::position);
+            warnings = problems
+                .into_iter()
+                .map(|e| e.in_file(source).to_string())
+                .collect();
+            let start = Instant::now();
+            let parsed = solution.parse(&input).map_err(|e| e.in_file(source))?;
+            Ok((start.elapsed(), parsed))
+        });
+        DayInput {
+            source: source.clone(),
+            hash,
+            warnings,
+            parsed: parsed.map_err(|e| e.to_string()),
+        }
+    }
+
+    /// The parsed input, or why there is none.
+    pub fn parsed(&self) -> Result<&dyn Any, String> {
+        match &self.parsed {
+            Ok((_, parsed)) => Ok(parsed.as_ref()),
+            Err(e) => Err(e.clone()),
+        }
+    }
+}
+
+/// Reads the input of the day from `source` and solves the given parts, each within `limit`.
+///
+/// What the validator finds in the input is reported as warnings, see [`lint`] for checking
+/// inputs strictly.
+pub fn run_day(
+    solution: &dyn Runnable,
+    source: &InputSource,
+    parts: &[Part],
+    limit: Option<Duration>,
+) -> DayReport {
+    solve_day(solution, &DayInput::load(solution, source), parts, limit)
+}
+
+/// Solves the given parts with the loaded input of the day, each within `limit`.
+pub fn solve_day(
+    solution: &dyn Runnable,
+    input: &DayInput,
+    parts: &[Part],
+    limit: Option<Duration>,
+) -> DayReport {
+    let (parse_time, results) = match &input.parsed {
+        Ok((parse_time, parsed)) => (
+            Some(*parse_time),
+            Ok(solve_parsed(solution, parsed.as_ref(), parts, limit)),
+        ),
+        Err(e) => (None, Err(e.clone())),
+    };
+    DayReport {
+        day: solution.day(),
+        title: solution.title(),
+        input: input.source.to_string(),
+        input_hash: input.hash.clone(),
+        parse_time,
+        results,
+        warnings: input.warnings.clone(),
+    }
+}
+
+/// Writes each picture the day renders of its loaded input into `dir`, as
+/// `dayNN-<name>.<ext>` in every [`RenderFormat`]. Returns the written files.
+pub fn render_day(
+    solution: &dyn Runnable,
+    input: &DayInput,
+    dir: &Path,
+) -> Result<Vec<PathBuf>, String> {
+    let frames = solution.render(input.parsed()?);
+    if !frames.is_empty() {
+        fs::create_dir_all(dir).map_err(|e| Error::io(dir.display(), e).to_string())?;
+    }
+    let mut files = Vec::new();
+    for (name, frame) in frames {
+        for format in RenderFormat::ALL {
+            let file_name = format!("day{:02}-{}.{}", solution.day(), name, format.extension());
+            let path = dir.join(file_name);
+            fs::write(&path, frame.encode(format))
+                .map_err(|e| Error::io(path.display(), e).to_string())?;
+            files.push(path);
+        }
+    }
+    Ok(files)
+}
+
+/// Records the simulation of `part` with the loaded input of the day into `recorder`.
+pub fn record_day(
+    solution: &dyn Runnable,
+    input: &DayInput,
+    part: Part,
+    recorder: &mut Recorder,
+) -> Result<(), String> {
+    solution.record(input.parsed()?, part, recorder);
+    Ok(())
+}
+
+/// Formats the reports as a table with one row per day, including the time of each step.
+pub fn summary_table(reports: &[DayReport]) -> String {
+    let rows: Vec<Vec<String>> = reports
+        .iter()
+        .map(|report| {
+            let mut row = vec![report.day.to_string(), report.title.to_string()];
+            match &report.results {
+                Ok(results) => {
+                    let result = |part| results.iter().find(|r: &&PartResult| r.part == part);
+                    let (part1, part2) = (result(Part::One), result(Part::Two));
+                    row.extend([
+                        part1.map_or_else(String::new, PartResult::cell),
+                        part2.map_or_else(String::new, PartResult::cell),
+                        report.parse_time.map_or_else(String::new, format_duration),
+                        part1.map_or_else(String::new, |r| format_duration(r.elapsed)),
+                        part2.map_or_else(String::new, |r| format_duration(r.elapsed)),
+                    ]);
+                }
+                Err(e) => row.push(format!("error: {}", e)),
+            }
+            row
+        })
+        .collect();
+    format_table(
+        &[
+            "Day", "Title", "Part 1", "Part 2", "Parse", "Time 1", "Time 2",
+        ],
+        &rows,
+    )
+}
+
+/// Formats the reports as CSV with one row per part, with times in nanoseconds.
+pub fn summary_csv(reports: &[DayReport]) -> String {
+    let mut rows = Vec::new();
+    for report in reports {
+        let parse_ns = report
+            .parse_time
+            .map_or_else(String::new, |t| t.as_nanos().to_string());
+        let input_hash = report.input_hash.clone().unwrap_or_default();
+        match &report.results {
+            Ok(results) => {
+                for result in results {
+                    rows.push(vec![
+                        report.day.to_string(),
+                        result.part.to_string(),
+                        result.answer.clone(),
+                        parse_ns.clone(),
+                        result.elapsed.as_nanos().to_string(),
+                        result
+                            .verdict
+                            .as_ref()
+                            .map_or_else(String::new, Verdict::to_string),
+                        result.error().unwrap_or_default(),
+                        report.input.clone(),
+                        input_hash.clone(),
+                    ]);
+                }
+            }
+            Err(e) => rows.push(vec![
+                report.day.to_string(),
+                String::new(),
+                String::new(),
+                parse_ns,
+                String::new(),
+                String::new(),
+                e.clone(),
+                report.input.clone(),
+                input_hash,
+            ]),
+        }
+    }
+    format_csv(
+        &[
+            "day",
+            "part",
+            "answer",
+            "parse_ns",
+            "time_ns",
+            "verdict",
+            "error",
+            "input",
+            "input_hash",
+        ],
+        &rows,
+    )
+}
+
+/// Formats the reports as a JSON array with one object per part, or one per day that failed
+/// before solving. Times are in nanoseconds, missing values are `null`, and `expected` holds the
+/// known answer when the verdict is `fail`.
+pub fn summary_json(reports: &[DayReport]) -> String {
+    let mut records = Vec::new();
+    for report in reports {
+        let record = |part: Option<&PartResult>, error: Option<String>| {
+            let verdict = part.and_then(|r| r.verdict.as_ref());
+            let expected = match verdict {
+                Some(Verdict::Fail { expected }) => Some(expected.as_str()),
+                _ => None,
+            };
+            Json::object([
+                ("day", report.day.into()),
+                ("title", report.title.into()),
+                ("part", part.map(|r| r.part.number()).into()),
+                (
+                    "answer",
+                    part.filter(|r| r.failure.is_none())
+                        .map(|r| r.answer.as_str())
+                        .into(),
+                ),
+                ("parse_ns", report.parse_time.map(|t| t.as_nanos()).into()),
+                ("time_ns", part.map(|r| r.elapsed.as_nanos()).into()),
+                ("verdict", verdict.map(Verdict::status).into()),
+                ("expected", expected.into()),
+                ("error", error.into()),
+                ("input", report.input.as_str().into()),
+                ("input_hash", report.input_hash.clone().into()),
+            ])
+        };
+        match &report.results {
+            Ok(results) => records.extend(results.iter().map(|r| record(Some(r), r.error()))),
+            Err(e) => records.push(record(None, Some(e.clone()))),
+        }
+    }
+    Json::lines(&records)
+}
+
+/// One line counting the verdicts of all verified parts, and the failed days and parts as errors.
+pub fn verification_summary(reports: &[DayReport]) -> String {
+    let (mut passed, mut failed, mut unknown) = (0, 0, 0);
+    for verdict in reports.iter().flat_map(DayReport::verdicts) {
+        match verdict {
+            Verdict::Pass => passed += 1,
+            Verdict::Fail { .. } => failed += 1,
+            Verdict::Unknown => unknown += 1,
+        }
+    }
+    let failures = reports
+        .iter()
+        .flat_map(|r| r.results.iter().flatten())
+        .filter(|r| r.failure.is_some())
+        .count();
+    let errors = reports.iter().filter(|r| r.results.is_err()).count() + failures;
+    format!(
+        "{} passed, {} failed, {} unknown, {} errors",
+        passed, failed, unknown, errors
+    )
+}
+
+/// How many times slower than on its fastest input a part must be to be flagged as slow.
+pub const SLOWDOWN_FACTOR: f64 = 5.0;
+
+/// Parts faster than this are never flagged as slow, their times are mostly noise.
+pub const SLOWDOWN_MIN_TIME: Duration = Duration::from_millis(10);
+
+/// One day solved with the input of each of several accounts, to catch solvers that only work, or
+/// only work fast, on some of them.
+#[derive(Debug, Clone)]
+pub struct DayMatrix {
+    pub day: u8,
+    pub title: &'static str,
+    pub parts: Vec<Part>,
+    /// One report per input directory, in order.
+    pub reports: Vec<DayReport>,
+}
+
+impl DayMatrix {
+    /// Solves the given parts with the `dayNN.txt` input of each of `dirs`, each within `limit`.
+    pub fn run(
+        solution: &dyn Runnable,
+        dirs: &[PathBuf],
+        parts: &[Part],
+        limit: Option<Duration>,
+    ) -> Self {
+        let reports = dirs
+            .iter()
+            .map(|dir| {
+                let source = InputSource::File(dir.join(input_file_name(solution.day())));
+                run_day(solution, &source, parts, limit)
+            })
+            .collect();
+        DayMatrix {
+            day: solution.day(),
+            title: solution.title(),
+            parts: parts.to_vec(),
+            reports,
+        }
+    }
+
+    /// Checks the answers of each input against those recorded for it.
+    pub fn verify(&mut self, answers: &Answers) {
+        for report in &mut self.reports {
+            let source = InputSource::File(PathBuf::from(&report.input));
+            let name = input_name(&source, report.day);
+            report.verify(answers, name.as_deref());
+        }
+    }
+
+    /// What went wrong with `part` on each of the inputs, named by `inputs`: errors, failed
+    /// solvers, wrong answers, and times at least [`SLOWDOWN_FACTOR`] times that of the fastest
+    /// input.
+    pub fn flags(&self, part: Part, inputs: &[String]) -> Vec<String> {
+        let mut flags = Vec::new();
+        let mut times = Vec::new();
+        for (report, input) in self.reports.iter().zip(inputs) {
+            let result = match &report.results {
+                Ok(results) => results.iter().find(|r| r.part == part),
+                Err(_) => {
+                    flags.push(format!("error on {}", input));
+                    continue;
+                }
+            };
+            let Some(result) = result else {
+                continue;
+            };
+            match (&result.failure, &result.verdict) {
+                (Some(Failure::TimedOut), _) => flags.push(format!("timed out on {}", input)),
+                (Some(Failure::Panicked(_)), _) => flags.push(format!("panicked on {}", input)),
+                (None, Some(Verdict::Fail { .. })) => flags.push(format!("FAIL on {}", input)),
+                (None, _) => times.push((result.elapsed, input)),
+            }
+        }
+        if let Some(fastest) = times.iter().map(|&(time, _)| time).min() {
+            for (time, input) in times {
+                let factor = time.as_secs_f64() / fastest.as_secs_f64();
+                if time >= SLOWDOWN_MIN_TIME && factor >= SLOWDOWN_FACTOR {
+                    flags.push(format!("{:.1}x slower on {}", factor, input));
+                }
+            }
+        }
+        flags
+    }
+
+    /// Whether any input couldn't be solved, or got a wrong answer. Slowdowns don't count.
+    pub fn has_errors(&self) -> bool {
+        self.reports.iter().any(|report| {
+            report.results.is_err()
+                || report.has_failures()
+                || report
+                    .verdicts()
+                    .any(|verdict| matches!(verdict, Verdict::Fail { .. }))
+        })
+    }
+}
+
+/// Formats the matrices as a table with one row per day and part, with the answer and time of each
+/// of `inputs` side by side, and what went wrong in the last column.
+pub fn matrix_table(inputs: &[String], matrices: &[DayMatrix]) -> String {
+    let mut headers = vec!["Day", "Title", "Part"];
+    for input in inputs {
+        headers.extend([input.as_str(), "Time"]);
+    }
+    headers.push("Flags");
+    let mut rows = Vec::new();
+    for matrix in matrices {
+        for &part in &matrix.parts {
+            let mut row = vec![
+                matrix.day.to_string(),
+                matrix.title.to_string(),
+                part.to_string(),
+            ];
+            for report in &matrix.reports {
+                let cells = match &report.results {
+                    Ok(results) => results
+                        .iter()
+                        .find(|r| r.part == part)
+                        .map_or_else(Default::default, |r| [r.cell(), format_duration(r.elapsed)]),
+                    Err(e) => [format!("error: {}", e), String::new()],
+                };
+                row.extend(cells);
+            }
+            row.push(matrix.flags(part, inputs).join(", "));
+            rows.push(row);
+        }
+    }
+    format_table(&headers, &rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A report of day 1 with the given answers, each taking 2ms after a 1ms parse.
+    fn report(answers: &[&str]) -> DayReport {
+        DayReport {
+            day: 1,
+            title: "First",
+            input: "inputs/day01.txt".to_string(),
+            input_hash: Some("ab12".to_string()),
+            parse_time: Some(Duration::from_millis(1)),
+            results: Ok(answers
+                .iter()
+                .zip(Part::ALL)
+                .map(|(answer, part)| PartResult {
+                    part,
+                    answer: answer.to_string(),
+                    elapsed: Duration::from_millis(2),
+                    verdict: None,
+                    failure: None,
+                })
+                .collect()),
+            warnings: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_summary_table() {
+        let reports = vec![
+            report(&["12", "345"]),
+            DayReport {
+                day: 2,
+                title: "Second",
+                input: "inputs/day02.txt".to_string(),
+                input_hash: None,
+                parse_time: None,
+                results: Err("missing".to_string()),
+                warnings: Vec::new(),
+            },
+        ];
+        let table = summary_table(&reports);
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(
+            lines[0],
+            "Day | Title  | Part 1         | Part 2 | Parse  | Time 1 | Time 2"
+        );
+        assert_eq!(
+            lines[1],
+            "----+--------+----------------+--------+--------+--------+-------"
+        );
+        assert_eq!(
+            lines[2],
+            "1   | First  | 12             | 345    | 1.00ms | 2.00ms | 2.00ms"
+        );
+        assert_eq!(lines[3], "2   | Second | error: missing");
+
+        let csv = summary_csv(&reports);
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(
+            lines[0],
+            "day,part,answer,parse_ns,time_ns,verdict,error,input,input_hash"
+        );
+        assert_eq!(lines[1], "1,1,12,1000000,2000000,,,inputs/day01.txt,ab12");
+        assert_eq!(lines[3], "2,,,,,,missing,inputs/day02.txt,");
+
+        let json = summary_json(&reports);
+        let lines: Vec<&str> = json.lines().collect();
+        assert_eq!(lines.len(), 5);
+        assert_eq!(
+            lines[1],
+            r#"  {"day":1,"title":"First","part":1,"answer":"12","parse_ns":1000000,"time_ns":2000000,"verdict":null,"expected":null,"error":null,"input":"inputs/day01.txt","input_hash":"ab12"},"#
+        );
+        assert_eq!(
+            lines[3],
+            r#"  {"day":2,"title":"Second","part":null,"answer":null,"parse_ns":null,"time_ns":null,"verdict":null,"expected":null,"error":"missing","input":"inputs/day02.txt","input_hash":null}"#
+        );
+    }
+
+    #[test]
+    fn test_verify() {
+        let mut answers = Answers::default();
+        answers.insert("inputs", 1, Part::One, "12");
+        answers.insert("inputs", 1, Part::Two, "344");
+        let mut report = report(&["12", "345"]);
+        report.verify(&answers, Some("inputs"));
+        let reports = [report];
+        let table = summary_table(&reports);
+        assert!(table
+            .lines()
+            .nth(2)
+            .unwrap()
+            .starts_with("1   | First | 12 (pass) | 345 (FAIL, expected 344) |"));
+        assert_eq!(
+            verification_summary(&reports),
+            "1 passed, 1 failed, 0 unknown, 0 errors"
+        );
+        let json = summary_json(&reports);
+        assert!(json.contains(
+            r#""answer":"12","parse_ns":1000000,"time_ns":2000000,"verdict":"pass","expected":null"#
+        ));
+        assert!(json.contains(r#""verdict":"fail","expected":"344""#));
+    }
+
+    #[test]
+    fn test_run_day_hashes_input() {
+        let source = InputSource::File(PathBuf::from("examples/day01.txt"));
+        let report = run_day(&crate::day01::Day01, &source, &Part::ALL, None);
+        let input = fs::read_to_string("examples/day01.txt").unwrap();
+        assert_eq!(report.input, "examples/day01.txt");
+        assert_eq!(report.input_hash, Some(sha256_hex(input)));
+
+        let source = InputSource::File(PathBuf::from("examples/missing.txt"));
+        let report = run_day(&crate::day01::Day01, &source, &Part::ALL, None);
+        assert_eq!(report.input_hash, None);
+    }
+
+    #[test]
+    fn test_time_limit() {
+        // The rules form a cycle, so correcting the update never finishes.
+        let input = "1|2\n2|3\n3|1\n\n1,2,3\n";
+        let limit = Some(Duration::from_millis(20));
+        let (_, results) = solve_parts(&crate::day05::Day05, input, &Part::ALL, limit).unwrap();
+        assert_eq!(results[0].failure, None);
+        assert_eq!(results[0].answer, "0");
+        assert_eq!(results[1].failure, Some(Failure::TimedOut));
+        assert!(results[1].elapsed >= Duration::from_millis(20));
+
+        let mut report = report(&["0", ""]);
+        if let Ok(results) = &mut report.results {
+            results[1].failure = Some(Failure::TimedOut);
+        }
+        assert!(report.has_failures());
+        assert_eq!(report.answer(Part::Two), None);
+        let mut answers = Answers::default();
+        answers.insert("inputs", 1, Part::Two, "5");
+        report.verify(&answers, Some("inputs"));
+        assert_eq!(report.verdicts().count(), 1);
+        let reports = [report];
+        assert!(summary_table(&reports)
+            .lines()
+            .nth(2)
+            .unwrap()
+            .starts_with("1   | First | 0 (unknown) | timed out |"));
+        assert!(summary_csv(&reports).contains(",timed out after 2.00ms,"));
+        assert!(summary_json(&reports).contains(r#""answer":null"#));
+        assert_eq!(
+            verification_summary(&reports),
+            "0 passed, 0 failed, 1 unknown, 1 errors"
+        );
+    }
+
+    #[test]
+    fn test_panic_is_caught() {
+        // With the size of the real memory space, the bytes of the example never cut off the exit.
+        let input = fs::read_to_string("examples/day18.txt").unwrap();
+        let day = crate::day18::Day18::default();
+        let (_, results) = solve_parts(&day, &input, &Part::ALL, None).unwrap();
+        assert_eq!(results[0].failure, None);
+        assert!(matches!(results[1].failure, Some(Failure::Panicked(_))));
+        assert!(results[1].error().unwrap().starts_with("panicked: "));
+    }
+
+    #[test]
+    fn test_matrix() {
+        let alice = report(&["12", "345"]);
+        let mut bob = report(&["13", "346"]);
+        let mut carol = report(&["12", ""]);
+        if let Ok(results) = &mut bob.results {
+            results[1].elapsed = Duration::from_millis(50);
+        }
+        if let Ok(results) = &mut carol.results {
+            results[1].failure = Some(Failure::Panicked("oops".to_string()));
+        }
+        let mut matrix = DayMatrix {
+            day: 1,
+            title: "First",
+            parts: Part::ALL.to_vec(),
+            reports: vec![alice, bob, carol],
+        };
+        let inputs = ["alice", "bob", "carol"].map(String::from);
+        assert!(matrix.flags(Part::One, &inputs).is_empty());
+        assert_eq!(
+            matrix.flags(Part::Two, &inputs),
+            ["panicked on carol", "25.0x slower on bob"]
+        );
+        assert!(matrix.has_errors());
+
+        let table = matrix_table(&inputs, std::slice::from_ref(&matrix));
+        let lines: Vec<&str> = table.lines().collect();
+        let cells = |line: &str| -> Vec<String> {
+            line.split(" | ")
+                .map(|cell| cell.trim().to_string())
+                .collect()
+        };
+        assert_eq!(
+            cells(lines[0]),
+            ["Day", "Title", "Part", "alice", "Time", "bob", "Time", "carol", "Time", "Flags"]
+        );
+        assert_eq!(
+            cells(lines[3]),
+            [
+                "1",
+                "First",
+                "2",
+                "345",
+                "2.00ms",
+                "346",
+                "50.00ms",
+                "panicked: oops",
+                "2.00ms",
+                "panicked on carol, 25.0x slower on bob"
+            ]
+        );
+
+        // Slowdowns are only flagged.
+        matrix.reports.pop();
+        assert!(!matrix.has_errors());
+        matrix.reports[1].results = Err("missing".to_string());
+        assert_eq!(matrix.flags(Part::One, &inputs), ["error on bob"]);
+        assert!(matrix.has_errors());
+    }
+
+    #[test]
+    fn test_render_day() {
+        let dir = std::env::temp_dir().join(format!("aoc-render-{}", std::process::id()));
+        let source = InputSource::File(PathBuf::from("examples/day10.txt"));
+        let input = DayInput::load(&crate::day10::Day10, &source);
+        let files = render_day(&crate::day10::Day10, &input, &dir).unwrap();
+        let names: Vec<_> = files
+            .iter()
+            .map(|f| f.file_name().unwrap().to_str().unwrap())
+            .collect();
+        assert_eq!(
+            names,
+            [
+                "day10-trails.ans",
+                "day10-trails.ppm",
+                "day10-trails.pgm",
+                "day10-trails.svg"
+            ]
+        );
+        let ppm = fs::read(&files[1]).unwrap();
+        assert!(ppm.starts_with(b"P6\n32 32\n255\n"));
+        fs::remove_dir_all(&dir).unwrap();
+
+        // The same input can be solved after rendering.
+        let report = solve_day(&crate::day10::Day10, &input, &Part::ALL, None);
+        assert_eq!(report.answer(Part::One), Some("36"));
+
+        let source = InputSource::File(PathBuf::from("examples/day01.txt"));
+        let input = DayInput::load(&crate::day01::Day01, &source);
+        let files = render_day(&crate::day01::Day01, &input, &dir).unwrap();
+        assert!(files.is_empty());
+        assert!(!dir.exists());
+
+        let source = InputSource::File(PathBuf::from("examples/missing.txt"));
+        let input = DayInput::load(&crate::day10::Day10, &source);
+        assert!(render_day(&crate::day10::Day10, &input, &dir).is_err());
+        assert!(input.hash.is_none());
+    }
+
+    #[test]
+    fn test_lint() {
+        let input = "..^.\n.>^.\n...\n";
+        let errors = lint(&crate::day06::Day06, input);
+        let messages: Vec<String> = errors.iter().map(Error::to_string).collect();
+        assert_eq!(
+            messages,
+            [
+                "2:2: Invalid character '>' in grid",
+                "2:3: Another guard '^', the first one is at 1:3",
+                "3:4: Expected 4 columns, got 3"
+            ]
+        );
+
+        let source = InputSource::File(PathBuf::from("examples/day06.txt"));
+        assert!(lint_day(&crate::day06::Day06, &source).unwrap().is_empty());
+        assert!(solve_parts(&crate::day06::Day06, input, &Part::ALL, None).is_err());
+    }
+
+    #[test]
+    fn test_validation_problems_are_warnings() {
+        // With the default 71x71 memory, the bytes of the example never cut off the exit, which
+        // only matters for part 2.
+        let source = InputSource::File(PathBuf::from("examples/day18.txt"));
+        let report = run_day(&crate::day18::Day18::default(), &source, &Part::ALL, None);
+        assert_eq!(
+            report.warnings,
+            ["examples/day18.txt: The bytes never cut off the exit"]
+        );
+        assert_eq!(report.answer(Part::One), Some("146"));
+        let results = report.results.unwrap();
+        assert!(matches!(results[1].failure, Some(Failure::Panicked(_))));
+    }
+
+    #[test]
+    fn test_examples_pass_lint() {
+        // Some examples need smaller parameters than the real puzzles to make sense.
+        let mut solutions = crate::solution::solutions();
+        crate::config::Config::load("examples/aoc.toml")
+            .unwrap()
+            .apply(&mut solutions)
+            .unwrap();
+        for solution in solutions {
+            let source = InputSource::File(
+                Path::new("examples").join(format!("day{:02}.txt", solution.day())),
+            );
+            let errors = lint_day(solution.as_ref(), &source).unwrap();
+            assert!(errors.is_empty(), "{:?}", errors);
+        }
+    }
+}
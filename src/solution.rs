@@ -0,0 +1,341 @@
+use std::{any::Any, fmt::Display, str::FromStr};
+
+use crate::{
+    error::{Error, Result},
+    utils::{record::Recorder, render::Frame},
+};
+
+/// One of the two puzzle parts of a day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("Invalid part: {}", s)),
+        }
+    }
+}
+
+/// Name of the implementation of a part in `part1` and `part2`, the one used to solve the puzzle.
+pub const MAIN_IMPLEMENTATION: &str = "main";
+
+/// Another way to solve a part, kept next to the main one to compare them, see
+/// [`Solution::implementations`].
+pub struct Implementation<S: Solution> {
+    pub name: &'static str,
+    pub part: Part,
+    pub solve: fn(&S, &S::Input) -> String,
+}
+
+/// A puzzle parameter of a day, like the size of the room of day 14, which the config file and
+/// `--param` can change, see [`Solution::params`].
+pub struct Param<S> {
+    pub name: &'static str,
+    pub description: &'static str,
+    /// The current value, formatted the way `set` parses it.
+    pub get: fn(&S) -> String,
+    /// Parses `value` and sets the parameter to it, usually with [`parse_param`].
+    pub set: fn(&mut S, &str) -> Result<()>,
+}
+
+/// Current value of a parameter of a type-erased solution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParamValue {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub value: String,
+}
+
+/// Parses the value of a parameter into its type.
+pub fn parse_param<T: FromStr>(value: &str) -> Result<T> {
+    value.parse().map_err(|_| {
+        Error::parse(format!(
+            "Expected {}, got '{}'",
+            std::any::type_name::<T>(),
+            value
+        ))
+    })
+}
+
+/// Parses the value of a parameter that the solution only supports from `min` up.
+pub fn parse_param_at_least<T: FromStr + PartialOrd + Display>(value: &str, min: T) -> Result<T> {
+    let parsed = parse_param(value)?;
+    if parsed < min {
+        return Err(Error::parse(format!(
+            "Expected at least {}, got '{}'",
+            min, value
+        )));
+    }
+    Ok(parsed)
+}
+
+/// A solution for one day of the puzzle.
+///
+/// The input is parsed once and shared by both parts.
+pub trait Solution {
+    type Input;
+
+    const DAY: u8;
+    const TITLE: &'static str;
+
+    fn parse(&self, input: &str) -> Result<Self::Input>;
+
+    fn part1(&self, input: &Self::Input) -> impl Display;
+
+    fn part2(&self, input: &Self::Input) -> impl Display;
+
+    /// Every violation of the assumptions the solver makes about the structure of `input`, each
+    /// with its position, so that malformed inputs are reported instead of panicking deep in a
+    /// part.
+    ///
+    /// Parsing already rejects most malformed inputs, so days only check what `parse` doesn't, or
+    /// what it stops at after the first problem.
+    fn validate(&self, _input: &str) -> Vec<Error> {
+        Vec::new()
+    }
+
+    /// Pictures of interesting states of the puzzle, each with a name for its file.
+    ///
+    /// Most days have nothing to show and keep the default.
+    fn render(&self, _input: &Self::Input) -> Vec<(String, Frame)> {
+        Vec::new()
+    }
+
+    /// Runs the simulation behind `part` again, passing the state after each step to `recorder`.
+    ///
+    /// Only days that simulate step by step record anything.
+    fn record(&self, _input: &Self::Input, _part: Part, _recorder: &mut Recorder) {}
+
+    /// Alternative implementations of the parts, each with a name, which `aoc compare` runs
+    /// against `part1` and `part2` to check that they agree and to time them.
+    ///
+    /// This is where an algorithm goes when a faster one replaces it, instead of being commented
+    /// out.
+    fn implementations(&self) -> Vec<Implementation<Self>>
+    where
+        Self: Sized,
+    {
+        Vec::new()
+    }
+
+    /// The puzzle parameters of the day, with the values of the real puzzle as defaults.
+    ///
+    /// Examples often use smaller values, and setting them here means running an example needs no
+    /// code changes.
+    fn params(&self) -> Vec<Param<Self>>
+    where
+        Self: Sized,
+    {
+        Vec::new()
+    }
+}
+
+/// Type-erased view of a [`Solution`], so that all days can be kept in one list.
+pub trait Runnable {
+    fn day(&self) -> u8;
+
+    fn title(&self) -> &'static str;
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>>;
+
+    fn solve(&self, input: &dyn Any, part: Part) -> String;
+
+    fn validate(&self, input: &str) -> Vec<Error>;
+
+    fn render(&self, input: &dyn Any) -> Vec<(String, Frame)>;
+
+    fn record(&self, input: &dyn Any, part: Part, recorder: &mut Recorder);
+
+    /// Names of the implementations of `part`, starting with [`MAIN_IMPLEMENTATION`].
+    fn implementations(&self, part: Part) -> Vec<&'static str>;
+
+    /// Solves `part` with the implementation called `name`, if there is one.
+    fn solve_with(&self, input: &dyn Any, part: Part, name: &str) -> Option<String>;
+
+    fn params(&self) -> Vec<ParamValue>;
+
+    /// Sets the parameter called `name`, failing if the day has no such parameter or if `value`
+    /// isn't valid for it.
+    fn set_param(&mut self, name: &str, value: &str) -> Result<()>;
+}
+
+impl<S> Runnable for S
+where
+    S: Solution,
+    S::Input: 'static,
+{
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn title(&self) -> &'static str {
+        S::TITLE
+    }
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>> {
+        let input = Solution::parse(self, input)?;
+        Ok(Box::new(input))
+    }
+
+    fn solve(&self, input: &dyn Any, part: Part) -> String {
+        let input = input
+            .downcast_ref::<S::Input>()
+            .expect("Input was parsed by a different solution");
+        match part {
+            Part::One => self.part1(input).to_string(),
+            Part::Two => self.part2(input).to_string(),
+        }
+    }
+
+    fn validate(&self, input: &str) -> Vec<Error> {
+        Solution::validate(self, input)
+    }
+
+    fn render(&self, input: &dyn Any) -> Vec<(String, Frame)> {
+        let input = input
+            .downcast_ref::<S::Input>()
+            .expect("Input was parsed by a different solution");
+        Solution::render(self, input)
+    }
+
+    fn record(&self, input: &dyn Any, part: Part, recorder: &mut Recorder) {
+        let input = input
+            .downcast_ref::<S::Input>()
+            .expect("Input was parsed by a different solution");
+        Solution::record(self, input, part, recorder);
+    }
+
+    fn implementations(&self, part: Part) -> Vec<&'static str> {
+        let others = Solution::implementations(self)
+            .into_iter()
+            .filter(|implementation| implementation.part == part)
+            .map(|implementation| implementation.name);
+        std::iter::once(MAIN_IMPLEMENTATION).chain(others).collect()
+    }
+
+    fn solve_with(&self, input: &dyn Any, part: Part, name: &str) -> Option<String> {
+        if name == MAIN_IMPLEMENTATION {
+            return Some(Runnable::solve(self, input, part));
+        }
+        let input = input
+            .downcast_ref::<S::Input>()
+            .expect("Input was parsed by a different solution");
+        Solution::implementations(self)
+            .into_iter()
+            .find(|implementation| implementation.part == part && implementation.name == name)
+            .map(|implementation| (implementation.solve)(self, input))
+    }
+
+    fn params(&self) -> Vec<ParamValue> {
+        Solution::params(self)
+            .into_iter()
+            .map(|param| ParamValue {
+                name: param.name,
+                description: param.description,
+                value: (param.get)(self),
+            })
+            .collect()
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<()> {
+        let params = Solution::params(self);
+        let Some(param) = params.iter().find(|param| param.name == name) else {
+            let names: Vec<&str> = params.iter().map(|param| param.name).collect();
+            let known = match names.as_slice() {
+                [] => "it has none".to_string(),
+                _ => format!("it has {}", names.join(", ")),
+            };
+            return Err(Error::parse(format!(
+                "Day {} has no parameter '{}', {}",
+                S::DAY,
+                name,
+                known
+            )));
+        };
+        (param.set)(self, value).map_err(|e| match e {
+            Error::Parse { reason, .. } => Error::parse(format!(
+                "Invalid value for parameter '{}' of day {}: {}",
+                name,
+                S::DAY,
+                reason
+            )),
+            e => e,
+        })
+    }
+}
+
+/// All days, in order.
+pub fn solutions() -> Vec<Box<dyn Runnable>> {
+    use crate::*;
+    vec![
+        Box::new(day01::Day01),
+        Box::new(day02::Day02),
+        Box::new(day03::Day03),
+        Box::new(day04::Day04),
+        Box::new(day05::Day05),
+        Box::new(day06::Day06),
+        Box::new(day07::Day07),
+        Box::new(day08::Day08),
+        Box::new(day09::Day09),
+        Box::new(day10::Day10),
+        Box::new(day11::Day11::default()),
+        Box::new(day12::Day12),
+        Box::new(day13::Day13::default()),
+        Box::new(day14::Day14::default()),
+        Box::new(day15::Day15),
+        Box::new(day16::Day16),
+        Box::new(day17::Day17),
+        Box::new(day18::Day18::default()),
+        Box::new(day19::Day19),
+        Box::new(day20::Day20::default()),
+        Box::new(day21::Day21::default()),
+        Box::new(day22::Day22::default()),
+        Box::new(day23::Day23),
+        Box::new(day24::Day24),
+        Box::new(day25::Day25),
+    ]
+}
+
+/// Looks up the solution of a single day.
+pub fn solution(day: u8) -> Option<Box<dyn Runnable>> {
+    solutions().into_iter().find(|s| s.day() == day)
+}
+
+/// Solves one part of the example stored in `examples/<file>`, for the example tests of each day.
+#[cfg(test)]
+pub(crate) fn example_answer<S: Solution>(solution: &S, file: &str, part: Part) -> String {
+    let path = std::path::Path::new("examples").join(file);
+    let input = crate::utils::file::read_lines(&path).unwrap();
+    let input = solution
+        .parse(&input)
+        .unwrap_or_else(|e| panic!("{}", e.in_file(path.display())));
+    match part {
+        Part::One => solution.part1(&input).to_string(),
+        Part::Two => solution.part2(&input).to_string(),
+    }
+}
@@ -0,0 +1,416 @@
+use std::{
+    fmt::Display,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::{answers::Answers, solution::Part};
+
+/// How long the website makes us wait after a wrong answer when the response doesn't say.
+pub const DEFAULT_WRONG_WAIT: Duration = Duration::from_secs(60);
+
+/// Whether a wrong answer was too high or too low, when the website tells.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+/// The website's response to a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    Wrong {
+        hint: Option<Hint>,
+        wait: Option<Duration>,
+    },
+    /// The answer wasn't checked because the previous one was submitted too recently.
+    TooSoon {
+        wait: Duration,
+    },
+    /// The part was already solved, or part 1 has to be solved first.
+    WrongLevel,
+}
+
+impl Outcome {
+    /// Recognizes the outcome from the HTML page returned by the website.
+    pub fn parse(html: &str) -> Option<Self> {
+        let text = article_text(html).to_lowercase();
+        if text.contains("that's the right answer") {
+            Some(Outcome::Correct)
+        } else if text.contains("that's not the right answer") {
+            let hint = if text.contains("your answer is too high") {
+                Some(Hint::TooHigh)
+            } else if text.contains("your answer is too low") {
+                Some(Hint::TooLow)
+            } else {
+                None
+            };
+            let wait = between(&text, "please wait ", " before trying again").and_then(parse_wait);
+            Some(Outcome::Wrong { hint, wait })
+        } else if text.contains("you gave an answer too recently") {
+            let wait = between(&text, "you have ", " left to wait").and_then(parse_wait)?;
+            Some(Outcome::TooSoon { wait })
+        } else if text.contains("you don't seem to be solving the right level") {
+            Some(Outcome::WrongLevel)
+        } else {
+            None
+        }
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::Wrong { hint, wait } => {
+                write!(f, "wrong")?;
+                match hint {
+                    Some(Hint::TooHigh) => write!(f, ", too high")?,
+                    Some(Hint::TooLow) => write!(f, ", too low")?,
+                    None => {}
+                }
+                let wait = wait.unwrap_or(DEFAULT_WRONG_WAIT);
+                write!(f, " (wait {} before trying again)", format_wait(wait))
+            }
+            Outcome::TooSoon { wait } => write!(
+                f,
+                "not checked, answered too recently (wait {})",
+                format_wait(*wait)
+            ),
+            Outcome::WrongLevel => write!(f, "not checked, the part is solved or not unlocked"),
+        }
+    }
+}
+
+/// Why an answer isn't worth submitting.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refusal {
+    Solved {
+        answer: String,
+    },
+    KnownWrong,
+    TooHigh {
+        bound: i64,
+    },
+    TooLow {
+        bound: i64,
+    },
+    Cooldown {
+        wait: Duration,
+    },
+    /// The solver said it found no answer, like day 17's `no answer`.
+    NoAnswer,
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Refusal::Solved { answer } => write!(f, "the part is already solved with {}", answer),
+            Refusal::KnownWrong => write!(f, "it was already submitted and is wrong"),
+            Refusal::TooHigh { bound } => write!(f, "{} is already too high", bound),
+            Refusal::TooLow { bound } => write!(f, "{} is already too low", bound),
+            Refusal::Cooldown { wait } => {
+                write!(
+                    f,
+                    "the website accepts answers again in {}",
+                    format_wait(*wait)
+                )
+            }
+            Refusal::NoAnswer => write!(f, "the solver found no answer"),
+        }
+    }
+}
+
+/// Seconds since the Unix epoch.
+fn unix_time(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs())
+}
+
+/// Checks what the registry knows before `answer` is submitted for the part at time `now`.
+pub fn check(
+    answers: &Answers,
+    input: &str,
+    day: u8,
+    part: Part,
+    answer: &str,
+    now: SystemTime,
+) -> Result<(), Refusal> {
+    // Answers are numbers or comma separated lists, so spaces only come from messages like the
+    // `no answer` of day 17.
+    if answer.contains(char::is_whitespace) {
+        return Err(Refusal::NoAnswer);
+    }
+    if let Some(correct) = answers.get(input, day, part) {
+        return Err(Refusal::Solved {
+            answer: correct.to_string(),
+        });
+    }
+    let Some(attempts) = answers.attempts(input, day, part) else {
+        return Ok(());
+    };
+    if attempts.wrong.iter().any(|wrong| wrong == answer) {
+        return Err(Refusal::KnownWrong);
+    }
+    if let Ok(value) = answer.parse::<i64>() {
+        if let Some(bound) = attempts.too_high.filter(|&bound| value >= bound) {
+            return Err(Refusal::TooHigh { bound });
+        }
+        if let Some(bound) = attempts.too_low.filter(|&bound| value <= bound) {
+            return Err(Refusal::TooLow { bound });
+        }
+    }
+    match attempts.retry_at {
+        Some(retry_at) if retry_at > unix_time(now) => Err(Refusal::Cooldown {
+            wait: Duration::from_secs(retry_at - unix_time(now)),
+        }),
+        _ => Ok(()),
+    }
+}
+
+/// Records what the website said about `answer` in the registry.
+pub fn record(
+    answers: &mut Answers,
+    input: &str,
+    day: u8,
+    part: Part,
+    answer: &str,
+    outcome: &Outcome,
+    now: SystemTime,
+) {
+    match outcome {
+        Outcome::Correct => answers.insert(input, day, part, answer),
+        Outcome::Wrong { hint, wait } => {
+            let attempts = answers.attempts_mut(input, day, part);
+            if !attempts.wrong.iter().any(|wrong| wrong == answer) {
+                attempts.wrong.push(answer.to_string());
+            }
+            if let Ok(value) = answer.parse::<i64>() {
+                match hint {
+                    Some(Hint::TooHigh) => {
+                        attempts.too_high = Some(attempts.too_high.map_or(value, |b| b.min(value)))
+                    }
+                    Some(Hint::TooLow) => {
+                        attempts.too_low = Some(attempts.too_low.map_or(value, |b| b.max(value)))
+                    }
+                    None => {}
+                }
+            }
+            let wait = wait.unwrap_or(DEFAULT_WRONG_WAIT);
+            attempts.retry_at = Some(unix_time(now + wait));
+        }
+        Outcome::TooSoon { wait } => {
+            answers.attempts_mut(input, day, part).retry_at = Some(unix_time(now + *wait));
+        }
+        Outcome::WrongLevel => {}
+    }
+}
+
+/// The text of the `<article>` of the page, or of the whole page if it has none, without tags.
+fn article_text(html: &str) -> String {
+    let article = html
+        .find("<article")
+        .map(|start| &html[start..])
+        .map(|article| &article[..article.find("</article>").unwrap_or(article.len())])
+        .unwrap_or(html);
+    let mut text = String::new();
+    let mut in_tag = false;
+    for ch in article.chars() {
+        match ch {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            ch if !in_tag => text.push(ch),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// The part of `text` before the first `end` and after the last `start` preceding it.
+fn between<'a>(text: &'a str, start: &str, end: &str) -> Option<&'a str> {
+    let head = &text[..text.find(end)?];
+    Some(&head[head.rfind(start)? + start.len()..])
+}
+
+/// Parses waits like `4m 32s`, `45s`, `one minute` or `5 minutes`.
+fn parse_wait(text: &str) -> Option<Duration> {
+    let mut seconds = 0;
+    let mut words = text.split_whitespace().peekable();
+    while let Some(word) = words.next() {
+        let split = word
+            .find(|ch: char| !ch.is_ascii_digit())
+            .unwrap_or(word.len());
+        let (number, unit) = match (&word[..split], &word[split..]) {
+            ("", _) if word == "one" || word == "a" => (1, words.next()?),
+            ("", _) => return None,
+            (number, "") => (number.parse().ok()?, words.next()?),
+            (number, unit) => (number.parse().ok()?, unit),
+        };
+        let unit_seconds = match unit.trim_end_matches(['s', ',', '.']) {
+            "h" | "hour" => 3600,
+            "m" | "minute" => 60,
+            "" | "second" => 1,
+            _ => return None,
+        };
+        seconds += number * unit_seconds;
+    }
+    (seconds > 0).then(|| Duration::from_secs(seconds))
+}
+
+/// Short form of a wait, like `4m 32s`.
+pub fn format_wait(wait: Duration) -> String {
+    let seconds = wait.as_secs();
+    match (seconds / 3600, seconds / 60 % 60, seconds % 60) {
+        (0, 0, s) => format!("{}s", s),
+        (0, m, s) => format!("{}m {}s", m, s),
+        (h, m, s) => format!("{}h {}m {}s", h, m, s),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page(message: &str) -> String {
+        format!(
+            "<!DOCTYPE html>\n<html><body><main>\n<article><p>{}</p></article>\n</main></body></html>",
+            message
+        )
+    }
+
+    #[test]
+    fn test_parse_outcome() {
+        let parse = |message: &str| Outcome::parse(&page(message));
+        assert_eq!(
+            parse("That's the right answer!  You are <em>one gold star</em> closer."),
+            Some(Outcome::Correct)
+        );
+        assert_eq!(
+            parse(
+                "That's not the right answer; your answer is too high.  If you're stuck, \
+                 make sure you're using the full input data.  Please wait one minute before \
+                 trying again. <a href=\"/2024/day/5\">[Return to Day 5]</a>"
+            ),
+            Some(Outcome::Wrong {
+                hint: Some(Hint::TooHigh),
+                wait: Some(Duration::from_secs(60))
+            })
+        );
+        assert_eq!(
+            parse(
+                "That's not the right answer.  Because you have guessed incorrectly 4 times \
+                 on this puzzle, please wait 5 minutes before trying again."
+            ),
+            Some(Outcome::Wrong {
+                hint: None,
+                wait: Some(Duration::from_secs(300))
+            })
+        );
+        assert_eq!(
+            parse("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 32s left to wait."),
+            Some(Outcome::TooSoon {
+                wait: Duration::from_secs(272)
+            })
+        );
+        assert_eq!(
+            parse("You don't seem to be solving the right level.  Did you already complete it?"),
+            Some(Outcome::WrongLevel)
+        );
+        assert_eq!(parse("Puzzle inputs differ by user."), None);
+    }
+
+    #[test]
+    fn test_parse_wait() {
+        assert_eq!(parse_wait("45s"), Some(Duration::from_secs(45)));
+        assert_eq!(parse_wait("1h 2m 3s"), Some(Duration::from_secs(3723)));
+        assert_eq!(parse_wait("10 minutes"), Some(Duration::from_secs(600)));
+        assert_eq!(parse_wait("a while"), None);
+        assert_eq!(format_wait(Duration::from_secs(3723)), "1h 2m 3s");
+        assert_eq!(format_wait(Duration::from_secs(272)), "4m 32s");
+    }
+
+    #[test]
+    fn test_guard_rails() {
+        let now = UNIX_EPOCH + Duration::from_secs(1_000_000);
+        let mut answers = Answers::default();
+        let check_part1 = |answers: &Answers, answer: &str, now| {
+            check(answers, "inputs", 5, Part::One, answer, now)
+        };
+        assert_eq!(check_part1(&answers, "100", now), Ok(()));
+        assert_eq!(
+            check_part1(&answers, "no answer", now),
+            Err(Refusal::NoAnswer)
+        );
+
+        let too_high = Outcome::Wrong {
+            hint: Some(Hint::TooHigh),
+            wait: None,
+        };
+        record(&mut answers, "inputs", 5, Part::One, "100", &too_high, now);
+        assert_eq!(check_part1(&answers, "100", now), Err(Refusal::KnownWrong));
+        assert_eq!(
+            check_part1(&answers, "120", now),
+            Err(Refusal::TooHigh { bound: 100 })
+        );
+        assert_eq!(
+            check_part1(&answers, "50", now),
+            Err(Refusal::Cooldown {
+                wait: DEFAULT_WRONG_WAIT
+            })
+        );
+        let later = now + DEFAULT_WRONG_WAIT;
+        assert_eq!(check_part1(&answers, "50", later), Ok(()));
+
+        let too_low = Outcome::Wrong {
+            hint: Some(Hint::TooLow),
+            wait: Some(Duration::from_secs(300)),
+        };
+        record(&mut answers, "inputs", 5, Part::One, "50", &too_low, later);
+        record(&mut answers, "inputs", 5, Part::One, "40", &too_low, later);
+        let attempts = answers.attempts("inputs", 5, Part::One).unwrap();
+        assert_eq!(attempts.wrong, ["100", "50", "40"]);
+        assert_eq!((attempts.too_low, attempts.too_high), (Some(50), Some(100)));
+        let much_later = later + Duration::from_secs(300);
+        assert_eq!(
+            check_part1(&answers, "45", much_later),
+            Err(Refusal::TooLow { bound: 50 })
+        );
+        assert_eq!(check_part1(&answers, "75", much_later), Ok(()));
+        // Bounds don't apply to answers that aren't numbers.
+        assert_eq!(check_part1(&answers, "abc", much_later), Ok(()));
+
+        record(
+            &mut answers,
+            "inputs",
+            5,
+            Part::One,
+            "75",
+            &Outcome::Correct,
+            much_later,
+        );
+        assert_eq!(
+            check_part1(&answers, "76", much_later),
+            Err(Refusal::Solved {
+                answer: "75".to_string()
+            })
+        );
+
+        let too_soon = Outcome::TooSoon {
+            wait: Duration::from_secs(30),
+        };
+        record(&mut answers, "inputs", 5, Part::Two, "1", &too_soon, now);
+        assert_eq!(
+            check(
+                &answers,
+                "inputs",
+                5,
+                Part::Two,
+                "1",
+                now + Duration::from_secs(10)
+            ),
+            Err(Refusal::Cooldown {
+                wait: Duration::from_secs(20)
+            })
+        );
+    }
+}
@@ -0,0 +1,77 @@
+use std::{
+    cell::Cell,
+    panic::{self, AssertUnwindSafe},
+    time::{Duration, Instant},
+};
+
+/// The solver was stopped because its deadline passed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cancelled;
+
+/// Number of calls to [`check`] between two looks at the clock, which costs more than most loop
+/// iterations that check.
+const CHECK_INTERVAL: u32 = 1024;
+
+thread_local! {
+    static DEADLINE: Cell<Option<Instant>> = const { Cell::new(None) };
+    static CHECKS: Cell<u32> = const { Cell::new(0) };
+}
+
+/// Runs `f` on the current thread, cancelling it at the next [`check`] after `limit` has passed.
+///
+/// This is how the runner stops solvers that may run forever on unexpected input: loops that
+/// don't necessarily terminate call `check` on every iteration, which unwinds back here once the
+/// deadline has passed. Without a limit, `f` runs to completion. Panics other than the
+/// cancellation are passed on.
+pub fn with_deadline<T>(limit: Option<Duration>, f: impl FnOnce() -> T) -> Result<T, Cancelled> {
+    let deadline = limit.map(|limit| Instant::now() + limit);
+    let outer = DEADLINE.replace(deadline);
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    DEADLINE.set(outer);
+    match result {
+        Ok(value) => Ok(value),
+        Err(payload) if payload.is::<Cancelled>() => Err(Cancelled),
+        Err(payload) => panic::resume_unwind(payload),
+    }
+}
+
+/// Stops the solver if the deadline of the current [`with_deadline`] has passed.
+pub fn check() {
+    let Some(deadline) = DEADLINE.get() else {
+        return;
+    };
+    let checks = CHECKS.get().wrapping_add(1);
+    CHECKS.set(checks);
+    if checks.is_multiple_of(CHECK_INTERVAL) && Instant::now() >= deadline {
+        // Unlike `panic!`, this doesn't print a message through the panic hook.
+        panic::resume_unwind(Box::new(Cancelled));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_with_deadline() {
+        let limit = Some(Duration::from_millis(20));
+        assert_eq!(with_deadline(limit, || 42), Ok(42));
+        assert_eq!(
+            with_deadline(limit, || loop {
+                check();
+            }),
+            Err::<(), _>(Cancelled)
+        );
+        // Checking outside of a deadline, or after it was lifted, never stops.
+        for _ in 0..10 * CHECK_INTERVAL {
+            check();
+        }
+        assert_eq!(
+            with_deadline(None, || (0..5000).inspect(|_| check()).sum()),
+            Ok(12497500)
+        );
+
+        let panicked = panic::catch_unwind(|| with_deadline(limit, || panic!("Not a timeout")));
+        assert!(panicked.is_err());
+    }
+}
@@ -2,22 +2,32 @@ use std::fs::File;
 use std::io::{self, BufRead};
 use std::path::Path;
 
-pub fn load_file_lines(filename: &str) -> io::Result<Vec<String>> {
+use super::grid::Grid;
+use crate::error::{Error, Result};
+
+pub fn load_file_lines(filename: &str) -> Result<Vec<String>> {
     let path = Path::new(filename);
-    let file = File::open(&path)?;
+    let file = File::open(path).map_err(|e| Error::io(filename, e))?;
     let reader = io::BufReader::new(file);
-    reader.lines().collect()
+    reader
+        .lines()
+        .collect::<io::Result<_>>()
+        .map_err(|e| Error::io(filename, e))
 }
 
-pub fn read_lines<P>(filename: P) -> io::Result<String>
+pub fn read_lines<P>(filename: P) -> Result<String>
 where
     P: AsRef<Path>,
 {
-    let file = File::open(filename)?;
-    let buf_reader = io::BufReader::new(file);
+    let path = filename.as_ref();
+    let file = File::open(path).map_err(|e| Error::io(path.display(), e))?;
+    read_lines_from(io::BufReader::new(file)).map_err(|e| Error::io(path.display(), e))
+}
+
+pub fn read_lines_from<R: BufRead>(reader: R) -> io::Result<String> {
     let mut contents = String::new();
 
-    for line in buf_reader.lines() {
+    for line in reader.lines() {
         contents.push_str(&line?);
         contents.push('\n');
     }
@@ -25,21 +35,11 @@ where
     Ok(contents)
 }
 
-pub fn read_file_to_grid<P>(filename: P) -> io::Result<Vec<Vec<char>>>
+pub fn read_file_to_grid<P>(filename: P) -> Result<Grid<char>>
 where
     P: AsRef<Path>,
 {
-    let file = File::open(filename)?;
-    let buf_reader = io::BufReader::new(file);
-    let mut grid = Vec::new();
-
-    for line in buf_reader.lines() {
-        let mut row = Vec::new();
-        for ch in line?.chars() {
-            row.push(ch);
-        }
-        grid.push(row);
-    }
-
-    Ok(grid)
+    let path = filename.as_ref();
+    let contents = read_lines(path)?;
+    Grid::parse(&contents).map_err(|e| e.in_file(path.display()))
 }
@@ -0,0 +1,302 @@
+use std::{
+    fmt::Display,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+use super::grid::Pos;
+
+/// A point or offset on the integer plane, with `y` growing downwards like in the grids.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Vec2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Vec2 {
+    pub const ZERO: Vec2 = Vec2::new(0, 0);
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    /// Sum of the absolute coordinates, i.e. the Manhattan distance to the origin.
+    pub fn manhattan(self) -> i64 {
+        self.x.abs() + self.y.abs()
+    }
+
+    pub fn manhattan_distance(self, other: Vec2) -> i64 {
+        (self - other).manhattan()
+    }
+
+    /// Wraps the point into the rectangle from the origin to `size`, exclusive.
+    pub fn rem_euclid(self, size: Vec2) -> Vec2 {
+        Vec2::new(self.x.rem_euclid(size.x), self.y.rem_euclid(size.y))
+    }
+
+    /// The point one step away in direction `dir`.
+    pub fn step(self, dir: Dir4) -> Vec2 {
+        self + dir.delta()
+    }
+}
+
+impl From<Pos> for Vec2 {
+    fn from((x, y): Pos) -> Self {
+        Vec2::new(x, y)
+    }
+}
+
+impl From<Vec2> for Pos {
+    fn from(v: Vec2) -> Self {
+        (v.x, v.y)
+    }
+}
+
+impl Display for Vec2 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+impl Add for Vec2 {
+    type Output = Vec2;
+
+    fn add(self, other: Vec2) -> Vec2 {
+        Vec2::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for Vec2 {
+    fn add_assign(&mut self, other: Vec2) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Vec2 {
+    type Output = Vec2;
+
+    fn sub(self, other: Vec2) -> Vec2 {
+        Vec2::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl SubAssign for Vec2 {
+    fn sub_assign(&mut self, other: Vec2) {
+        *self = *self - other;
+    }
+}
+
+impl Neg for Vec2 {
+    type Output = Vec2;
+
+    fn neg(self) -> Vec2 {
+        Vec2::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<i64> for Vec2 {
+    type Output = Vec2;
+
+    fn mul(self, factor: i64) -> Vec2 {
+        Vec2::new(self.x * factor, self.y * factor)
+    }
+}
+
+/// One of the 4 orthogonal directions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Dir4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Dir4 {
+    /// All directions, clockwise starting at up like [`DIRS4`](super::grid::DIRS4).
+    pub const ALL: [Dir4; 4] = [Dir4::Up, Dir4::Right, Dir4::Down, Dir4::Left];
+
+    /// Parses one of `^>v<`.
+    pub fn from_char(ch: char) -> Option<Self> {
+        match ch {
+            '^' => Some(Dir4::Up),
+            '>' => Some(Dir4::Right),
+            'v' => Some(Dir4::Down),
+            '<' => Some(Dir4::Left),
+            _ => None,
+        }
+    }
+
+    pub fn to_char(self) -> char {
+        match self {
+            Dir4::Up => '^',
+            Dir4::Right => '>',
+            Dir4::Down => 'v',
+            Dir4::Left => '<',
+        }
+    }
+
+    fn from_index(index: usize) -> Self {
+        Self::ALL[index % 4]
+    }
+
+    /// Rotates clockwise by 90 degrees.
+    pub fn turn_right(self) -> Self {
+        Self::from_index(self as usize + 1)
+    }
+
+    /// Rotates counter-clockwise by 90 degrees.
+    pub fn turn_left(self) -> Self {
+        Self::from_index(self as usize + 3)
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::from_index(self as usize + 2)
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Dir4::Left | Dir4::Right)
+    }
+
+    /// The offset of one step in this direction.
+    pub fn delta(self) -> Vec2 {
+        match self {
+            Dir4::Up => Vec2::new(0, -1),
+            Dir4::Right => Vec2::new(1, 0),
+            Dir4::Down => Vec2::new(0, 1),
+            Dir4::Left => Vec2::new(-1, 0),
+        }
+    }
+
+    /// The grid position one step away from `pos` in this direction.
+    pub fn step(self, (x, y): Pos) -> Pos {
+        let delta = self.delta();
+        (x + delta.x, y + delta.y)
+    }
+}
+
+/// One of the 8 directions to the surrounding cells.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Dir8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Dir8 {
+    /// All directions, clockwise starting at up like [`DIRS8`](super::grid::DIRS8).
+    pub const ALL: [Dir8; 8] = [
+        Dir8::Up,
+        Dir8::UpRight,
+        Dir8::Right,
+        Dir8::DownRight,
+        Dir8::Down,
+        Dir8::DownLeft,
+        Dir8::Left,
+        Dir8::UpLeft,
+    ];
+
+    fn from_index(index: usize) -> Self {
+        Self::ALL[index % 8]
+    }
+
+    /// Rotates clockwise by 45 degrees.
+    pub fn turn_right(self) -> Self {
+        Self::from_index(self as usize + 1)
+    }
+
+    /// Rotates counter-clockwise by 45 degrees.
+    pub fn turn_left(self) -> Self {
+        Self::from_index(self as usize + 7)
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::from_index(self as usize + 4)
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self as usize % 2 == 1
+    }
+
+    /// The offset of one step in this direction.
+    pub fn delta(self) -> Vec2 {
+        let (x, y) = match self {
+            Dir8::Up => (0, -1),
+            Dir8::UpRight => (1, -1),
+            Dir8::Right => (1, 0),
+            Dir8::DownRight => (1, 1),
+            Dir8::Down => (0, 1),
+            Dir8::DownLeft => (-1, 1),
+            Dir8::Left => (-1, 0),
+            Dir8::UpLeft => (-1, -1),
+        };
+        Vec2::new(x, y)
+    }
+
+    /// The grid position one step away from `pos` in this direction.
+    pub fn step(self, (x, y): Pos) -> Pos {
+        let delta = self.delta();
+        (x + delta.x, y + delta.y)
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(dir: Dir4) -> Self {
+        match dir {
+            Dir4::Up => Dir8::Up,
+            Dir4::Right => Dir8::Right,
+            Dir4::Down => Dir8::Down,
+            Dir4::Left => Dir8::Left,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::grid::{DIRS4, DIRS8};
+
+    #[test]
+    fn test_vec2() {
+        let a = Vec2::new(3, -4);
+        let b = Vec2::new(1, 2);
+        assert_eq!(a + b, Vec2::new(4, -2));
+        assert_eq!(a - b, Vec2::new(2, -6));
+        assert_eq!(-a * 2, Vec2::new(-6, 8));
+        assert_eq!(a.manhattan(), 7);
+        assert_eq!(a.manhattan_distance(b), 8);
+        assert_eq!(
+            Vec2::new(-1, 13).rem_euclid(Vec2::new(11, 7)),
+            Vec2::new(10, 6)
+        );
+        assert_eq!(Vec2::from((5, 6)).step(Dir4::Left), Vec2::new(4, 6));
+        assert_eq!(a.to_string(), "3,-4");
+    }
+
+    #[test]
+    fn test_dir4() {
+        assert_eq!(Dir4::Up.turn_right(), Dir4::Right);
+        assert_eq!(Dir4::Up.turn_left(), Dir4::Left);
+        assert_eq!(Dir4::Left.opposite(), Dir4::Right);
+        for dir in Dir4::ALL {
+            assert_eq!(dir.turn_right().turn_left(), dir);
+            assert_eq!(dir.opposite().delta(), -dir.delta());
+            assert_eq!(Dir4::from_char(dir.to_char()), Some(dir));
+        }
+        assert_eq!(Dir4::from_char('x'), None);
+        assert_eq!(Dir4::ALL.map(|dir| dir.step((0, 0))), DIRS4);
+    }
+
+    #[test]
+    fn test_dir8() {
+        assert_eq!(Dir8::Up.turn_right(), Dir8::UpRight);
+        assert_eq!(Dir8::Up.turn_left(), Dir8::UpLeft);
+        assert_eq!(Dir8::DownLeft.opposite(), Dir8::UpRight);
+        assert!(Dir8::DownLeft.is_diagonal());
+        assert_eq!(Dir8::from(Dir4::Down), Dir8::Down);
+        assert_eq!(Dir8::ALL.map(|dir| dir.step((0, 0))), DIRS8);
+    }
+}
@@ -0,0 +1,242 @@
+use std::{
+    collections::{BTreeSet, HashMap},
+    hash::Hash,
+    ops::Range,
+};
+
+/// Dense index of a vertex in a [`Graph`].
+pub type VertexId = usize;
+
+/// An undirected graph stored as adjacency lists.
+///
+/// Vertices can be any hashable value, like a name or a grid position. They are interned on
+/// insertion and referred to by their [`VertexId`] afterwards, so the algorithms work on plain
+/// indices.
+#[derive(Debug, Clone)]
+pub struct Graph<V> {
+    ids: HashMap<V, VertexId>,
+    vertices: Vec<V>,
+    adjacency: Vec<BTreeSet<VertexId>>,
+}
+
+impl<V> Default for Graph<V> {
+    fn default() -> Self {
+        Self {
+            ids: HashMap::new(),
+            vertices: Vec::new(),
+            adjacency: Vec::new(),
+        }
+    }
+}
+
+impl<V: Clone + Eq + Hash> Graph<V> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the vertex if it isn't in the graph yet and returns its id.
+    pub fn add_vertex(&mut self, vertex: V) -> VertexId {
+        if let Some(&id) = self.ids.get(&vertex) {
+            return id;
+        }
+        let id = self.vertices.len();
+        self.ids.insert(vertex.clone(), id);
+        self.vertices.push(vertex);
+        self.adjacency.push(BTreeSet::new());
+        id
+    }
+
+    /// Connects the two vertices, adding them first if needed.
+    pub fn add_edge(&mut self, a: V, b: V) -> (VertexId, VertexId) {
+        let (a, b) = (self.add_vertex(a), self.add_vertex(b));
+        self.adjacency[a].insert(b);
+        self.adjacency[b].insert(a);
+        (a, b)
+    }
+
+    pub fn id(&self, vertex: &V) -> Option<VertexId> {
+        self.ids.get(vertex).copied()
+    }
+}
+
+impl<V> Graph<V> {
+    pub fn vertex(&self, id: VertexId) -> &V {
+        &self.vertices[id]
+    }
+
+    pub fn len(&self) -> usize {
+        self.vertices.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.vertices.is_empty()
+    }
+
+    /// All vertex ids, in insertion order.
+    pub fn ids(&self) -> Range<VertexId> {
+        0..self.vertices.len()
+    }
+
+    /// All vertices, in insertion order.
+    pub fn vertices(&self) -> impl Iterator<Item = &V> {
+        self.vertices.iter()
+    }
+
+    /// The ids of the vertices connected to `id`, in increasing order.
+    pub fn neighbors(&self, id: VertexId) -> &BTreeSet<VertexId> {
+        &self.adjacency[id]
+    }
+
+    pub fn has_edge(&self, a: VertexId, b: VertexId) -> bool {
+        self.adjacency[a].contains(&b)
+    }
+
+    /// The connected components, each as a list of vertex ids in increasing order.
+    pub fn components(&self) -> Vec<Vec<VertexId>> {
+        let mut union_find = UnionFind::new(self.len());
+        for (a, neighbors) in self.adjacency.iter().enumerate() {
+            for &b in neighbors.range(a + 1..) {
+                union_find.union(a, b);
+            }
+        }
+        union_find.groups()
+    }
+}
+
+/// Disjoint sets over `0..n` with union by size and path compression.
+///
+/// Useful for connectivity questions while edges are being added, where re-running a search after
+/// every change would be too slow.
+#[derive(Debug, Clone)]
+pub struct UnionFind {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    num_sets: usize,
+}
+
+impl UnionFind {
+    /// `n` singleton sets.
+    pub fn new(n: usize) -> Self {
+        Self {
+            parent: (0..n).collect(),
+            size: vec![1; n],
+            num_sets: n,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// The representative of the set containing `x`.
+    pub fn find(&mut self, x: usize) -> usize {
+        let mut root = x;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+        let mut node = x;
+        while self.parent[node] != root {
+            node = std::mem::replace(&mut self.parent[node], root);
+        }
+        root
+    }
+
+    /// Merges the sets containing `a` and `b`. Returns `false` if they were already the same set.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        let (big, small) = if self.size[a] >= self.size[b] {
+            (a, b)
+        } else {
+            (b, a)
+        };
+        self.parent[small] = big;
+        self.size[big] += self.size[small];
+        self.num_sets -= 1;
+        true
+    }
+
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// Number of elements in the set containing `x`.
+    pub fn set_size(&mut self, x: usize) -> usize {
+        let root = self.find(x);
+        self.size[root]
+    }
+
+    pub fn num_sets(&self) -> usize {
+        self.num_sets
+    }
+
+    /// The sets, each as a list of elements in increasing order, ordered by their smallest element.
+    pub fn groups(&mut self) -> Vec<Vec<usize>> {
+        let mut index_of_root = HashMap::new();
+        let mut groups: Vec<Vec<usize>> = Vec::with_capacity(self.num_sets);
+        for x in 0..self.len() {
+            let root = self.find(x);
+            let index = *index_of_root.entry(root).or_insert_with(|| {
+                groups.push(Vec::new());
+                groups.len() - 1
+            });
+            groups[index].push(x);
+        }
+        groups
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_graph() {
+        let mut graph = Graph::new();
+        graph.add_edge("kh", "tc");
+        graph.add_edge("qp", "kh");
+        graph.add_edge("tc", "qp");
+        graph.add_edge("de", "cg");
+        let lone = graph.add_vertex("yn");
+
+        let kh = graph.id(&"kh").unwrap();
+        assert_eq!(graph.len(), 6);
+        assert_eq!(graph.vertex(kh), &"kh");
+        assert_eq!(graph.add_vertex("kh"), kh);
+        assert!(graph.has_edge(kh, graph.id(&"tc").unwrap()));
+        assert_eq!(graph.neighbors(kh).len(), 2);
+        assert!(graph.neighbors(lone).is_empty());
+        assert_eq!(graph.id(&"ab"), None);
+
+        let components: Vec<Vec<&str>> = graph
+            .components()
+            .iter()
+            .map(|component| component.iter().map(|&id| *graph.vertex(id)).collect())
+            .collect();
+        assert_eq!(
+            components,
+            [vec!["kh", "tc", "qp"], vec!["de", "cg"], vec!["yn"]]
+        );
+    }
+
+    #[test]
+    fn test_union_find() {
+        let mut sets = UnionFind::new(6);
+        assert_eq!(sets.num_sets(), 6);
+        assert!(sets.union(0, 1));
+        assert!(sets.union(4, 3));
+        assert!(sets.union(1, 4));
+        assert!(!sets.union(3, 0));
+        assert!(sets.connected(0, 3));
+        assert!(!sets.connected(0, 5));
+        assert_eq!(sets.set_size(3), 4);
+        assert_eq!(sets.num_sets(), 3);
+        assert_eq!(sets.groups(), [vec![0, 1, 3, 4], vec![2], vec![5]]);
+    }
+}
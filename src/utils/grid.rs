@@ -0,0 +1,263 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use crate::error::{Error, Result};
+
+/// Position in a grid as `(x, y)`, signed so that neighbours can be computed without underflow.
+pub type Pos = (i64, i64);
+
+/// Offsets of the 4 orthogonal neighbours: up, right, down, left.
+pub const DIRS4: [Pos; 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Offsets of the 8 surrounding neighbours, clockwise starting at up.
+pub const DIRS8: [Pos; 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+/// A rectangular grid stored row by row in a flat vector.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    /// A `width` x `height` grid with all cells set to `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+}
+
+impl Grid<char> {
+    /// Parses a grid of characters, one row per line.
+    pub fn parse(input: &str) -> Result<Self> {
+        Self::parse_with(input, |_, ch| Some(ch))
+    }
+}
+
+impl<T> Grid<T> {
+    /// Parses a grid, mapping each character with `f`.
+    ///
+    /// `f` also gets the position of the character and returns `None` for invalid characters,
+    /// which are reported with their line and column.
+    pub fn parse_with<F>(input: &str, mut f: F) -> Result<Self>
+    where
+        F: FnMut(Pos, char) -> Option<T>,
+    {
+        let mut rows = Vec::new();
+        for (y, line) in input.lines().enumerate() {
+            let row = line
+                .chars()
+                .enumerate()
+                .map(|(x, ch)| {
+                    f((x as i64, y as i64), ch).ok_or_else(|| {
+                        Error::at(y + 1, x + 1, format!("Invalid character '{}' in grid", ch))
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?;
+            rows.push(row);
+        }
+        Self::from_rows(rows)
+    }
+
+    /// Builds a grid from its rows, which must all have the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self> {
+        let width = rows.first().map_or(0, |row| row.len());
+        if width == 0 {
+            return Err(Error::parse("Empty grid"));
+        }
+        let height = rows.len();
+        let mut cells = Vec::with_capacity(width * height);
+        for (y, row) in rows.into_iter().enumerate() {
+            if row.len() != width {
+                return Err(
+                    Error::parse(format!("Expected {} columns, got {}", width, row.len()))
+                        .at_line(y + 1),
+                );
+            }
+            cells.extend(row);
+        }
+        Ok(Self {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn in_bounds(&self, (x, y): Pos) -> bool {
+        x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height
+    }
+
+    fn offset(&self, pos: Pos) -> Option<usize> {
+        self.in_bounds(pos)
+            .then(|| pos.1 as usize * self.width + pos.0 as usize)
+    }
+
+    /// The cell at `pos`, or `None` if it lies outside the grid.
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.offset(pos).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.offset(pos).map(|i| &mut self.cells[i])
+    }
+
+    /// All positions, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |i| ((i % width) as i64, (i / width) as i64))
+    }
+
+    /// All cells with their positions, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    /// The orthogonal neighbours of `pos` that lie inside the grid.
+    pub fn neighbors4(&self, (x, y): Pos) -> impl Iterator<Item = Pos> + '_ {
+        DIRS4
+            .iter()
+            .map(move |(dx, dy)| (x + dx, y + dy))
+            .filter(|&pos| self.in_bounds(pos))
+    }
+
+    /// The orthogonal and diagonal neighbours of `pos` that lie inside the grid.
+    pub fn neighbors8(&self, (x, y): Pos) -> impl Iterator<Item = Pos> + '_ {
+        DIRS8
+            .iter()
+            .map(move |(dx, dy)| (x + dx, y + dy))
+            .filter(|&pos| self.in_bounds(pos))
+    }
+
+    /// Position of the first cell, row by row, matching `predicate`.
+    pub fn find<P>(&self, mut predicate: P) -> Option<Pos>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(pos, _)| pos)
+    }
+
+    /// Positions of all cells for which `predicate` holds, row by row.
+    pub fn find_all<'a, P>(&'a self, mut predicate: P) -> impl Iterator<Item = Pos> + 'a
+    where
+        P: FnMut(&T) -> bool + 'a,
+    {
+        self.iter()
+            .filter(move |(_, cell)| predicate(cell))
+            .map(|(pos, _)| pos)
+    }
+
+    /// A grid of the same size with `f` applied to every cell.
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &Self::Output {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("Position {:?} is outside of the grid", pos))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut Self::Output {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("Position {:?} is outside of the grid", pos))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_display() {
+        let grid = Grid::parse("#.S\n..#\n").unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 0)], 'S');
+        assert_eq!(grid.find(|&c| c == 'S'), Some((2, 0)));
+        assert_eq!(
+            grid.find_all(|&c| c == '#').collect::<Vec<_>>(),
+            [(0, 0), (2, 1)]
+        );
+        assert_eq!(grid.to_string(), "#.S\n..#\n");
+
+        let err = Grid::parse("##\n#\n").unwrap_err();
+        assert_eq!(err.to_string(), "2: Expected 2 columns, got 1");
+        let err = Grid::parse_with("..\n.x\n", |_, c| (c == '.').then_some(0)).unwrap_err();
+        assert_eq!(err.to_string(), "2:2: Invalid character 'x' in grid");
+    }
+
+    #[test]
+    fn test_bounds_and_neighbors() {
+        let grid = Grid::new(3, 2, 0u8);
+        assert!(grid.in_bounds((2, 1)));
+        assert!(!grid.in_bounds((-1, 0)));
+        assert!(!grid.in_bounds((3, 0)));
+        assert_eq!(grid.get((0, 2)), None);
+        assert_eq!(
+            grid.neighbors4((0, 0)).collect::<Vec<_>>(),
+            vec![(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbors8((1, 0)).count(), 5);
+        assert_eq!(grid.positions().last(), Some((2, 1)));
+    }
+
+    #[test]
+    fn test_map() {
+        let grid = Grid::parse("12\n34\n").unwrap();
+        let digits = grid.map(|c| c.to_digit(10).unwrap());
+        assert_eq!(digits[(1, 1)], 4);
+        assert_eq!(digits.iter().map(|(_, d)| d).sum::<u32>(), 10);
+    }
+}
@@ -0,0 +1,112 @@
+use std::{
+    env,
+    fmt::Display,
+    io,
+    path::{Path, PathBuf},
+    process,
+};
+
+use super::file::{read_lines, read_lines_from};
+use crate::{
+    error::{Error, Result},
+    solution::Solution,
+};
+
+/// Environment variable pointing to the directory with the `dayNN.txt` inputs.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+pub const DEFAULT_INPUT_DIR: &str = "inputs";
+
+/// Where the puzzle input of a day is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Resolves the input of `day` from an optional command line argument.
+    ///
+    /// The argument can be `-` for stdin, a directory with `dayNN.txt` files,
+    /// or the path to the input file itself. Without an argument the input is
+    /// looked up in `$AOC_INPUT_DIR`, falling back to `inputs/`.
+    pub fn resolve(day: u8, arg: Option<&str>) -> Self {
+        match arg {
+            Some("-") => InputSource::Stdin,
+            Some(path) if Path::new(path).is_dir() => {
+                InputSource::File(Path::new(path).join(input_file_name(day)))
+            }
+            Some(path) => InputSource::File(PathBuf::from(path)),
+            None => InputSource::File(input_dir().join(input_file_name(day))),
+        }
+    }
+
+    /// Resolves the input of `day` from the first argument of the process.
+    pub fn from_args(day: u8) -> Self {
+        Self::resolve(day, env::args().nth(1).as_deref())
+    }
+
+    pub fn read(&self) -> Result<String> {
+        match self {
+            InputSource::File(path) => read_lines(path),
+            InputSource::Stdin => {
+                read_lines_from(io::stdin().lock()).map_err(|e| Error::io(self, e))
+            }
+        }
+    }
+
+    /// Reads the input and parses it with `solution`.
+    pub fn read_parsed<S: Solution>(&self, solution: &S) -> Result<S::Input> {
+        let input = self.read()?;
+        solution.parse(&input).map_err(|e| e.in_file(self))
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputSource::File(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "<stdin>"),
+        }
+    }
+}
+
+pub fn input_file_name(day: u8) -> String {
+    format!("day{:02}.txt", day)
+}
+
+pub fn input_dir() -> PathBuf {
+    env::var_os(INPUT_DIR_VAR)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(DEFAULT_INPUT_DIR))
+}
+
+/// Reads and parses the input selected on the command line of a day binary.
+///
+/// Exits the process with a diagnostic if the input can't be read or parsed.
+pub fn load_parsed<S: Solution>(solution: &S) -> S::Input {
+    InputSource::from_args(S::DAY)
+        .read_parsed(solution)
+        .unwrap_or_else(|e| {
+            eprintln!("Error: {}", e);
+            process::exit(1);
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_input_source() {
+        assert_eq!(InputSource::resolve(5, Some("-")), InputSource::Stdin);
+        assert_eq!(
+            InputSource::resolve(5, Some("examples/day05.txt")),
+            InputSource::File(PathBuf::from("examples/day05.txt"))
+        );
+        assert_eq!(
+            InputSource::resolve(5, Some("src")),
+            InputSource::File(PathBuf::from("src/day05.txt"))
+        );
+    }
+}
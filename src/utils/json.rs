@@ -0,0 +1,149 @@
+use std::fmt::{self, Display, Write as _};
+
+/// A JSON value, to write machine-readable reports without a serialization library.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Json {
+    Null,
+    Bool(bool),
+    Int(i128),
+    Str(String),
+    Array(Vec<Json>),
+    /// Members are written in the order given.
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    /// An object with the given members.
+    pub fn object<K: Into<String>>(members: impl IntoIterator<Item = (K, Json)>) -> Self {
+        Json::Object(
+            members
+                .into_iter()
+                .map(|(key, value)| (key.into(), value))
+                .collect(),
+        )
+    }
+
+    /// `values` as an array with one element per line, for output that is easy to diff and grep.
+    pub fn lines(values: &[Json]) -> String {
+        let mut text = String::from("[");
+        for (i, value) in values.iter().enumerate() {
+            let separator = if i == 0 { "" } else { "," };
+            write!(text, "{}\n  {}", separator, value).unwrap();
+        }
+        text.push_str(if values.is_empty() { "]\n" } else { "\n]\n" });
+        text
+    }
+}
+
+impl From<&str> for Json {
+    fn from(s: &str) -> Self {
+        Json::Str(s.to_string())
+    }
+}
+
+impl From<String> for Json {
+    fn from(s: String) -> Self {
+        Json::Str(s)
+    }
+}
+
+impl From<bool> for Json {
+    fn from(b: bool) -> Self {
+        Json::Bool(b)
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Json {
+            fn from(n: $t) -> Self {
+                Json::Int(n as i128)
+            }
+        })*
+    };
+}
+
+impl_from_int!(u8, u32, u64, u128, usize, i32, i64);
+
+impl<T: Into<Json>> From<Option<T>> for Json {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Json::Null, Into::into)
+    }
+}
+
+impl Display for Json {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Json::Null => write!(f, "null"),
+            Json::Bool(b) => write!(f, "{}", b),
+            Json::Int(n) => write!(f, "{}", n),
+            Json::Str(s) => write_string(f, s),
+            Json::Array(values) => {
+                write!(f, "[")?;
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{}", value)?;
+                }
+                write!(f, "]")
+            }
+            Json::Object(members) => {
+                write!(f, "{{")?;
+                for (i, (key, value)) in members.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write_string(f, key)?;
+                    write!(f, ":{}", value)?;
+                }
+                write!(f, "}}")
+            }
+        }
+    }
+}
+
+/// Writes `s` as a quoted JSON string, escaping quotes, backslashes and control characters.
+fn write_string(f: &mut fmt::Formatter<'_>, s: &str) -> fmt::Result {
+    f.write_char('"')?;
+    for ch in s.chars() {
+        match ch {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            '\r' => f.write_str("\\r")?,
+            '\t' => f.write_str("\\t")?,
+            ch if (ch as u32) < 0x20 => write!(f, "\\u{:04x}", ch as u32)?,
+            ch => f.write_char(ch)?,
+        }
+    }
+    f.write_char('"')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json() {
+        let value = Json::object([
+            ("day", Json::from(9u8)),
+            ("answer", "say \"hi\"\n\\".into()),
+            ("time_ns", Json::from(Some(1_500u128))),
+            ("error", Json::from(None::<String>)),
+            ("ok", true.into()),
+            ("parts", Json::Array(vec![1u8.into(), 2u8.into()])),
+            ("bell", "\x07".into()),
+        ]);
+        assert_eq!(
+            value.to_string(),
+            r#"{"day":9,"answer":"say \"hi\"\n\\","time_ns":1500,"error":null,"ok":true,"parts":[1,2],"bell":"\u0007"}"#
+        );
+
+        assert_eq!(Json::lines(&[]), "[]\n");
+        assert_eq!(
+            Json::lines(&[Json::Null, Json::Int(-1)]),
+            "[\n  null,\n  -1\n]\n"
+        );
+    }
+}
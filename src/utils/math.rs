@@ -0,0 +1,206 @@
+/// Greatest common divisor, always non-negative. `gcd(0, 0)` is 0.
+pub fn gcd(a: i64, b: i64) -> i64 {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Least common multiple, always non-negative. It is 0 if either argument is 0.
+pub fn lcm(a: i64, b: i64) -> i64 {
+    if a == 0 || b == 0 {
+        return 0;
+    }
+    (a / gcd(a, b) * b).abs()
+}
+
+/// Extended Euclidean algorithm: returns `(g, x, y)` with `a * x + b * y == g == gcd(a, b)`.
+pub fn ext_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// The inverse of `a` modulo `m` in `0..m`, if `a` and `m` are coprime.
+pub fn mod_inv(a: i64, m: i64) -> Option<i64> {
+    assert!(m > 0, "Modulus must be positive");
+    let (g, x, _) = ext_gcd(a.rem_euclid(m), m);
+    (g == 1).then(|| x.rem_euclid(m))
+}
+
+/// Chinese remainder theorem: the smallest non-negative `x` with `x ≡ r (mod m)` for all the
+/// `(r, m)` congruences, together with the combined modulus.
+///
+/// The moduli don't need to be coprime. Returns `None` if the congruences contradict each other,
+/// or if the combined modulus doesn't fit in an `i64`.
+pub fn crt(congruences: &[(i64, i64)]) -> Option<(i64, i64)> {
+    let mut x: i128 = 0;
+    let mut modulus: i128 = 1;
+    for &(r, m) in congruences {
+        assert!(m > 0, "Modulus must be positive");
+        let (g, p, _) = ext_gcd(i64::try_from(modulus).ok()?, m);
+        let (g, m) = (g as i128, m as i128);
+        let diff = r as i128 - x;
+        if diff % g != 0 {
+            return None;
+        }
+        // x + modulus * k ≡ r (mod m)  <=>  k ≡ diff / g * p (mod m / g)
+        let step = m / g;
+        let k = (diff / g % step * p as i128).rem_euclid(step);
+        x += modulus * k;
+        modulus *= step;
+        x = x.rem_euclid(modulus);
+    }
+    Some((i64::try_from(x).ok()?, i64::try_from(modulus).ok()?))
+}
+
+/// Solves `a * x + b * y == e` and `c * x + d * y == f` exactly.
+///
+/// Returns `None` unless the system has a unique solution in integers.
+pub fn solve_2x2(a: i64, b: i64, c: i64, d: i64, e: i64, f: i64) -> Option<(i64, i64)> {
+    let [a, b, c, d, e, f] = [a, b, c, d, e, f].map(i128::from);
+    let det = a * d - b * c;
+    if det == 0 {
+        return None;
+    }
+    let x_num = e * d - b * f;
+    let y_num = a * f - e * c;
+    if x_num % det != 0 || y_num % det != 0 {
+        return None;
+    }
+    let x = i64::try_from(x_num / det).ok()?;
+    let y = i64::try_from(y_num / det).ok()?;
+    Some((x, y))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::rng::Rng;
+
+    const CASES: usize = 1000;
+
+    #[test]
+    fn test_gcd_lcm() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(-12, 18), 6);
+        assert_eq!(gcd(0, 0), 0);
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!(lcm(0, 6), 0);
+
+        let mut rng = Rng::new(12);
+        for _ in 0..CASES {
+            let (a, b) = (
+                rng.between(-1_000_000, 1_000_000),
+                rng.between(-1_000_000, 1_000_000),
+            );
+            let g = gcd(a, b);
+            assert!(g >= 0);
+            assert_eq!(g, gcd(b, a));
+            if g != 0 {
+                assert_eq!(a % g, 0);
+                assert_eq!(b % g, 0);
+                assert_eq!(gcd(a / g, b / g), 1, "gcd({}, {})", a, b);
+                assert_eq!(lcm(a, b) * g, (a * b).abs(), "lcm({}, {})", a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_ext_gcd_mod_inv() {
+        assert_eq!(ext_gcd(240, 46), (2, -9, 47));
+        assert_eq!(mod_inv(3, 11), Some(4));
+        assert_eq!(mod_inv(-3, 11), Some(7));
+        assert_eq!(mod_inv(6, 9), None);
+
+        let mut rng = Rng::new(46);
+        for _ in 0..CASES {
+            let (a, b) = (
+                rng.between(-1_000_000, 1_000_000),
+                rng.between(-1_000_000, 1_000_000),
+            );
+            let (g, x, y) = ext_gcd(a, b);
+            assert_eq!(g, gcd(a, b));
+            assert_eq!(a * x + b * y, g, "ext_gcd({}, {})", a, b);
+
+            let m = b.abs().max(1);
+            match mod_inv(a, m) {
+                Some(inv) => {
+                    assert!((0..m).contains(&inv));
+                    assert_eq!((a.rem_euclid(m) * inv) % m, 1 % m, "mod_inv({}, {})", a, m);
+                }
+                None => assert_ne!(gcd(a, m), 1),
+            }
+        }
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt(&[(1, 4), (3, 6)]), Some((9, 12)));
+        assert_eq!(crt(&[(1, 4), (2, 6)]), None);
+        assert_eq!(crt(&[]), Some((0, 1)));
+        let big = 1 << 40;
+        assert_eq!(
+            crt(&[(1, big), (2, big + 1), (3, big + 3), (4, big + 5)]),
+            None
+        );
+
+        let mut rng = Rng::new(105);
+        for _ in 0..CASES {
+            let count = rng.between(1, 4);
+            let x = rng.between(0, 1_000_000);
+            let congruences: Vec<(i64, i64)> = (0..count)
+                .map(|_| {
+                    let m = rng.between(1, 201);
+                    (x % m, m)
+                })
+                .collect();
+            let (solution, modulus) = crt(&congruences).expect("Congruences are consistent");
+            let expected_modulus = congruences.iter().fold(1, |acc, &(_, m)| lcm(acc, m));
+            assert_eq!(modulus, expected_modulus);
+            assert_eq!(solution, x % modulus, "crt({:?})", congruences);
+        }
+    }
+
+    #[test]
+    fn test_solve_2x2() {
+        assert_eq!(solve_2x2(94, 22, 34, 67, 8400, 5400), Some((80, 40)));
+        assert_eq!(solve_2x2(26, 67, 66, 21, 12748, 12176), None);
+        assert_eq!(solve_2x2(1, 2, 2, 4, 3, 6), None);
+
+        let mut rng = Rng::new(2);
+        for _ in 0..CASES {
+            let [a, b, c, d, x, y] = [(); 6].map(|_| rng.between(-10_000, 10_000));
+            let (e, f) = (a * x + b * y, c * x + d * y);
+            let solution = solve_2x2(a, b, c, d, e, f);
+            if a * d == b * c {
+                assert_eq!(solution, None);
+            } else {
+                assert_eq!(
+                    solution,
+                    Some((x, y)),
+                    "{} {} {} {} {} {}",
+                    a,
+                    b,
+                    c,
+                    d,
+                    e,
+                    f
+                );
+            }
+        }
+    }
+}
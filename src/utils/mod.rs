@@ -1 +1,17 @@
+pub mod cancel;
 pub mod file;
+pub mod geom;
+pub mod graph;
+pub mod grid;
+pub mod hash;
+pub mod input;
+pub mod json;
+pub mod math;
+pub mod parse;
+pub mod property;
+pub mod record;
+pub mod render;
+pub mod rng;
+pub mod search;
+pub mod table;
+pub mod validate;
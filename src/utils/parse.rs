@@ -0,0 +1,206 @@
+use std::str::FromStr;
+
+use crate::error::{parse_number, Error, Result};
+
+/// A run of consecutive non-empty lines of the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Block<'a> {
+    /// 1-based line number of the first line of the block.
+    pub line: usize,
+    /// The lines of the block, without the trailing newline.
+    pub text: &'a str,
+}
+
+impl<'a> Block<'a> {
+    pub fn lines(&self) -> impl Iterator<Item = &'a str> {
+        self.text.lines()
+    }
+
+    /// Parses the whole block with `f`, moving error positions to the block's place in the input.
+    pub fn parse_with<T, F>(&self, f: F) -> Result<T>
+    where
+        F: FnOnce(&'a str) -> Result<T>,
+    {
+        f(self.text).map_err(|e| e.offset_lines(self.line - 1))
+    }
+
+    /// Parses each line of the block with `f`.
+    pub fn parse_lines<T, F>(&self, f: F) -> Result<Vec<T>>
+    where
+        F: FnMut(&str) -> Result<T>,
+    {
+        self.parse_with(|text| parse_lines(text, f))
+    }
+}
+
+/// Groups the input into blocks separated by one or more blank lines.
+pub fn blocks(input: &str) -> Vec<Block<'_>> {
+    let mut blocks = Vec::new();
+    let mut current: Option<(usize, usize, usize)> = None;
+    for (i, line) in input.lines().enumerate() {
+        let start = line.as_ptr() as usize - input.as_ptr() as usize;
+        if line.trim().is_empty() {
+            if let Some((line_number, block_start, block_end)) = current.take() {
+                blocks.push(Block {
+                    line: line_number,
+                    text: &input[block_start..block_end],
+                });
+            }
+        } else {
+            let end = start + line.len();
+            current = match current {
+                Some((line_number, block_start, _)) => Some((line_number, block_start, end)),
+                None => Some((i + 1, start, end)),
+            };
+        }
+    }
+    if let Some((line_number, block_start, block_end)) = current {
+        blocks.push(Block {
+            line: line_number,
+            text: &input[block_start..block_end],
+        });
+    }
+    blocks
+}
+
+/// Splits the input into exactly `N` blocks separated by blank lines.
+pub fn sections<const N: usize>(input: &str) -> Result<[Block<'_>; N]> {
+    let blocks = blocks(input);
+    let num_blocks = blocks.len();
+    blocks.try_into().map_err(|_| {
+        Error::parse(format!(
+            "Expected {} sections separated by blank lines, got {}",
+            N, num_blocks
+        ))
+    })
+}
+
+/// Parses each line of `input` with `f`, adding the 1-based line number to errors.
+pub fn parse_lines<T, F>(input: &str, mut f: F) -> Result<Vec<T>>
+where
+    F: FnMut(&str) -> Result<T>,
+{
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| f(line).map_err(|e| e.at_line(i + 1)))
+        .collect()
+}
+
+/// All integers in `line`, ignoring any text around them.
+///
+/// A `-` directly in front of the digits makes the number negative.
+pub fn integers<T: FromStr>(line: &str) -> Result<Vec<T>> {
+    let bytes = line.as_bytes();
+    let mut numbers = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        let is_sign = bytes[i] == b'-' && bytes.get(i + 1).is_some_and(u8::is_ascii_digit);
+        if is_sign || bytes[i].is_ascii_digit() {
+            let start = i;
+            i += 1;
+            while i < bytes.len() && bytes[i].is_ascii_digit() {
+                i += 1;
+            }
+            numbers.push(parse_number(line, &line[start..i])?);
+        } else {
+            i += 1;
+        }
+    }
+    Ok(numbers)
+}
+
+/// Exactly `N` integers in `line`, see [`integers`].
+pub fn fixed_integers<T: FromStr, const N: usize>(line: &str) -> Result<[T; N]> {
+    let numbers = integers(line)?;
+    let num_found = numbers.len();
+    numbers
+        .try_into()
+        .map_err(|_| Error::parse(format!("Expected {} numbers, got {}", N, num_found)))
+}
+
+/// Parses a list of values separated by `separator`.
+///
+/// Surrounding whitespace of each item is ignored. A blank `separator` splits at any run of
+/// whitespace.
+pub fn list<T: FromStr>(line: &str, separator: &str) -> Result<Vec<T>> {
+    let items: Vec<&str> = if separator.trim().is_empty() {
+        line.split_whitespace().collect()
+    } else {
+        line.split(separator).collect()
+    };
+    items
+        .into_iter()
+        .map(|item| parse_number(line, item))
+        .collect()
+}
+
+/// Splits a `key<separator>value` line.
+pub fn key_value<'a>(line: &'a str, separator: &str) -> Result<(&'a str, &'a str)> {
+    line.split_once(separator)
+        .map(|(key, value)| (key.trim(), value.trim()))
+        .ok_or_else(|| Error::parse(format!("Expected 'key{}value', got '{}'", separator, line)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blocks_and_sections() {
+        let input = "a\nb\n\n\nc\n\nd\ne\n";
+        let blocks = blocks(input);
+        assert_eq!(
+            blocks,
+            vec![
+                Block {
+                    line: 1,
+                    text: "a\nb"
+                },
+                Block { line: 5, text: "c" },
+                Block {
+                    line: 7,
+                    text: "d\ne"
+                },
+            ]
+        );
+
+        let err = sections::<2>(input).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Expected 2 sections separated by blank lines, got 3"
+        );
+
+        let [first, second] = sections("1,2\n\n3,4\n5,x\n").unwrap();
+        assert_eq!(
+            first.parse_lines(|line| list::<u8>(line, ",")).unwrap(),
+            vec![vec![1, 2]]
+        );
+        let err = second
+            .parse_lines(|line| list::<u8>(line, ","))
+            .unwrap_err();
+        assert_eq!(err.to_string(), "4:3: Invalid number 'x'");
+    }
+
+    #[test]
+    fn test_integers() {
+        assert_eq!(integers::<i64>("p=0,4 v=3,-3").unwrap(), vec![0, 4, 3, -3]);
+        assert_eq!(
+            fixed_integers::<u64, 2>("Button A: X+94, Y+34").unwrap(),
+            [94, 34]
+        );
+        assert_eq!(integers::<u8>("a-b - 7").unwrap(), vec![7]);
+        let err = integers::<u8>("x=300").unwrap_err();
+        assert_eq!(err.to_string(), "Invalid number '300' (column 3)");
+        let err = fixed_integers::<i32, 2>("Prize: X=1").unwrap_err();
+        assert_eq!(err.to_string(), "Expected 2 numbers, got 1");
+    }
+
+    #[test]
+    fn test_list_and_key_value() {
+        assert_eq!(list::<i32>("7 6  4", " ").unwrap(), vec![7, 6, 4]);
+        assert_eq!(list::<i32>("75, 47", ",").unwrap(), vec![75, 47]);
+        assert_eq!(key_value("x00: 1", ":").unwrap(), ("x00", "1"));
+        assert!(key_value("x00 1", ":").is_err());
+    }
+}
@@ -0,0 +1,271 @@
+use std::{env, fs, path::PathBuf};
+
+use super::rng::Rng;
+use crate::solution::Solution;
+
+/// Number of random inputs each property is checked on, unless `$AOC_PROPERTY_CASES` is set.
+pub const DEFAULT_CASES: u64 = 100;
+
+pub const CASES_VAR: &str = "AOC_PROPERTY_CASES";
+
+/// Environment variable with the first seed to check, e.g. to replay a reported failure.
+pub const SEED_VAR: &str = "AOC_PROPERTY_SEED";
+
+/// Most shrinking steps before giving up on finding a smaller counter-example.
+const MAX_SHRINKS: usize = 1000;
+
+/// An input on which a property doesn't hold, shrunk as far as possible.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Counterexample {
+    /// Seed of the random input the property first failed on.
+    pub seed: u64,
+    pub input: String,
+    /// Why the property failed on `input`.
+    pub message: String,
+    /// Number of shrinking steps from the random input to `input`.
+    pub shrinks: usize,
+}
+
+/// Checks `property` on the inputs generated from each of `seeds` and returns the first one it
+/// fails on, shrunk.
+///
+/// `parse` turns an input into what the property checks, or `None` if a shrunk input is no longer
+/// valid. `shrink` lists smaller variants of an input: the first one the property still fails on
+/// replaces the counter-example, until it fails on none of them.
+pub fn find_counterexample<T>(
+    seeds: impl IntoIterator<Item = u64>,
+    generate: impl Fn(&mut Rng) -> String,
+    parse: impl Fn(&str) -> Option<T>,
+    shrink: impl Fn(&str) -> Vec<String>,
+    property: impl Fn(&T) -> Result<(), String>,
+) -> Option<Counterexample> {
+    let fails = |input: &str| parse(input).and_then(|parsed| property(&parsed).err());
+    for seed in seeds {
+        let input = generate(&mut Rng::new(seed));
+        let Some(message) = fails(&input) else {
+            continue;
+        };
+        let mut counterexample = Counterexample {
+            seed,
+            input,
+            message,
+            shrinks: 0,
+        };
+        while counterexample.shrinks < MAX_SHRINKS {
+            let smaller = shrink(&counterexample.input)
+                .into_iter()
+                .find_map(|input| fails(&input).map(|message| (input, message)));
+            let Some((input, message)) = smaller else {
+                break;
+            };
+            counterexample.input = input;
+            counterexample.message = message;
+            counterexample.shrinks += 1;
+        }
+        return Some(counterexample);
+    }
+    None
+}
+
+/// Checks a property of the solution on random inputs, see [`find_counterexample`].
+///
+/// Generated and shrunk inputs are only used if they pass the validator of the day and parse. On
+/// failure, the shrunk counter-example is written to `target/counterexamples/dayNN-<name>.txt`
+/// and the test panics with the seed that found it.
+pub fn check<S: Solution>(
+    solution: &S,
+    name: &str,
+    generate: impl Fn(&mut Rng) -> String,
+    shrink: impl Fn(&str) -> Vec<String>,
+    property: impl Fn(&S::Input) -> Result<(), String>,
+) {
+    let cases = env::var(CASES_VAR)
+        .ok()
+        .and_then(|cases| cases.parse().ok())
+        .unwrap_or(DEFAULT_CASES);
+    let first = env::var(SEED_VAR)
+        .ok()
+        .and_then(|seed| seed.parse().ok())
+        .unwrap_or(0);
+    let parse = |input: &str| {
+        if !solution.validate(input).is_empty() {
+            return None;
+        }
+        solution.parse(input).ok()
+    };
+    let Some(counterexample) =
+        find_counterexample(first..first + cases, generate, parse, shrink, property)
+    else {
+        return;
+    };
+    let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("target")
+        .join("counterexamples");
+    let file = dir.join(format!("day{:02}-{}.txt", S::DAY, name));
+    let written = fs::create_dir_all(&dir)
+        .and_then(|_| fs::write(&file, &counterexample.input))
+        .map_or_else(
+            |e| format!("couldn't write it to {}: {}", file.display(), e),
+            |_| format!("written to {}", file.display()),
+        );
+    panic!(
+        "Property {} of day {} fails with {}={} ({} shrinks, {}): {}\n{}",
+        name,
+        S::DAY,
+        SEED_VAR,
+        counterexample.seed,
+        counterexample.shrinks,
+        written,
+        counterexample.message,
+        counterexample.input
+    );
+}
+
+/// The input without each one of its lines.
+pub fn remove_lines(input: &str) -> Vec<String> {
+    let lines: Vec<&str> = input.lines().collect();
+    (0..lines.len())
+        .map(|i| {
+            lines
+                .iter()
+                .enumerate()
+                .filter(|&(j, _)| j != i)
+                .map(|(_, line)| format!("{}\n", line))
+                .collect()
+        })
+        .collect()
+}
+
+/// The input without each one of its blocks separated by blank lines.
+pub fn remove_blocks(input: &str) -> Vec<String> {
+    let blocks: Vec<&str> = input.split("\n\n").map(|b| b.trim_matches('\n')).collect();
+    (0..blocks.len())
+        .map(|i| {
+            let rest: Vec<&str> = blocks
+                .iter()
+                .enumerate()
+                .filter(|&(j, _)| j != i)
+                .map(|(_, block)| *block)
+                .collect();
+            format!("{}\n", rest.join("\n\n"))
+        })
+        .collect()
+}
+
+/// The grid without each one of its rows, then without each one of its columns.
+pub fn remove_rows_and_columns(input: &str) -> Vec<String> {
+    let rows: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
+    let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+    let mut smaller = remove_lines(input);
+    for x in 0..width {
+        smaller.push(
+            rows.iter()
+                .map(|row| {
+                    let mut row = row.clone();
+                    if x < row.len() {
+                        row.remove(x);
+                    }
+                    row.into_iter().collect::<String>() + "\n"
+                })
+                .collect(),
+        );
+    }
+    smaller
+}
+
+/// The input with each one of its numbers replaced by 0, half of it, or one less.
+pub fn smaller_numbers(input: &str) -> Vec<String> {
+    let mut smaller = Vec::new();
+    let bytes = input.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        if !bytes[i].is_ascii_digit() {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        let Ok(n) = input[start..i].parse::<u64>() else {
+            continue;
+        };
+        let mut candidates = vec![0, n / 2, n.saturating_sub(1)];
+        candidates.dedup();
+        for m in candidates.into_iter().filter(|&m| m < n) {
+            smaller.push(format!("{}{}{}", &input[..start], m, &input[i..]));
+        }
+    }
+    smaller
+}
+
+/// The input with each one of its digits lowered by one, for inputs where every digit counts on
+/// its own.
+pub fn smaller_digits(input: &str) -> Vec<String> {
+    input
+        .char_indices()
+        .filter_map(|(i, ch)| {
+            let digit = ch.to_digit(10).filter(|&d| d > 0)?;
+            let lower = char::from_digit(digit - 1, 10)?;
+            Some(format!("{}{}{}", &input[..i], lower, &input[i + 1..]))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shrinkers() {
+        assert_eq!(remove_lines("a\nb\nc\n"), ["b\nc\n", "a\nc\n", "a\nb\n"]);
+        assert_eq!(remove_blocks("a\nb\n\nc\n"), ["c\n", "a\nb\n"]);
+        assert_eq!(
+            remove_rows_and_columns("ab\ncd\n"),
+            ["cd\n", "ab\n", "b\nd\n", "a\nc\n"]
+        );
+        assert_eq!(
+            smaller_numbers("x=7, y=0"),
+            ["x=0, y=0", "x=3, y=0", "x=6, y=0"]
+        );
+        assert_eq!(smaller_numbers("1"), ["0"]);
+        assert_eq!(smaller_digits("102"), ["002", "101"]);
+    }
+
+    #[test]
+    fn test_find_counterexample() {
+        // Lines of numbers never add up to 100 or more, which is false.
+        let generate = |rng: &mut Rng| {
+            (0..rng.between(1, 8))
+                .map(|_| format!("{}\n", rng.between(0, 60)))
+                .collect::<String>()
+        };
+        let parse = |input: &str| {
+            input
+                .lines()
+                .map(|line| line.parse().ok())
+                .collect::<Option<Vec<u64>>>()
+        };
+        let shrink = |input: &str| [remove_lines(input), smaller_numbers(input)].concat();
+        let property = |numbers: &Vec<u64>| {
+            let sum: u64 = numbers.iter().sum();
+            (sum < 100)
+                .then_some(())
+                .ok_or_else(|| format!("Sum is {}", sum))
+        };
+        let counterexample =
+            find_counterexample(0..100, generate, parse, shrink, property).unwrap();
+        assert_eq!(counterexample.message, "Sum is 100");
+        assert!(counterexample.shrinks > 0);
+        assert_eq!(
+            counterexample,
+            find_counterexample(0..100, generate, parse, shrink, property).unwrap()
+        );
+
+        let property = |_: &Vec<u64>| Ok(());
+        assert_eq!(
+            find_counterexample(0..100, generate, parse, shrink, property),
+            None
+        );
+    }
+}
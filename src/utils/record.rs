@@ -0,0 +1,353 @@
+use std::{
+    fmt::Write as _,
+    fs::{self, File},
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+    thread,
+    time::Duration,
+};
+
+use super::render::{Frame, RenderFormat};
+use crate::error::{Error, Result};
+
+/// Prefix of the line that starts each frame in an animation file.
+const FRAME_HEADER: &str = "=== step ";
+
+/// Frames kept by a recording unless the command line allows more, which keeps recordings of the
+/// longer simulations to a few tens of megabytes.
+pub const DEFAULT_MAX_FRAMES: usize = 1000;
+
+/// Where a [`Recorder`] puts the frames it keeps.
+#[derive(Debug)]
+enum Sink {
+    /// Kept in memory.
+    Memory(Vec<(usize, Frame)>),
+    /// Appended to one animation file, which is created with the first frame.
+    Animation {
+        path: PathBuf,
+        file: Option<BufWriter<File>>,
+    },
+    /// Each written to its own file in `dir`, named `<name>-<step>.<ext>`.
+    Frames {
+        dir: PathBuf,
+        name: String,
+        format: RenderFormat,
+        files: Vec<PathBuf>,
+    },
+}
+
+/// Collects snapshots of a step-by-step simulation, writing them out as they are taken or keeping
+/// them to replay them later.
+#[derive(Debug)]
+pub struct Recorder {
+    every: usize,
+    max_frames: usize,
+    steps: usize,
+    kept: usize,
+    last_kept: Option<usize>,
+    capped: bool,
+    sink: Sink,
+    error: Option<Error>,
+}
+
+impl Recorder {
+    /// A recorder keeping the state after every `every`-th step in memory, starting with step 0.
+    pub fn new(every: usize) -> Self {
+        Self::with_sink(every, Sink::Memory(Vec::new()))
+    }
+
+    /// A recorder appending the state after every `every`-th step to the animation file `path`,
+    /// see [`Recorder::animation`].
+    pub fn to_animation(path: &Path, every: usize) -> Self {
+        let sink = Sink::Animation {
+            path: path.to_path_buf(),
+            file: None,
+        };
+        Self::with_sink(every, sink)
+    }
+
+    /// A recorder writing the state after every `every`-th step to its own file in `dir`, named
+    /// `<name>-<step>.<ext>`.
+    pub fn to_frames(dir: &Path, name: &str, format: RenderFormat, every: usize) -> Self {
+        let sink = Sink::Frames {
+            dir: dir.to_path_buf(),
+            name: name.to_string(),
+            format,
+            files: Vec::new(),
+        };
+        Self::with_sink(every, sink)
+    }
+
+    fn with_sink(every: usize, sink: Sink) -> Self {
+        assert!(every > 0, "Must record at least every step");
+        Self {
+            every,
+            max_frames: usize::MAX,
+            steps: 0,
+            kept: 0,
+            last_kept: None,
+            capped: false,
+            sink,
+            error: None,
+        }
+    }
+
+    /// Keeps at most `max_frames` frames, the last of them being the final state: steps after the
+    /// cap is reached are counted but not kept.
+    pub fn with_max_frames(mut self, max_frames: usize) -> Self {
+        assert!(max_frames > 0, "Must keep at least one frame");
+        self.max_frames = max_frames;
+        self
+    }
+
+    /// Called once per step with a way to draw the current state, which is only drawn when the
+    /// step is kept.
+    pub fn record(&mut self, frame: impl FnOnce() -> Frame) {
+        if self.steps.is_multiple_of(self.every) {
+            // One frame is held back for the final state.
+            if self.kept + 1 < self.max_frames {
+                self.keep(self.steps, frame());
+            } else {
+                self.capped = true;
+            }
+        }
+        self.steps += 1;
+    }
+
+    /// Adds the final state if the last step wasn't kept, so that recordings always end with it.
+    pub fn finish(&mut self, frame: impl FnOnce() -> Frame) {
+        let last_step = self.steps.saturating_sub(1);
+        if self.last_kept != Some(last_step) {
+            self.keep(last_step, frame());
+        }
+    }
+
+    fn keep(&mut self, step: usize, frame: Frame) {
+        self.kept += 1;
+        self.last_kept = Some(step);
+        if self.error.is_some() {
+            return;
+        }
+        let written = match &mut self.sink {
+            Sink::Memory(frames) => {
+                frames.push((step, frame));
+                Ok(())
+            }
+            Sink::Animation { path, file } => {
+                let text = animation_frame(step, &frame);
+                let written = match file {
+                    Some(file) => file.write_all(text.as_bytes()),
+                    None => create_file(path).and_then(|mut created| {
+                        created.write_all(text.as_bytes())?;
+                        *file = Some(created);
+                        Ok(())
+                    }),
+                };
+                written.map_err(|e| Error::io(path.display(), e))
+            }
+            Sink::Frames {
+                dir,
+                name,
+                format,
+                files,
+            } => {
+                let path = dir.join(format!("{}-{:06}.{}", name, step, format.extension()));
+                let written =
+                    fs::create_dir_all(&*dir).and_then(|_| fs::write(&path, frame.encode(*format)));
+                files.push(path.clone());
+                written.map_err(|e| Error::io(path.display(), e))
+            }
+        };
+        self.error = written.err();
+    }
+
+    /// The frames kept in memory with the number of their step, none if they are written out.
+    pub fn frames(&self) -> &[(usize, Frame)] {
+        match &self.sink {
+            Sink::Memory(frames) => frames,
+            _ => &[],
+        }
+    }
+
+    /// Number of frames kept so far.
+    pub fn kept(&self) -> usize {
+        self.kept
+    }
+
+    /// Number of steps recorded so far, kept or not.
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    /// Whether steps were left out because the recorder already kept its maximum of frames.
+    pub fn capped(&self) -> bool {
+        self.capped
+    }
+
+    /// Finishes writing the frames out, returning the written files, or the first error that
+    /// stopped the recorder from writing them.
+    pub fn close(self) -> Result<Vec<PathBuf>> {
+        if let Some(e) = self.error {
+            return Err(e);
+        }
+        match self.sink {
+            Sink::Memory(_) => Ok(Vec::new()),
+            Sink::Animation { path, file } => match file {
+                Some(mut file) => {
+                    file.flush().map_err(|e| Error::io(path.display(), e))?;
+                    Ok(vec![path])
+                }
+                None => Ok(Vec::new()),
+            },
+            Sink::Frames { files, .. } => Ok(files),
+        }
+    }
+
+    /// The frames kept in memory as ANSI text in one file, each after a header line with its
+    /// step.
+    pub fn animation(&self) -> String {
+        self.frames()
+            .iter()
+            .map(|(step, frame)| animation_frame(*step, frame))
+            .collect()
+    }
+}
+
+/// One frame of an animation file: a header line with its step followed by the ANSI text.
+fn animation_frame(step: usize, frame: &Frame) -> String {
+    let mut text = String::new();
+    writeln!(text, "{}{} ===", FRAME_HEADER, step).unwrap();
+    text.push_str(&frame.to_ansi());
+    text
+}
+
+/// Creates the file `path` along with its directory, for writing to it piece by piece.
+fn create_file(path: &Path) -> io::Result<BufWriter<File>> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    File::create(path).map(BufWriter::new)
+}
+
+/// Splits an animation written by [`Recorder::animation`] into its steps and frame texts.
+pub fn parse_animation(text: &str) -> Result<Vec<(usize, &str)>> {
+    let mut frames = Vec::new();
+    let mut rest = text;
+    let mut line_number = 1;
+    while !rest.is_empty() {
+        let (header, body) = rest.split_once('\n').unwrap_or((rest, ""));
+        let step = header
+            .strip_prefix(FRAME_HEADER)
+            .and_then(|h| h.strip_suffix(" ==="))
+            .and_then(|step| step.parse().ok())
+            .ok_or_else(|| {
+                Error::parse("Expected a '=== step N ===' header").at_line(line_number)
+            })?;
+        let end = body
+            .find(&format!("\n{}", FRAME_HEADER))
+            .map_or(body.len(), |i| i + 1);
+        frames.push((step, &body[..end]));
+        line_number += 1 + body[..end].lines().count();
+        rest = &body[end..];
+    }
+    Ok(frames)
+}
+
+/// Plays the frames of an animation in the terminal, `fps` frames per second.
+pub fn replay(frames: &[(usize, &str)], fps: f64, out: &mut impl Write) -> io::Result<()> {
+    let delay = Duration::from_secs_f64(1.0 / fps);
+    for (i, (step, frame)) in frames.iter().enumerate() {
+        // Move the cursor home and clear the screen before drawing the next frame.
+        writeln!(out, "\x1b[H\x1b[2J{}step {}", frame, step)?;
+        out.flush()?;
+        if i + 1 < frames.len() {
+            thread::sleep(delay);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::render::{Cell, Color};
+
+    fn frame(ch: char) -> Frame {
+        Frame::filled(2, 1, Cell::new(ch, Color::WHITE))
+    }
+
+    #[test]
+    fn test_recorder() {
+        let mut recorder = Recorder::new(3);
+        for ch in "abcdefg".chars() {
+            recorder.record(|| frame(ch));
+        }
+        recorder.finish(|| frame('g'));
+        let steps: Vec<usize> = recorder.frames().iter().map(|&(step, _)| step).collect();
+        assert_eq!(steps, [0, 3, 6]);
+        assert_eq!(recorder.steps(), 7);
+
+        recorder.record(|| frame('h'));
+        recorder.finish(|| frame('h'));
+        assert_eq!(recorder.frames().last().unwrap().0, 7);
+        assert!(!recorder.capped());
+
+        let mut recorder = Recorder::new(2).with_max_frames(3);
+        for ch in "abcdefghi".chars() {
+            recorder.record(|| frame(ch));
+        }
+        recorder.finish(|| frame('i'));
+        let steps: Vec<usize> = recorder.frames().iter().map(|&(step, _)| step).collect();
+        assert_eq!(steps, [0, 2, 8]);
+        assert!(recorder.capped());
+    }
+
+    #[test]
+    fn test_streaming() {
+        let dir = std::env::temp_dir().join(format!("aoc-record-{}", std::process::id()));
+        let path = dir.join("anim").join("day00.anim");
+        let mut recorder = Recorder::to_animation(&path, 1);
+        recorder.record(|| frame('a'));
+        assert!(path.exists());
+        recorder.record(|| frame('b'));
+        recorder.finish(|| frame('b'));
+        assert_eq!(recorder.kept(), 2);
+        assert!(recorder.frames().is_empty());
+        assert_eq!(recorder.close().unwrap(), std::slice::from_ref(&path));
+        let text = fs::read_to_string(&path).unwrap();
+        assert_eq!(parse_animation(&text).unwrap().len(), 2);
+
+        let mut recorder = Recorder::to_frames(&dir, "day00", RenderFormat::Ansi, 1);
+        recorder.record(|| frame('a'));
+        let files = recorder.close().unwrap();
+        assert_eq!(files, [dir.join("day00-000000.ans")]);
+        assert_eq!(fs::read_to_string(&files[0]).unwrap(), frame('a').to_ansi());
+
+        // Nothing is created without frames.
+        let recorder = Recorder::to_animation(&dir.join("none.anim"), 1);
+        assert!(recorder.close().unwrap().is_empty());
+        assert!(!dir.join("none.anim").exists());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_animation() {
+        let mut recorder = Recorder::new(1);
+        recorder.record(|| frame('a'));
+        recorder.record(|| frame('b'));
+        let animation = recorder.animation();
+        let frames = parse_animation(&animation).unwrap();
+        assert_eq!(frames.len(), 2);
+        assert_eq!(frames[1].0, 1);
+        assert_eq!(frames[1].1, frame('b').to_ansi());
+
+        let mut out = Vec::new();
+        replay(&frames, 1000.0, &mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.starts_with("\x1b[H\x1b[2J"));
+        assert!(out.ends_with("step 1\n"));
+
+        let err = parse_animation("aa\n").unwrap_err();
+        assert_eq!(err.to_string(), "1: Expected a '=== step N ===' header");
+    }
+}
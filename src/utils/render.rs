@@ -0,0 +1,438 @@
+use std::fmt::Write;
+
+use super::{
+    geom::Dir4,
+    grid::{Grid, Pos},
+};
+
+/// Side length of a grid cell in pixels when writing images.
+pub const DEFAULT_SCALE: usize = 4;
+
+/// An RGB color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Color {
+    pub const BLACK: Color = Color::rgb(0, 0, 0);
+    pub const WHITE: Color = Color::rgb(255, 255, 255);
+    pub const GRAY: Color = Color::rgb(128, 128, 128);
+    pub const DARK_GRAY: Color = Color::rgb(48, 48, 48);
+    pub const RED: Color = Color::rgb(220, 50, 47);
+    pub const GREEN: Color = Color::rgb(80, 200, 80);
+    pub const BLUE: Color = Color::rgb(38, 139, 210);
+    pub const YELLOW: Color = Color::rgb(240, 200, 40);
+    pub const ORANGE: Color = Color::rgb(230, 120, 30);
+    pub const BROWN: Color = Color::rgb(150, 100, 50);
+
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b }
+    }
+
+    pub const fn gray(level: u8) -> Self {
+        Self::rgb(level, level, level)
+    }
+
+    /// A color for the `index`-th of many items, like regions, that is easy to tell apart from
+    /// the colors of the items next to it.
+    pub fn palette(index: usize) -> Self {
+        // Golden angle steps spread consecutive hues around the color wheel.
+        let hue = (index as f64 * 137.508) % 360.0;
+        Self::from_hsv(hue, 0.6, 0.9)
+    }
+
+    /// Blends linearly from `self` at `t == 0.0` to `other` at `t == 1.0`.
+    pub fn mix(self, other: Color, t: f64) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        let channel = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * t).round() as u8;
+        Self::rgb(
+            channel(self.r, other.r),
+            channel(self.g, other.g),
+            channel(self.b, other.b),
+        )
+    }
+
+    /// Perceived brightness, for grayscale images.
+    pub fn luminance(self) -> u8 {
+        (0.299 * self.r as f64 + 0.587 * self.g as f64 + 0.114 * self.b as f64).round() as u8
+    }
+
+    fn from_hsv(hue: f64, saturation: f64, value: f64) -> Self {
+        let c = value * saturation;
+        let x = c * (1.0 - ((hue / 60.0) % 2.0 - 1.0).abs());
+        let (r, g, b) = match hue as u32 / 60 {
+            0 => (c, x, 0.0),
+            1 => (x, c, 0.0),
+            2 => (0.0, c, x),
+            3 => (0.0, x, c),
+            4 => (x, 0.0, c),
+            _ => (c, 0.0, x),
+        };
+        let channel = |v: f64| ((v + value - c) * 255.0).round() as u8;
+        Self::rgb(channel(r), channel(g), channel(b))
+    }
+
+    fn hex(self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+    }
+}
+
+/// How one grid cell is drawn: a character for text output, and its color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cell {
+    pub ch: char,
+    pub color: Color,
+    /// Background color of the cell, which replaces `color` in images.
+    pub highlight: Option<Color>,
+}
+
+impl Cell {
+    pub const fn new(ch: char, color: Color) -> Self {
+        Self {
+            ch,
+            color,
+            highlight: None,
+        }
+    }
+
+    pub const fn with_highlight(mut self, color: Color) -> Self {
+        self.highlight = Some(color);
+        self
+    }
+}
+
+/// The output formats of a [`Frame`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenderFormat {
+    /// Text with ANSI true color escape codes, for `cat` in a terminal.
+    Ansi,
+    /// Binary color image.
+    Ppm,
+    /// Binary grayscale image.
+    Pgm,
+    Svg,
+}
+
+impl RenderFormat {
+    pub const ALL: [RenderFormat; 4] = [
+        RenderFormat::Ansi,
+        RenderFormat::Ppm,
+        RenderFormat::Pgm,
+        RenderFormat::Svg,
+    ];
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            RenderFormat::Ansi => "ans",
+            RenderFormat::Ppm => "ppm",
+            RenderFormat::Pgm => "pgm",
+            RenderFormat::Svg => "svg",
+        }
+    }
+}
+
+/// A line through points given in cell units, where `(0.0, 0.0)` is the top left corner of the
+/// grid and `(x + 0.5, y + 0.5)` the center of cell `(x, y)`.
+#[derive(Debug, Clone, PartialEq)]
+struct Polyline {
+    points: Vec<(f64, f64)>,
+    color: Color,
+}
+
+/// A picture of a grid state with overlays, which can be written as text or as an image.
+#[derive(Debug, Clone)]
+pub struct Frame {
+    cells: Grid<Cell>,
+    lines: Vec<Polyline>,
+}
+
+impl Frame {
+    /// Draws every cell of `grid` with `style`.
+    pub fn from_grid<T>(grid: &Grid<T>, mut style: impl FnMut(Pos, &T) -> Cell) -> Self {
+        let mut cells = Grid::new(grid.width(), grid.height(), Cell::new(' ', Color::BLACK));
+        for (pos, value) in grid.iter() {
+            cells[pos] = style(pos, value);
+        }
+        Self {
+            cells,
+            lines: Vec::new(),
+        }
+    }
+
+    /// An empty `width` x `height` frame where every cell is `cell`.
+    pub fn filled(width: usize, height: usize, cell: Cell) -> Self {
+        Self {
+            cells: Grid::new(width, height, cell),
+            lines: Vec::new(),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.cells.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.cells.height()
+    }
+
+    pub fn cell(&self, pos: Pos) -> &Cell {
+        &self.cells[pos]
+    }
+
+    /// Replaces the cell at `pos`, keeping its highlight. Positions outside the frame are ignored.
+    pub fn set(&mut self, pos: Pos, ch: char, color: Color) {
+        if let Some(cell) = self.cells.get_mut(pos) {
+            cell.ch = ch;
+            cell.color = color;
+        }
+    }
+
+    /// Highlights the cell at `pos`. Positions outside the frame are ignored.
+    pub fn highlight(&mut self, pos: Pos, color: Color) {
+        if let Some(cell) = self.cells.get_mut(pos) {
+            cell.highlight = Some(color);
+        }
+    }
+
+    pub fn highlight_all(&mut self, positions: impl IntoIterator<Item = Pos>, color: Color) {
+        for pos in positions {
+            self.highlight(pos, color);
+        }
+    }
+
+    /// Highlights the cells of a path and connects their centers with a line.
+    pub fn path(&mut self, path: &[Pos], color: Color) {
+        self.highlight_all(path.iter().copied(), color);
+        self.line(path, color);
+    }
+
+    /// Connects the centers of the cells with a line, without highlighting them.
+    pub fn line(&mut self, positions: &[Pos], color: Color) {
+        let points = positions
+            .iter()
+            .map(|&(x, y)| (x as f64 + 0.5, y as f64 + 0.5))
+            .collect();
+        self.lines.push(Polyline { points, color });
+    }
+
+    /// Draws a line along the side of the cell at `pos` that faces `side`, like a fence.
+    pub fn edge(&mut self, (x, y): Pos, side: Dir4, color: Color) {
+        let (x, y) = (x as f64, y as f64);
+        let points = match side {
+            Dir4::Up => vec![(x, y), (x + 1.0, y)],
+            Dir4::Right => vec![(x + 1.0, y), (x + 1.0, y + 1.0)],
+            Dir4::Down => vec![(x, y + 1.0), (x + 1.0, y + 1.0)],
+            Dir4::Left => vec![(x, y), (x, y + 1.0)],
+        };
+        self.lines.push(Polyline { points, color });
+    }
+
+    pub fn encode(&self, format: RenderFormat) -> Vec<u8> {
+        match format {
+            RenderFormat::Ansi => self.to_ansi().into_bytes(),
+            RenderFormat::Ppm => self.to_ppm(DEFAULT_SCALE),
+            RenderFormat::Pgm => self.to_pgm(DEFAULT_SCALE),
+            RenderFormat::Svg => self.to_svg(DEFAULT_SCALE).into_bytes(),
+        }
+    }
+
+    /// The characters of the cells in their colors. Lines are not drawn, only the cells they
+    /// highlight.
+    pub fn to_ansi(&self) -> String {
+        let mut text = String::new();
+        for row in self.cells.rows() {
+            let mut current = None;
+            for cell in row {
+                let style = (cell.color, cell.highlight);
+                if current != Some(style) {
+                    write!(
+                        text,
+                        "\x1b[0;38;2;{};{};{}",
+                        cell.color.r, cell.color.g, cell.color.b
+                    )
+                    .unwrap();
+                    if let Some(bg) = cell.highlight {
+                        write!(text, ";48;2;{};{};{}", bg.r, bg.g, bg.b).unwrap();
+                    }
+                    text.push('m');
+                    current = Some(style);
+                }
+                text.push(cell.ch);
+            }
+            text.push_str("\x1b[0m\n");
+        }
+        text
+    }
+
+    /// Binary PPM (P6) image with `scale` x `scale` pixels per cell.
+    pub fn to_ppm(&self, scale: usize) -> Vec<u8> {
+        let (width, height, pixels) = self.rasterize(scale);
+        let mut image = format!("P6\n{} {}\n255\n", width, height).into_bytes();
+        image.extend(pixels.iter().flat_map(|c| [c.r, c.g, c.b]));
+        image
+    }
+
+    /// Binary PGM (P5) grayscale image with `scale` x `scale` pixels per cell.
+    pub fn to_pgm(&self, scale: usize) -> Vec<u8> {
+        let (width, height, pixels) = self.rasterize(scale);
+        let mut image = format!("P5\n{} {}\n255\n", width, height).into_bytes();
+        image.extend(pixels.iter().map(|c| c.luminance()));
+        image
+    }
+
+    /// SVG image where each cell is a `scale` x `scale` square.
+    pub fn to_svg(&self, scale: usize) -> String {
+        let (width, height) = (self.width() * scale, self.height() * scale);
+        let mut svg = String::new();
+        writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}" shape-rendering="crispEdges">"#,
+            w = width,
+            h = height
+        )
+        .unwrap();
+        // One rectangle per run of cells with the same color keeps the file small.
+        for (y, row) in self.cells.rows().enumerate() {
+            let mut x = 0;
+            while x < row.len() {
+                let color = fill(&row[x]);
+                let run = row[x..].iter().take_while(|c| fill(c) == color).count();
+                writeln!(
+                    svg,
+                    r#"<rect x="{}" y="{}" width="{}" height="{}" fill="{}"/>"#,
+                    x * scale,
+                    y * scale,
+                    run * scale,
+                    scale,
+                    color.hex()
+                )
+                .unwrap();
+                x += run;
+            }
+        }
+        for line in &self.lines {
+            let points: Vec<String> = line
+                .points
+                .iter()
+                .map(|(x, y)| format!("{},{}", x * scale as f64, y * scale as f64))
+                .collect();
+            writeln!(
+                svg,
+                r#"<polyline points="{}" fill="none" stroke="{}" stroke-width="{}" stroke-linecap="round"/>"#,
+                points.join(" "),
+                line.color.hex(),
+                (scale as f64 / 4.0).max(1.0)
+            )
+            .unwrap();
+        }
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    /// The pixels of the image, row by row, with the lines drawn one pixel wide.
+    fn rasterize(&self, scale: usize) -> (usize, usize, Vec<Color>) {
+        let (width, height) = (self.width() * scale, self.height() * scale);
+        let mut pixels = vec![Color::BLACK; width * height];
+        for (y, row) in self.cells.rows().enumerate() {
+            for (x, cell) in row.iter().enumerate() {
+                for py in y * scale..(y + 1) * scale {
+                    pixels[py * width + x * scale..py * width + (x + 1) * scale].fill(fill(cell));
+                }
+            }
+        }
+        if width == 0 || height == 0 {
+            return (width, height, pixels);
+        }
+        let to_pixel = |v: f64, size: usize| ((v * scale as f64).round() as usize).min(size - 1);
+        for line in &self.lines {
+            for segment in line.points.windows(2) {
+                let (x1, y1) = (
+                    to_pixel(segment[0].0, width),
+                    to_pixel(segment[0].1, height),
+                );
+                let (x2, y2) = (
+                    to_pixel(segment[1].0, width),
+                    to_pixel(segment[1].1, height),
+                );
+                let steps = x1.abs_diff(x2).max(y1.abs_diff(y2)).max(1);
+                for i in 0..=steps {
+                    let t = i as f64 / steps as f64;
+                    let x = (x1 as f64 + (x2 as f64 - x1 as f64) * t).round() as usize;
+                    let y = (y1 as f64 + (y2 as f64 - y1 as f64) * t).round() as usize;
+                    pixels[y * width + x] = line.color;
+                }
+            }
+        }
+        (width, height, pixels)
+    }
+}
+
+/// The color of a cell in images.
+fn fill(cell: &Cell) -> Color {
+    cell.highlight.unwrap_or(cell.color)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame() -> Frame {
+        let grid = Grid::parse("#.\n.#\n").unwrap();
+        Frame::from_grid(&grid, |_, &ch| match ch {
+            '#' => Cell::new('#', Color::WHITE),
+            _ => Cell::new('.', Color::BLACK),
+        })
+    }
+
+    #[test]
+    fn test_images() {
+        let mut frame = frame();
+        frame.highlight((1, 0), Color::RED);
+        let ppm = frame.to_ppm(1);
+        assert_eq!(&ppm[..11], b"P6\n2 2\n255\n");
+        assert_eq!(
+            &ppm[11..],
+            [255, 255, 255, 220, 50, 47, 0, 0, 0, 255, 255, 255]
+        );
+        let pgm = frame.to_pgm(2);
+        assert_eq!(&pgm[..11], b"P5\n4 4\n255\n");
+        assert_eq!(pgm.len(), 11 + 16);
+        assert_eq!(&pgm[11..15], [255, 255, 100, 100]);
+
+        frame.path(&[(0, 1), (1, 1)], Color::BLUE);
+        let ppm = frame.to_ppm(2);
+        // The line runs through the centers of the bottom row.
+        let pixel = |x: usize, y: usize| &ppm[11 + 3 * (y * 4 + x)..][..3];
+        assert_eq!(pixel(0, 2), [38, 139, 210]);
+        assert_eq!(pixel(3, 2), [38, 139, 210]);
+    }
+
+    #[test]
+    fn test_text() {
+        let mut frame = frame();
+        frame.set((1, 0), '@', Color::YELLOW);
+        frame.edge((0, 0), Dir4::Right, Color::RED);
+        assert_eq!(
+            frame.to_ansi(),
+            "\x1b[0;38;2;255;255;255m#\x1b[0;38;2;240;200;40m@\x1b[0m\n\
+             \x1b[0;38;2;0;0;0m.\x1b[0;38;2;255;255;255m#\x1b[0m\n"
+        );
+        let svg = frame.to_svg(10);
+        assert!(svg.starts_with("<svg "));
+        assert!(svg.contains(r##"<rect x="0" y="0" width="10" height="10" fill="#ffffff"/>"##));
+        assert!(svg.contains(r##"<polyline points="10,0 10,10" fill="none" stroke="#dc322f""##));
+        assert!(svg.ends_with("</svg>\n"));
+    }
+
+    #[test]
+    fn test_palette() {
+        let colors: Vec<Color> = (0..10).map(Color::palette).collect();
+        for (i, a) in colors.iter().enumerate() {
+            assert!(colors[i + 1..].iter().all(|b| a != b));
+        }
+        assert_eq!(Color::BLACK.mix(Color::WHITE, 0.5), Color::gray(128));
+    }
+}
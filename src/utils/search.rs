@@ -0,0 +1,264 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// Result of a search from a start state: the cost of reaching each visited state, and all
+/// predecessors through which it is reached at that cost.
+#[derive(Debug, Clone)]
+pub struct Search<S, C> {
+    start: S,
+    distances: HashMap<S, C>,
+    predecessors: HashMap<S, Vec<S>>,
+}
+
+impl<S, C> Search<S, C>
+where
+    S: Clone + Hash + Eq,
+    C: Copy,
+{
+    pub fn start(&self) -> &S {
+        &self.start
+    }
+
+    /// Cost of the cheapest path to `state`, or `None` if it can't be reached.
+    pub fn distance(&self, state: &S) -> Option<C> {
+        self.distances.get(state).copied()
+    }
+
+    /// All reached states with their costs.
+    pub fn distances(&self) -> &HashMap<S, C> {
+        &self.distances
+    }
+
+    /// The states right before `state` on its cheapest paths.
+    pub fn predecessors(&self, state: &S) -> &[S] {
+        self.predecessors.get(state).map_or(&[], Vec::as_slice)
+    }
+
+    /// One cheapest path from the start to `target`, both included.
+    pub fn path_to(&self, target: &S) -> Option<Vec<S>> {
+        self.distances.get(target)?;
+        let mut path = vec![target.clone()];
+        while let Some(prev) = self.predecessors(path.last().unwrap()).first() {
+            path.push(prev.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// All states lying on any cheapest path from the start to one of `targets`.
+    pub fn states_on_optimal_paths<'a, I>(&self, targets: I) -> HashSet<S>
+    where
+        I: IntoIterator<Item = &'a S>,
+        S: 'a,
+    {
+        let mut states = HashSet::new();
+        let mut stack: Vec<S> = targets
+            .into_iter()
+            .filter(|target| self.distances.contains_key(target))
+            .cloned()
+            .collect();
+        while let Some(state) = stack.pop() {
+            if states.insert(state.clone()) {
+                stack.extend(self.predecessors(&state).iter().cloned());
+            }
+        }
+        states
+    }
+}
+
+/// Breadth-first search where every step costs 1.
+pub fn bfs<S, N, I>(start: S, mut neighbors: N) -> Search<S, usize>
+where
+    S: Clone + Hash + Eq,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = S>,
+{
+    let mut distances = HashMap::from([(start.clone(), 0)]);
+    let mut predecessors: HashMap<S, Vec<S>> = HashMap::new();
+    let mut queue = VecDeque::from([start.clone()]);
+    while let Some(state) = queue.pop_front() {
+        let next_distance = distances[&state] + 1;
+        for next in neighbors(&state) {
+            match distances.get(&next) {
+                None => {
+                    distances.insert(next.clone(), next_distance);
+                    predecessors.insert(next.clone(), vec![state.clone()]);
+                    queue.push_back(next);
+                }
+                Some(&distance) if distance == next_distance => {
+                    predecessors.entry(next).or_default().push(state.clone());
+                }
+                Some(_) => {}
+            }
+        }
+    }
+    Search {
+        start,
+        distances,
+        predecessors,
+    }
+}
+
+/// Dijkstra's algorithm over non-negative step costs.
+///
+/// `neighbors` returns the states reachable from a state together with the cost of each step.
+pub fn dijkstra<S, C, N, I>(start: S, neighbors: N) -> Search<S, C>
+where
+    S: Clone + Hash + Eq,
+    C: Copy + Ord + Default + Add<Output = C>,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, C)>,
+{
+    best_first(start, neighbors, |_| C::default(), |_| false).0
+}
+
+/// A* search towards the first state for which `is_goal` holds.
+///
+/// `heuristic` must be consistent: it never drops by more than the cost of a step, like the
+/// Manhattan distance on a grid. Returns the cost and one cheapest path to the goal.
+pub fn astar<S, C, N, I, H, G>(
+    start: S,
+    neighbors: N,
+    heuristic: H,
+    is_goal: G,
+) -> Option<(C, Vec<S>)>
+where
+    S: Clone + Hash + Eq,
+    C: Copy + Ord + Default + Add<Output = C>,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, C)>,
+    H: FnMut(&S) -> C,
+    G: FnMut(&S) -> bool,
+{
+    let (search, goal) = best_first(start, neighbors, heuristic, is_goal);
+    let goal = goal?;
+    Some((search.distance(&goal)?, search.path_to(&goal)?))
+}
+
+/// Shared core of Dijkstra and A*: expands states by cost plus heuristic until the queue is
+/// empty or a goal state is expanded.
+fn best_first<S, C, N, I, H, G>(
+    start: S,
+    mut neighbors: N,
+    mut heuristic: H,
+    mut is_goal: G,
+) -> (Search<S, C>, Option<S>)
+where
+    S: Clone + Hash + Eq,
+    C: Copy + Ord + Default + Add<Output = C>,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, C)>,
+    H: FnMut(&S) -> C,
+    G: FnMut(&S) -> bool,
+{
+    let mut distances = HashMap::from([(start.clone(), C::default())]);
+    let mut predecessors: HashMap<S, Vec<S>> = HashMap::new();
+    let mut done = HashSet::new();
+    // The heap holds indices into `queued`, so that states don't need to be `Ord`.
+    let mut queued = vec![start.clone()];
+    let mut heap = BinaryHeap::from([Reverse((heuristic(&start), C::default(), 0))]);
+    let mut goal = None;
+
+    while let Some(Reverse((_, distance, index))) = heap.pop() {
+        let state = queued[index].clone();
+        if distances[&state] < distance || !done.insert(state.clone()) {
+            continue;
+        }
+        if is_goal(&state) {
+            goal = Some(state);
+            break;
+        }
+        for (next, step_cost) in neighbors(&state) {
+            let next_distance = distance + step_cost;
+            match distances.get(&next) {
+                Some(&known) if known < next_distance => continue,
+                Some(&known) if known == next_distance => {
+                    predecessors.entry(next).or_default().push(state.clone());
+                    continue;
+                }
+                _ => {}
+            }
+            distances.insert(next.clone(), next_distance);
+            predecessors.insert(next.clone(), vec![state.clone()]);
+            let priority = next_distance + heuristic(&next);
+            queued.push(next);
+            heap.push(Reverse((priority, next_distance, queued.len() - 1)));
+        }
+    }
+
+    let search = Search {
+        start,
+        distances,
+        predecessors,
+    };
+    (search, goal)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A 3x3 grid without walls, as `(x, y)` positions.
+    fn open_neighbors(&(x, y): &(i64, i64)) -> Vec<(i64, i64)> {
+        [(x - 1, y), (x + 1, y), (x, y - 1), (x, y + 1)]
+            .into_iter()
+            .filter(|&(x, y)| (0..3).contains(&x) && (0..3).contains(&y))
+            .collect()
+    }
+
+    #[test]
+    fn test_bfs() {
+        let search = bfs((0, 0), open_neighbors);
+        assert_eq!(search.distance(&(2, 2)), Some(4));
+        assert_eq!(search.distance(&(3, 3)), None);
+        assert_eq!(search.predecessors(&(1, 1)).len(), 2);
+        assert_eq!(
+            search.path_to(&(2, 0)).unwrap(),
+            vec![(0, 0), (1, 0), (2, 0)]
+        );
+        assert_eq!(search.states_on_optimal_paths([&(2, 2)]).len(), 9);
+        assert_eq!(search.states_on_optimal_paths([&(2, 0)]).len(), 3);
+    }
+
+    #[test]
+    fn test_dijkstra_keeps_all_optimal_predecessors() {
+        // Two paths of cost 3 from 'a' to 'd', and a more expensive direct edge.
+        let edges = HashMap::from([
+            ('a', vec![('b', 1), ('c', 2), ('d', 5)]),
+            ('b', vec![('d', 2)]),
+            ('c', vec![('d', 1)]),
+        ]);
+        let search = dijkstra('a', |s| edges.get(s).cloned().unwrap_or_default());
+        assert_eq!(search.distance(&'d'), Some(3));
+        let mut preds = search.predecessors(&'d').to_vec();
+        preds.sort();
+        assert_eq!(preds, vec!['b', 'c']);
+        assert_eq!(
+            search.states_on_optimal_paths([&'d']),
+            HashSet::from(['a', 'b', 'c', 'd'])
+        );
+    }
+
+    #[test]
+    fn test_astar() {
+        let goal = (2, 1);
+        let (cost, path) = astar(
+            (0, 0),
+            |s| open_neighbors(s).into_iter().map(|n| (n, 1)),
+            |&(x, y): &(i64, i64)| x.abs_diff(goal.0) + y.abs_diff(goal.1),
+            |&s| s == goal,
+        )
+        .unwrap();
+        assert_eq!(cost, 3);
+        assert_eq!(path.len(), 4);
+        assert_eq!(path.first(), Some(&(0, 0)));
+        assert_eq!(path.last(), Some(&goal));
+
+        let unreachable = astar((0, 0), |_| Vec::new(), |_| 0u64, |&s| s == goal);
+        assert_eq!(unreachable, None);
+    }
+}
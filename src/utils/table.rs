@@ -0,0 +1,87 @@
+use std::{fmt::Write, time::Duration};
+
+/// Formats rows as a text table with left-aligned columns.
+pub fn format_table<S: AsRef<str>>(header: &[&str], rows: &[Vec<S>]) -> String {
+    let mut widths: Vec<usize> = header.iter().map(|h| h.len()).collect();
+    for row in rows.iter() {
+        for (width, cell) in widths.iter_mut().zip(row.iter()) {
+            *width = (*width).max(cell.as_ref().chars().count());
+        }
+    }
+
+    let format_row = |row: &mut dyn Iterator<Item = &str>| {
+        row.zip(widths.iter())
+            .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+            .collect::<Vec<_>>()
+            .join(" | ")
+            .trim_end()
+            .to_string()
+    };
+    let separator = widths
+        .iter()
+        .map(|width| "-".repeat(*width))
+        .collect::<Vec<_>>()
+        .join("-+-");
+
+    let mut table = String::new();
+    writeln!(table, "{}", format_row(&mut header.iter().copied())).unwrap();
+    writeln!(table, "{}", separator).unwrap();
+    for row in rows.iter() {
+        writeln!(table, "{}", format_row(&mut row.iter().map(|c| c.as_ref()))).unwrap();
+    }
+    table
+}
+
+/// Formats rows as CSV, quoting the fields that need it.
+pub fn format_csv<S: AsRef<str>>(header: &[&str], rows: &[Vec<S>]) -> String {
+    let mut csv = String::new();
+    writeln!(csv, "{}", header.join(",")).unwrap();
+    for row in rows.iter() {
+        let fields: Vec<String> = row.iter().map(|field| csv_field(field.as_ref())).collect();
+        writeln!(csv, "{}", fields.join(",")).unwrap();
+    }
+    csv
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Short human-readable form of a duration, like `1.25ms`.
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    if nanos < 1_000 {
+        format!("{}ns", nanos)
+    } else if nanos < 1_000_000 {
+        format!("{:.2}µs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.2}ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.2}s", duration.as_secs_f64())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_csv() {
+        let rows = vec![vec!["17", "5,1,3"], vec!["23", "say \"hi\""]];
+        assert_eq!(
+            format_csv(&["day", "answer"], &rows),
+            "day,answer\n17,\"5,1,3\"\n23,\"say \"\"hi\"\"\"\n"
+        );
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_nanos(950)), "950ns");
+        assert_eq!(format_duration(Duration::from_micros(1250)), "1.25ms");
+        assert_eq!(format_duration(Duration::from_millis(2500)), "2.50s");
+    }
+}
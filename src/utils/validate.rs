@@ -0,0 +1,188 @@
+use super::parse::Block;
+use crate::error::{Error, Result};
+
+/// Checks that `input` has at least one non-blank line.
+pub fn non_empty(input: &str) -> Vec<Error> {
+    if input.trim().is_empty() {
+        vec![Error::parse("Empty input").at_line(1)]
+    } else {
+        Vec::new()
+    }
+}
+
+/// Checks that `input` is a non-empty rectangular grid whose characters all satisfy `allowed`,
+/// reporting every ragged row and every invalid character.
+pub fn grid(input: &str, allowed: impl Fn(char) -> bool) -> Vec<Error> {
+    let mut errors = Vec::new();
+    let width = input.lines().next().map_or(0, |line| line.chars().count());
+    if width == 0 {
+        errors.push(Error::parse("Empty grid").at_line(1));
+        return errors;
+    }
+    for (y, line) in input.lines().enumerate() {
+        let mut len = 0;
+        for (x, ch) in line.chars().enumerate() {
+            if !allowed(ch) {
+                errors.push(Error::at(
+                    y + 1,
+                    x + 1,
+                    format!("Invalid character '{}' in grid", ch),
+                ));
+            }
+            len += 1;
+        }
+        if len != width {
+            errors.push(Error::at(
+                y + 1,
+                len.min(width) + 1,
+                format!("Expected {} columns, got {}", width, len),
+            ));
+        }
+    }
+    errors
+}
+
+/// Checks that the border of a rectangular grid is made of `wall`, so that nothing inside can
+/// leave it.
+pub fn walled(input: &str, wall: char) -> Vec<Error> {
+    let rows: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
+    let mut errors = Vec::new();
+    for (y, row) in rows.iter().enumerate() {
+        for (x, &ch) in row.iter().enumerate() {
+            let on_border = y == 0 || y == rows.len() - 1 || x == 0 || x == row.len() - 1;
+            if on_border && ch != wall {
+                errors.push(Error::at(
+                    y + 1,
+                    x + 1,
+                    format!("Expected a wall '{}' on the border, got '{}'", wall, ch),
+                ));
+            }
+        }
+    }
+    errors
+}
+
+/// The 1-based line and column of every `ch` in `input`.
+pub fn occurrences(input: &str, ch: char) -> Vec<(usize, usize)> {
+    input
+        .lines()
+        .enumerate()
+        .flat_map(|(y, line)| {
+            line.chars()
+                .enumerate()
+                .filter(move |&(_, c)| c == ch)
+                .map(move |(x, _)| (y + 1, x + 1))
+        })
+        .collect()
+}
+
+/// Checks that `ch`, the `name` of a tile, appears exactly once, reporting every extra one.
+pub fn exactly_one(input: &str, ch: char, name: &str) -> Vec<Error> {
+    match occurrences(input, ch).as_slice() {
+        [] => vec![Error::parse(format!("No {} '{}' found", name, ch))],
+        [(line, column), rest @ ..] => rest
+            .iter()
+            .map(|&(l, c)| {
+                Error::at(
+                    l,
+                    c,
+                    format!(
+                        "Another {} '{}', the first one is at {}:{}",
+                        name, ch, line, column
+                    ),
+                )
+            })
+            .collect(),
+    }
+}
+
+/// Parses each line with `f`, collecting the errors of all lines instead of stopping at the first.
+pub fn each_line<T>(input: &str, mut f: impl FnMut(&str) -> Result<T>) -> Vec<Error> {
+    input
+        .lines()
+        .enumerate()
+        .filter_map(|(i, line)| f(line).err().map(|e| e.at_line(i + 1)))
+        .collect()
+}
+
+/// Validates a block of the input with `f`, moving the errors to the block's place in the input.
+pub fn in_block(block: &Block, f: impl FnOnce(&str) -> Vec<Error>) -> Vec<Error> {
+    f(block.text)
+        .into_iter()
+        .map(|e| e.at_line(1).offset_lines(block.line - 1))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::parse::blocks;
+
+    fn messages(errors: Vec<Error>) -> Vec<String> {
+        errors.iter().map(Error::to_string).collect()
+    }
+
+    #[test]
+    fn test_non_empty() {
+        assert!(non_empty("1\n").is_empty());
+        assert_eq!(messages(non_empty(" \n\n")), ["1: Empty input"]);
+    }
+
+    #[test]
+    fn test_grid() {
+        assert!(grid("#.#\n.S.\n", |ch| "#.S".contains(ch)).is_empty());
+        assert_eq!(
+            messages(grid("#.#\n.x\n#..#\n", |ch| "#.".contains(ch))),
+            [
+                "2:2: Invalid character 'x' in grid",
+                "2:3: Expected 3 columns, got 2",
+                "3:4: Expected 3 columns, got 4"
+            ]
+        );
+        assert_eq!(messages(grid("", |_| true)), ["1: Empty grid"]);
+    }
+
+    #[test]
+    fn test_walled() {
+        assert!(walled("###\n#.#\n###\n", '#').is_empty());
+        assert_eq!(
+            messages(walled("###\n..#\n##.\n", '#')),
+            [
+                "2:1: Expected a wall '#' on the border, got '.'",
+                "3:3: Expected a wall '#' on the border, got '.'"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_exactly_one() {
+        assert!(exactly_one("S..\n...\n", 'S', "start").is_empty());
+        assert_eq!(
+            messages(exactly_one("...\n", 'S', "start")),
+            ["No start 'S' found"]
+        );
+        assert_eq!(
+            messages(exactly_one(".S.\n..S\nS..\n", 'S', "start")),
+            [
+                "2:3: Another start 'S', the first one is at 1:2",
+                "3:1: Another start 'S', the first one is at 1:2"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_each_line_and_in_block() {
+        let input = "1\n2\n\nx\n4\ny\n";
+        let [_, block] = <[_; 2]>::try_from(blocks(input)).unwrap();
+        let errors = in_block(&block, |text| {
+            each_line(text, |line| {
+                line.parse::<u8>()
+                    .map_err(|_| Error::parse(format!("Invalid number '{}'", line)))
+            })
+        });
+        assert_eq!(
+            messages(errors),
+            ["4: Invalid number 'x'", "6: Invalid number 'y'"]
+        );
+    }
+}
@@ -0,0 +1,352 @@
+//! Talks to a local stand-in for the puzzle website, so the client is tested without network
+//! access.
+
+use std::{
+    fs,
+    io::{BufRead, BufReader, Read, Write},
+    net::{TcpListener, TcpStream},
+    path::PathBuf,
+    process::{self, Command},
+    sync::{Arc, Mutex},
+    thread,
+    time::{Duration, Instant},
+};
+
+use aoc2024::{
+    client::{Client, Fetched},
+    solution::Part,
+    submit::{Hint, Outcome},
+};
+
+const SESSION: &str = "53616c7465645f5f";
+
+/// A request received by the [`MockServer`].
+#[derive(Debug, Clone)]
+struct Request {
+    method: String,
+    path: String,
+    headers: Vec<(String, String)>,
+    body: String,
+}
+
+impl Request {
+    fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+type Handler = dyn Fn(&Request) -> (u16, String) + Send + Sync;
+
+/// An HTTP server on a free local port, answering each request with the status and body returned
+/// by its handler and keeping a log of the requests.
+struct MockServer {
+    url: String,
+    requests: Arc<Mutex<Vec<Request>>>,
+}
+
+impl MockServer {
+    fn start(handler: impl Fn(&Request) -> (u16, String) + Send + Sync + 'static) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let log = Arc::clone(&requests);
+        let handler: Arc<Handler> = Arc::new(handler);
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(stream) = stream else { continue };
+                if let Some(request) = read_request(&stream) {
+                    let (status, body) = handler(&request);
+                    log.lock().unwrap().push(request);
+                    respond(stream, status, &body);
+                }
+            }
+        });
+        MockServer { url, requests }
+    }
+
+    fn requests(&self) -> Vec<Request> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+fn read_request(stream: &TcpStream) -> Option<Request> {
+    let mut reader = BufReader::new(stream);
+    let mut line = String::new();
+    reader.read_line(&mut line).ok()?;
+    let mut words = line.split_whitespace();
+    let method = words.next()?.to_string();
+    let path = words.next()?.to_string();
+    let mut headers = Vec::new();
+    loop {
+        line.clear();
+        reader.read_line(&mut line).ok()?;
+        let Some((key, value)) = line.trim_end().split_once(": ") else {
+            break;
+        };
+        headers.push((key.to_string(), value.to_string()));
+    }
+    let mut request = Request {
+        method,
+        path,
+        headers,
+        body: String::new(),
+    };
+    let length = request
+        .header("Content-Length")
+        .map_or(0, |length| length.parse().unwrap());
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body).ok()?;
+    request.body = String::from_utf8(body).ok()?;
+    Some(request)
+}
+
+fn respond(mut stream: TcpStream, status: u16, body: &str) {
+    let response = format!(
+        "HTTP/1.1 {} Mock\r\nContent-Type: text/plain\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        body.len(),
+        body
+    );
+    let _ = stream.write_all(response.as_bytes());
+}
+
+/// Serves the inputs of days 1 to 24 to the right session, like the website before day 25.
+fn input_server() -> MockServer {
+    MockServer::start(|request| {
+        if request.header("Cookie") != Some(&format!("session={}", SESSION)) {
+            return (
+                400,
+                "Puzzle inputs differ by user.  Please log in.\n".to_string(),
+            );
+        }
+        let day = request
+            .path
+            .strip_prefix("/2024/day/")
+            .and_then(|rest| rest.strip_suffix("/input"))
+            .and_then(|day| day.parse::<u8>().ok());
+        match day {
+            Some(day) if day < 25 => (200, format!("input of day {}\n", day)),
+            _ => (404, "Not Found\n".to_string()),
+        }
+    })
+}
+
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("aoc-{}-{}", name, process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    dir
+}
+
+#[test]
+fn test_fetch_input() {
+    let server = input_server();
+    let dir = temp_dir("fetch");
+    let mut client = Client::new(&server.url, SESSION).with_throttle(Duration::ZERO);
+
+    let path = dir.join("day03.txt");
+    assert_eq!(
+        client.fetch_input(3, &dir).unwrap(),
+        Fetched::Downloaded(path.clone())
+    );
+    assert_eq!(fs::read_to_string(&path).unwrap(), "input of day 3\n");
+    // Cached inputs are never downloaded again, even if they were changed.
+    fs::write(&path, "edited\n").unwrap();
+    assert_eq!(
+        client.fetch_input(3, &dir).unwrap(),
+        Fetched::Cached(path.clone())
+    );
+    assert_eq!(fs::read_to_string(&path).unwrap(), "edited\n");
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "GET");
+    assert_eq!(requests[0].path, "/2024/day/3/input");
+    assert!(requests[0]
+        .header("User-Agent")
+        .unwrap()
+        .starts_with("aoc2024/"));
+
+    let err = client.fetch_input(25, &dir).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        format!("{}/2024/day/25/input: 404 (Not Found)", server.url)
+    );
+    assert_eq!(fs::read_dir(&dir).unwrap().count(), 1);
+
+    let mut client = Client::new(&server.url, "wrong").with_throttle(Duration::ZERO);
+    let err = client.fetch_input(4, &dir).unwrap_err();
+    assert!(err
+        .to_string()
+        .ends_with("400 (Puzzle inputs differ by user.  Please log in.)"));
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_fetch_throttle() {
+    let server = input_server();
+    let dir = temp_dir("throttle");
+    let throttle = Duration::from_millis(200);
+    let mut client = Client::new(&server.url, SESSION).with_throttle(throttle);
+    let start = Instant::now();
+    client.fetch_input(1, &dir).unwrap();
+    client.fetch_input(1, &dir).unwrap();
+    assert!(start.elapsed() < throttle, "Cached inputs don't wait");
+    client.fetch_input(2, &dir).unwrap();
+    assert!(start.elapsed() >= throttle);
+    assert_eq!(server.requests().len(), 2);
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_aoc_fetch() {
+    let server = input_server();
+    let dir = temp_dir("aoc-fetch");
+    let aoc = |session: Option<&str>, day: &str| {
+        let mut command = Command::new(env!("CARGO_BIN_EXE_aoc"));
+        command
+            .args(["fetch", "-i", dir.to_str().unwrap(), day])
+            .env("AOC_BASE_URL", &server.url)
+            .env("AOC_SESSION_FILE", dir.join("no-session"))
+            .env_remove("AOC_SESSION");
+        if let Some(session) = session {
+            command.env("AOC_SESSION", session);
+        }
+        command.output().unwrap()
+    };
+
+    let output = aoc(Some(SESSION), "7");
+    assert!(output.status.success());
+    assert_eq!(
+        fs::read_to_string(dir.join("day07.txt")).unwrap(),
+        "input of day 7\n"
+    );
+    let output = aoc(Some(SESSION), "7");
+    assert!(String::from_utf8_lossy(&output.stderr).contains("already in"));
+    assert_eq!(server.requests().len(), 1);
+
+    let output = aoc(None, "8");
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("No session token"));
+    assert!(!dir.join("day08.txt").exists());
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+/// Accepts 11 as the answer of day 1 part 1 and says whether other numbers are too high or low.
+fn answer_server() -> MockServer {
+    MockServer::start(|request| {
+        let page = |message: &str| {
+            format!(
+                "<html><main><article><p>{}</p></article></main></html>",
+                message
+            )
+        };
+        if request.method != "POST" || request.path != "/2024/day/1/answer" {
+            return (404, "Not Found".to_string());
+        }
+        let form: Vec<(&str, &str)> = request
+            .body
+            .split('&')
+            .filter_map(|field| field.split_once('='))
+            .collect();
+        if form.first() != Some(&("level", "1")) {
+            return (200, page("You don't seem to be solving the right level."));
+        }
+        let answer: i64 = form[1].1.parse().unwrap();
+        let message = match answer.cmp(&11) {
+            std::cmp::Ordering::Equal => "That's the right answer!  You are <em>one gold star</em> closer.",
+            std::cmp::Ordering::Greater => "That's not the right answer; your answer is too high.  Please wait one minute before trying again.",
+            std::cmp::Ordering::Less => "That's not the right answer; your answer is too low.  Please wait one minute before trying again.",
+        };
+        (200, page(message))
+    })
+}
+
+#[test]
+fn test_submit() {
+    let server = answer_server();
+    let mut client = Client::new(&server.url, SESSION).with_throttle(Duration::ZERO);
+    assert_eq!(
+        client.submit(1, Part::One, "12").unwrap(),
+        Outcome::Wrong {
+            hint: Some(Hint::TooHigh),
+            wait: Some(Duration::from_secs(60))
+        }
+    );
+    assert_eq!(client.submit(1, Part::One, "11").unwrap(), Outcome::Correct);
+    assert_eq!(
+        client.submit(1, Part::Two, "11").unwrap(),
+        Outcome::WrongLevel
+    );
+    let requests = server.requests();
+    assert_eq!(requests[0].body, "level=1&answer=12");
+    assert_eq!(
+        requests[0].header("Content-Type"),
+        Some("application/x-www-form-urlencoded")
+    );
+    assert_eq!(
+        requests[0].header("Cookie"),
+        Some(format!("session={}", SESSION).as_str())
+    );
+
+    let err = client.submit(2, Part::One, "1").unwrap_err();
+    assert!(err
+        .to_string()
+        .ends_with("/2024/day/2/answer: 404 (Not Found)"));
+}
+
+#[test]
+fn test_aoc_submit() {
+    let server = answer_server();
+    let dir = temp_dir("aoc-submit");
+    fs::create_dir_all(&dir).unwrap();
+    let answers = dir.join("answers.toml");
+    let aoc = |args: &[&str]| {
+        Command::new(env!("CARGO_BIN_EXE_aoc"))
+            .args(["submit", "-i", "examples/day01.txt", "--answers"])
+            .arg(&answers)
+            .args(args)
+            .env("AOC_BASE_URL", &server.url)
+            .env("AOC_SESSION", SESSION)
+            .output()
+            .unwrap()
+    };
+    let stderr = |output: &process::Output| String::from_utf8_lossy(&output.stderr).to_string();
+
+    let output = aoc(&["1", "1", "100"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "Day 1 part 1: 100 is wrong, too high (wait 1m 0s before trying again)\n"
+    );
+    let registry = fs::read_to_string(&answers).unwrap();
+    assert!(registry.contains("[examples.day01]\npart1_wrong = [\"100\"]\npart1_too_high = 100\n"));
+
+    // Neither known wrong answers nor answers during the wait are sent.
+    let output = aoc(&["1", "1", "100"]);
+    assert!(stderr(&output).contains("Not submitting 100: it was already submitted and is wrong"));
+    let output = aoc(&["1", "1", "101"]);
+    assert!(stderr(&output).contains("Not submitting 101: 100 is already too high"));
+    let output = aoc(&["1", "1", "5"]);
+    assert!(stderr(&output).contains("Not submitting 5: the website accepts answers again in"));
+    assert_eq!(server.requests().len(), 1);
+
+    // Once the wait is over, the answer computed from the example is accepted.
+    let registry: String = registry
+        .lines()
+        .filter(|line| !line.contains("_retry_at"))
+        .map(|line| format!("{}\n", line))
+        .collect();
+    fs::write(&answers, registry).unwrap();
+    let output = aoc(&["1", "1"]);
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert!(fs::read_to_string(&answers)
+        .unwrap()
+        .contains("part1 = \"11\"\n"));
+    let output = aoc(&["1", "1"]);
+    assert!(stderr(&output).contains("Not submitting 11: the part is already solved with 11"));
+    assert_eq!(server.requests().len(), 2);
+    fs::remove_dir_all(&dir).unwrap();
+}
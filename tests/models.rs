@@ -0,0 +1,80 @@
+//! Uses the day models through the public library API, the way other crates would.
+
+use std::fs;
+
+use aoc2024::{
+    day09::Disk,
+    day16::Maze,
+    day17::{reconstruct_program, Computer},
+    day21::{DirButton, NumButton, NumPathFinder},
+    day23::ComputerGraph,
+    day24::Circuit,
+};
+
+fn example(file: &str) -> String {
+    fs::read_to_string(format!("examples/{}", file)).unwrap()
+}
+
+#[test]
+fn test_disk() {
+    let mut disk = Disk::from_layout_string("12345").unwrap();
+    assert_eq!(disk.blocks().len(), 15);
+    assert_eq!(
+        disk.iter_files().collect::<Vec<_>>(),
+        vec![(0, 1, 0), (3, 6, 1), (10, 15, 2)]
+    );
+    disk.compact_blocks();
+    assert_eq!(disk.blocks().iter().flatten().count(), 9);
+
+    let mut disk = Disk::from_layout_string(&example("day09.txt")).unwrap();
+    disk.compact_files2();
+    assert_eq!(disk.checksum(), 2858);
+}
+
+#[test]
+fn test_computer() {
+    let mut computer = Computer::new([0, 0, 9], vec![2, 6]).unwrap();
+    computer.run();
+    assert_eq!(computer.registers(), [0, 1, 9]);
+
+    let computer = Computer::parse(&example("day17-2.txt")).unwrap();
+    let a = reconstruct_program(&computer).unwrap();
+    assert_eq!(a, 117440);
+    assert_eq!(computer.with_register_a(a).run(), computer.program());
+}
+
+#[test]
+fn test_maze() {
+    let maze = Maze::parse(&example("day16.txt")).unwrap();
+    assert_eq!(maze.find_paths(), (7036, 45));
+}
+
+#[test]
+fn test_keypad_paths() {
+    let mut finder = NumPathFinder::default();
+    // From A to 0 there is only one way, and the gap in the corner rules out going up first
+    // from 0 to 1.
+    assert_eq!(
+        finder.find_paths(NumButton::A, NumButton::Num(0)),
+        vec![vec![DirButton::Left, DirButton::A]]
+    );
+    assert_eq!(
+        finder.find_paths(NumButton::Num(0), NumButton::Num(1)),
+        vec![vec![DirButton::Up, DirButton::Left, DirButton::A]]
+    );
+}
+
+#[test]
+fn test_computer_graph() {
+    let graph = ComputerGraph::parse(&example("day23.txt")).unwrap();
+    assert_eq!(graph.computers().count(), 16);
+    assert_eq!(graph.cliques_of_size(3).len(), 12);
+    assert_eq!(graph.largest_clique().to_string(), "co,de,ka,ta");
+}
+
+#[test]
+fn test_circuit() {
+    let circuit = Circuit::parse(&example("day24.txt")).unwrap();
+    assert_eq!(circuit.eval(), 2024);
+    assert_eq!(circuit.eval_var(&"z00".to_string()), 0);
+}